@@ -0,0 +1,15 @@
+CREATE TABLE sync_runs (
+    id SERIAL PRIMARY KEY,
+    kind VARCHAR(30) NOT NULL,
+    params JSONB NOT NULL DEFAULT '{}'::jsonb,
+    status VARCHAR(20) NOT NULL DEFAULT 'running',
+    started_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMP,
+    success_count INT NOT NULL DEFAULT 0,
+    failure_count INT NOT NULL DEFAULT 0,
+    skipped_count INT NOT NULL DEFAULT 0,
+    summary JSONB,
+    error TEXT
+);
+
+CREATE INDEX idx_sync_runs_kind_started_at ON sync_runs (kind, started_at DESC);
@@ -0,0 +1,21 @@
+CREATE TABLE inspect_days (
+    inspect_day VARCHAR(10) PRIMARY KEY,
+    status VARCHAR(20) NOT NULL DEFAULT 'discovered',
+    last_run_id INT REFERENCES sync_runs (id) ON DELETE SET NULL,
+    saved_prices INT NOT NULL DEFAULT 0,
+    failed_stores INT NOT NULL DEFAULT 0,
+    discovered_at TIMESTAMP NOT NULL DEFAULT NOW(),
+    synced_at TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+-- 이미 저장된 가격 데이터의 조사일은 동기화 완료 상태로 등록
+INSERT INTO inspect_days (inspect_day, status, saved_prices, synced_at)
+SELECT
+    inspect_day,
+    'synced',
+    COUNT(*),
+    MAX(created_at)
+FROM prices
+GROUP BY inspect_day
+ON CONFLICT (inspect_day) DO NOTHING;
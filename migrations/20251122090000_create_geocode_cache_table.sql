@@ -0,0 +1,35 @@
+CREATE TABLE geocode_cache (
+    address_key TEXT PRIMARY KEY,
+    address TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL,
+    lat DOUBLE PRECISION,
+    lon DOUBLE PRECISION,
+    provider VARCHAR(20) NOT NULL,
+    geocoded_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+-- 이미 좌표가 저장된 매장 주소는 캐시에 등록하여 재조회하지 않도록 한다.
+-- (주소 정규화: 앞뒤 공백 제거, 연속 공백을 하나로)
+INSERT INTO geocode_cache (address_key, address, status, lat, lon, provider, geocoded_at)
+SELECT DISTINCT ON (address_key)
+    address_key,
+    address,
+    'found',
+    x_coord,
+    y_coord,
+    'vworld',
+    updated_at
+FROM (
+    SELECT
+        regexp_replace(btrim(COALESCE(NULLIF(road_addr, ''), jibun_addr)), '\s+', ' ', 'g') AS address_key,
+        COALESCE(NULLIF(road_addr, ''), jibun_addr) AS address,
+        x_coord,
+        y_coord,
+        updated_at
+    FROM stores
+    WHERE x_coord IS NOT NULL
+      AND y_coord IS NOT NULL
+      AND COALESCE(NULLIF(road_addr, ''), jibun_addr) <> ''
+) s
+ORDER BY address_key, updated_at DESC
+ON CONFLICT (address_key) DO NOTHING;
@@ -0,0 +1,10 @@
+ALTER TABLE stores
+ADD COLUMN geocode_status VARCHAR(20) NOT NULL DEFAULT 'manual_review';
+
+-- 이미 좌표가 저장된 매장은 기존 방식(도로명 주소 검색)으로 변환된 것으로 간주한다.
+UPDATE stores
+SET geocode_status = 'road'
+WHERE x_coord IS NOT NULL
+  AND y_coord IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_stores_geocode_status ON stores (geocode_status);
@@ -0,0 +1,17 @@
+CREATE TABLE raw_payloads (
+    id BIGSERIAL PRIMARY KEY,
+    upstream VARCHAR(20) NOT NULL,
+    endpoint VARCHAR(30) NOT NULL,
+    params JSONB NOT NULL DEFAULT '{}'::jsonb,
+    http_status INT,
+    content_hash CHAR(64),
+    body_gzip BYTEA,
+    body_size INT,
+    error TEXT,
+    fetched_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_raw_payloads_endpoint_params ON raw_payloads (endpoint, params, fetched_at DESC);
+CREATE INDEX idx_raw_payloads_inspect_day ON raw_payloads ((params->>'inspect_day'))
+    WHERE endpoint = 'prices';
+CREATE INDEX idx_raw_payloads_content_hash ON raw_payloads (content_hash);
@@ -0,0 +1,13 @@
+CREATE TABLE sync_quarantine (
+    id SERIAL PRIMARY KEY,
+    run_id INT REFERENCES sync_runs(id) ON DELETE SET NULL,
+    entity VARCHAR(20) NOT NULL,
+    record_key VARCHAR(100) NOT NULL,
+    reason VARCHAR(50) NOT NULL,
+    detail TEXT,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_sync_quarantine_run_id ON sync_quarantine (run_id);
+CREATE INDEX idx_sync_quarantine_reason ON sync_quarantine (reason, created_at DESC);
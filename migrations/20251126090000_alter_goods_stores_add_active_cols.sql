@@ -0,0 +1,14 @@
+ALTER TABLE goods
+ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT TRUE,
+ADD COLUMN last_seen_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+ALTER TABLE stores
+ADD COLUMN is_active BOOLEAN NOT NULL DEFAULT TRUE,
+ADD COLUMN last_seen_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+-- 기존 행은 마지막 갱신 시각을 마지막 확인 시각으로 사용한다.
+UPDATE goods SET last_seen_at = COALESCE(updated_at, created_at, NOW());
+UPDATE stores SET last_seen_at = COALESCE(updated_at, created_at, NOW());
+
+CREATE INDEX IF NOT EXISTS idx_goods_is_active ON goods (is_active);
+CREATE INDEX IF NOT EXISTS idx_stores_is_active ON stores (is_active);
@@ -0,0 +1,13 @@
+CREATE TABLE master_history (
+    id SERIAL PRIMARY KEY,
+    run_id INT REFERENCES sync_runs(id) ON DELETE SET NULL,
+    entity VARCHAR(20) NOT NULL,
+    entity_id VARCHAR(100) NOT NULL,
+    field VARCHAR(50) NOT NULL,
+    old_value TEXT,
+    new_value TEXT,
+    changed_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_master_history_entity ON master_history (entity, entity_id, changed_at DESC);
+CREATE INDEX idx_master_history_run_id ON master_history (run_id);
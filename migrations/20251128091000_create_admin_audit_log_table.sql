@@ -0,0 +1,15 @@
+CREATE TABLE admin_audit_log (
+    id SERIAL PRIMARY KEY,
+    actor VARCHAR(255),
+    auth_method VARCHAR(20),
+    method VARCHAR(10) NOT NULL,
+    path VARCHAR(255) NOT NULL,
+    query TEXT,
+    status_code INT NOT NULL,
+    allowed BOOLEAN NOT NULL,
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_admin_audit_log_created_at ON admin_audit_log (created_at DESC);
+CREATE INDEX idx_admin_audit_log_actor ON admin_audit_log (actor, created_at DESC);
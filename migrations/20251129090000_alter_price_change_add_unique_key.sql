@@ -0,0 +1,36 @@
+-- 중복 생성된 가격 변동 정리 (같은 키 중 가장 최근 행만 유지)
+DELETE FROM price_change pc
+USING price_change dup
+WHERE pc.good_id = dup.good_id
+    AND pc.store_id = dup.store_id
+    AND pc.inspect_day = dup.inspect_day
+    AND pc.id < dup.id;
+
+ALTER TABLE price_change
+ADD COLUMN prev_inspect_day VARCHAR(10),
+ADD COLUMN change_rate DOUBLE PRECISION,  -- (current - previous) / previous * 100 (%)
+ADD COLUMN updated_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+-- 기존 행의 비교 조사일은 기준일 직전의 가격 조사일로 채운다.
+UPDATE price_change pc
+SET prev_inspect_day = d.prev_day
+FROM (
+    SELECT
+        days.inspect_day,
+        (
+            SELECT MAX(p.inspect_day)
+            FROM prices p
+            WHERE p.inspect_day < days.inspect_day
+        ) AS prev_day
+    FROM (SELECT DISTINCT inspect_day FROM price_change) days
+) d
+WHERE pc.inspect_day = d.inspect_day;
+
+UPDATE price_change
+SET change_rate = ROUND((diff * 100.0 / previous_price)::numeric, 2)
+WHERE previous_price <> 0;
+
+ALTER TABLE price_change
+ADD CONSTRAINT price_change_good_store_day_unique UNIQUE (good_id, store_id, inspect_day);
+
+CREATE INDEX idx_price_change_inspect_day ON price_change (inspect_day);
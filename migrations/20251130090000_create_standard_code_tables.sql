@@ -0,0 +1,34 @@
+-- 한국소비자원 표준코드(getStandardInfoSvc) 분류별 코드 테이블
+-- 지역코드(AR)는 기존 regions 테이블을 그대로 사용한다.
+CREATE TABLE IF NOT EXISTS good_categories (
+    code VARCHAR(20) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    parent_code VARCHAR(20),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS units (
+    code VARCHAR(20) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    parent_code VARCHAR(20),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS store_types (
+    code VARCHAR(20) PRIMARY KEY,
+    name VARCHAR(100) NOT NULL,
+    parent_code VARCHAR(20),
+    updated_at TIMESTAMP NOT NULL DEFAULT NOW()
+);
+
+-- 상품 / 매장 동기화가 표준코드 동기화보다 먼저 실행될 수 있으므로
+-- 외래 키 대신 인덱스만 두고, 조회 시 LEFT JOIN / 코드표로 이름을 붙인다.
+ALTER TABLE goods
+ADD COLUMN category_code VARCHAR(20),
+ADD COLUMN unit_code VARCHAR(20);
+
+ALTER TABLE stores
+ADD COLUMN store_type_code VARCHAR(20);
+
+CREATE INDEX IF NOT EXISTS idx_goods_category_code ON goods (category_code);
+CREATE INDEX IF NOT EXISTS idx_stores_store_type_code ON stores (store_type_code);
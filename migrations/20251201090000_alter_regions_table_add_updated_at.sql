@@ -0,0 +1,10 @@
+-- 지역명 변경 / 상위 지역 변경을 갱신할 수 있도록 마지막 갱신 시각을 기록한다.
+ALTER TABLE regions
+ADD COLUMN IF NOT EXISTS updated_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+-- 하위 지역 / 하위 지역 전체(재귀) 조회용
+CREATE INDEX IF NOT EXISTS idx_regions_parent_code ON regions (parent_code);
+
+-- 지역 기준 매장 조회용
+CREATE INDEX IF NOT EXISTS idx_stores_area_code ON stores (area_code);
+CREATE INDEX IF NOT EXISTS idx_stores_area_detail_code ON stores (area_detail_code);
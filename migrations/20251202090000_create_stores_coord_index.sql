@@ -0,0 +1,2 @@
+-- 근처 매장 조회 시 위도 / 경도 범위(bounding box) 사전 필터용
+CREATE INDEX IF NOT EXISTS idx_stores_coord ON stores (x_coord, y_coord) WHERE is_active;
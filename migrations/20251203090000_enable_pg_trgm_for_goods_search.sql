@@ -0,0 +1,7 @@
+-- 상품명 오타 / 부분 일치 검색(trigram 유사도)용
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_goods_good_name_trgm ON goods USING gin (good_name gin_trgm_ops);
+
+-- 상품 검색 결과의 인기도(사용자 선택 수) 집계용
+CREATE INDEX IF NOT EXISTS idx_user_selection_log_good_id ON user_selection_log (good_id);
@@ -0,0 +1,2 @@
+-- 보관 기간(RAW_PAYLOAD_RETENTION_DAYS)이 지난 원본 응답 정리용
+CREATE INDEX IF NOT EXISTS idx_raw_payloads_fetched_at ON raw_payloads (fetched_at);
@@ -0,0 +1,27 @@
+-- 초기 캐시 등록 시 도로명 주소가 없는 매장은 지번 주소를 접두어 없이 키로 사용했다.
+-- 지번 주소 검색 캐시 키("parcel:" 접두어)와 맞도록 해당 항목의 키를 바꾼다.
+-- (같은 문자열이 도로명 주소로도 쓰이는 키는 도로명 캐시로 보고 그대로 둔다)
+CREATE TEMP TABLE parcel_seed_keys ON COMMIT DROP AS
+SELECT DISTINCT regexp_replace(btrim(jibun_addr), '\s+', ' ', 'g') AS address_key
+FROM stores
+WHERE COALESCE(road_addr, '') = ''
+  AND btrim(COALESCE(jibun_addr, '')) <> ''
+EXCEPT
+SELECT regexp_replace(btrim(road_addr), '\s+', ' ', 'g')
+FROM stores
+WHERE COALESCE(road_addr, '') <> '';
+
+-- 이미 지번 주소 검색 결과가 캐시된 주소는 초기 등록 항목을 버린다.
+DELETE FROM geocode_cache g
+USING parcel_seed_keys p
+WHERE g.address_key = p.address_key
+  AND EXISTS (
+      SELECT 1
+      FROM geocode_cache c
+      WHERE c.address_key = 'parcel:' || p.address_key
+  );
+
+UPDATE geocode_cache g
+SET address_key = 'parcel:' || g.address_key
+FROM parcel_seed_keys p
+WHERE g.address_key = p.address_key;
@@ -0,0 +1,6 @@
+-- 실행 중인 동기화가 진행 상황을 기록할 때마다 갱신되는 생존 신호 시각
+-- 오래 갱신되지 않은 'running' 실행만 중단된 것으로 보고 정리한다.
+ALTER TABLE sync_runs
+ADD COLUMN heartbeat_at TIMESTAMP NOT NULL DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS idx_sync_runs_running_heartbeat ON sync_runs (heartbeat_at) WHERE status = 'running';
@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct AdminAuditLogEntity {
+    pub id: i32,
+    pub actor: Option<String>,
+    pub auth_method: Option<String>,
+    pub method: String,
+    pub path: String,
+    pub query: Option<String>,
+    pub status_code: i32,
+    pub allowed: bool,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
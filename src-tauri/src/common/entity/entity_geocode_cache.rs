@@ -0,0 +1,13 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct GeocodeCacheEntity {
+    pub address_key: String,
+    pub address: String,
+    pub status: String,
+    pub lat: Option<f64>,
+    pub lon: Option<f64>,
+    pub provider: String,
+    pub geocoded_at: NaiveDateTime,
+}
@@ -10,4 +10,17 @@ pub struct GoodEntity {
     pub total_div_code: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub is_active: bool,
+    pub last_seen_at: NaiveDateTime,
+    pub category_code: Option<String>,
+    pub unit_code: Option<String>,
+}
+
+#[derive(Debug, FromRow)]
+pub struct GoodSearchEntity {
+    pub good_id: String,
+    pub good_name: String,
+    pub category_code: Option<String>,
+    pub relevance: f64,
+    pub popularity: i64,
 }
@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct InspectDayEntity {
+    pub inspect_day: String,
+    pub status: String,
+    pub last_run_id: Option<i32>,
+    pub saved_prices: i32,
+    pub failed_stores: i32,
+    pub discovered_at: NaiveDateTime,
+    pub synced_at: Option<NaiveDateTime>,
+    pub updated_at: NaiveDateTime,
+}
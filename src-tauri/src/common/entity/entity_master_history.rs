@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct MasterHistoryEntity {
+    pub id: i32,
+    pub run_id: Option<i32>,
+    pub entity: String,
+    pub entity_id: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: NaiveDateTime,
+}
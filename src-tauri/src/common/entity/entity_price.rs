@@ -13,4 +13,28 @@ pub struct PriceEntity {
     pub discount_start: Option<String>,
     pub discount_end: Option<String>,
     pub created_at: NaiveDateTime,
-}
\ No newline at end of file
+}
+
+#[derive(Debug, FromRow)]
+pub struct StorePriceDistanceEntity {
+    pub store_id: String,
+    pub store_name: String,
+    pub x_coord: f64,
+    pub y_coord: f64,
+    pub price: i32,
+    pub inspect_day: String,
+    pub distance_km: f64,
+}
+
+#[derive(Debug, FromRow)]
+pub struct GoodStorePriceEntity {
+    pub good_id: String,
+    pub good_name: String,
+    pub store_id: String,
+    pub store_name: String,
+    pub x_coord: f64,
+    pub y_coord: f64,
+    pub price: i32,
+    pub inspect_day: String,
+    pub distance_km: f64,
+}
@@ -11,4 +11,7 @@ pub struct PriceChangeEntity {
     pub diff: i32,
     pub inspect_day: String,
     pub created_at: NaiveDateTime,
+    pub prev_inspect_day: Option<String>,
+    pub change_rate: Option<f64>,
+    pub updated_at: NaiveDateTime,
 }
\ No newline at end of file
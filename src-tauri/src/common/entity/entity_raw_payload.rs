@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct RawPayloadEntity {
+    pub id: i64,
+    pub upstream: String,
+    pub endpoint: String,
+    pub params: String,
+    pub http_status: Option<i32>,
+    pub content_hash: Option<String>,
+    pub body_gzip: Option<Vec<u8>>,
+    pub body_size: Option<i32>,
+    pub error: Option<String>,
+    pub fetched_at: NaiveDateTime,
+}
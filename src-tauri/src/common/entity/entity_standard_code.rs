@@ -0,0 +1,8 @@
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct StandardCodeEntity {
+    pub code: String,
+    pub name: String,
+    pub parent_code: Option<String>,
+}
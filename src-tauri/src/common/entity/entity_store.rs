@@ -16,4 +16,18 @@ pub struct StoreEntity {
     pub updated_at: NaiveDateTime,
     pub area_code: String,
     pub area_detail_code: String,
+    pub geocode_status: String,
+    pub is_active: bool,
+    pub last_seen_at: NaiveDateTime,
+    pub store_type_code: Option<String>,
+    /// 주소 검색 요청이 실패하여 기존 좌표와 좌표 변환 상태를 유지해야 하는 행 (저장 시에만 사용)
+    #[sqlx(skip)]
+    pub keep_previous_coords: bool,
+}
+
+#[derive(Debug, FromRow)]
+pub struct StoreDistanceEntity {
+    #[sqlx(flatten)]
+    pub store: StoreEntity,
+    pub distance_km: f64,
 }
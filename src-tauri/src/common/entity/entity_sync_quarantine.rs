@@ -0,0 +1,14 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct SyncQuarantineEntity {
+    pub id: i32,
+    pub run_id: Option<i32>,
+    pub entity: String,
+    pub record_key: String,
+    pub reason: String,
+    pub detail: Option<String>,
+    pub payload: String,
+    pub created_at: NaiveDateTime,
+}
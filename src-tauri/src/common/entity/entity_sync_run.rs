@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+
+#[derive(Debug, FromRow)]
+pub struct SyncRunEntity {
+    pub id: i32,
+    pub kind: String,
+    pub params: String,
+    pub status: String,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub success_count: i32,
+    pub failure_count: i32,
+    pub skipped_count: i32,
+    pub summary: Option<String>,
+    pub error: Option<String>,
+}
@@ -9,4 +9,5 @@ pub struct UserEntity {
     pub name: String,
     pub created_at: NaiveDateTime,
     pub last_login: NaiveDateTime,
+    pub role: String,
 }
\ No newline at end of file
@@ -1,8 +1,16 @@
+pub mod entity_admin_audit_log;
+pub mod entity_geocode_cache;
 pub mod entity_good;
+pub mod entity_inspect_day;
+pub mod entity_master_history;
 pub mod entity_price_change;
 pub mod entity_price;
+pub mod entity_raw_payload;
 pub mod entity_region;
+pub mod entity_standard_code;
 pub mod entity_store;
+pub mod entity_sync_quarantine;
+pub mod entity_sync_run;
 pub mod entity_user_preference;
 pub mod entity_user_selection_log;
 pub mod entity_user;
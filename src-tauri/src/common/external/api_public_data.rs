@@ -5,83 +5,73 @@
  *
  * Reponsibilities:
  *     1) fetch_goods_api()            
- *         - 상품 정보 조회
+ *         - 상품 정보 조회 (페이지 단위)
  * 
  *     2) fetch_store_api()            
- *         - 매장 정보 조회
+ *         - 매장 정보 조회 (페이지 단위)
  * 
  *     3) fetch_prices_api()           
  *         - 특정 날짜.매장 기준 가격 정보 조회
  * 
- *     4) fetch_region_codes_api()     
- *         - 지역 코드 정보 조회
+ *     4) fetch_standard_codes_api()     
+ *         - 분류(classCode)별 표준 코드 정보 조회 (페이지 단위)
+ *         - 지역(AR) / 상품분류(AL) / 단위(UT) / 업태(CT)
+ *
+ *     모든 요청은 공용 HTTP 클라이언트(http_client)를 통해
+ *     타임아웃 / 재시도 / 서킷 브레이커가 적용된다.
 ************************************************************************/
 
-use reqwest::{header::USER_AGENT, Client};
-
-use crate::config::env::get_env_value;
+use crate::{
+    common::external::http_client::{get_text, HttpError, HttpResponse, Upstream},
+    config::env::get_env_value,
+};
 
-/// 공공데이터포털에서 상품 정보를 조회한다.
+/// 공공데이터포털에서 상품 정보를 한 페이지 조회한다.
+///
+/// # Arguments
+/// * `page_no`       - 페이지 번호 (1부터 시작)
+/// * `num_of_rows`   - 페이지당 요청 건수
 ///
 /// # Returns
-/// * `Ok(String)`  - API 응답 본문
-/// * `Err(String)` - 요청 실패 또는 본문 파싱 실패
+/// * `Ok(HttpResponse)`    - API 응답 상태 코드와 본문
+/// * `Err(HttpError)`      - 요청 실패 혹은 본문 읽기 실패
 pub async fn fetch_goods_api(
-) -> Result<String, String> {
+    page_no: u32,
+    num_of_rows: u32,
+) -> Result<HttpResponse, HttpError> {
     let service_key = get_env_value("PUBLIC_API_KEY");
     let url = format!(
-        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getProductInfoSvc.do?serviceKey={}",
-        service_key
+        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getProductInfoSvc.do?pageNo={}&numOfRows={}&serviceKey={}",
+        page_no, num_of_rows, service_key
     );
 
-    let client = Client::new();
-    let res = client
-        .get(&url)
-        .header(USER_AGENT, "StoreRader/1.0")
-        .send()
-        .await
-        .map_err(|e| format!("상품 API 요청 실패: {}", e))?;
-
-    if !res.status().is_success() {
-        return Err(format!("상품 API 오류 상태: {}", res.status()));
-    }
-
-    let response_body = res.text()
+    get_text(Upstream::PublicData, &url)
         .await
-        .map_err(|e| format!("상품 본문 읽기 실패: {}", e))?;
-
-    Ok(response_body)
+        .map_err(|e| e.context("상품 API 요청 실패"))
 }
 
-/// 공공데이터포털에서 매장 정보를 조회한다.
+/// 공공데이터포털에서 매장 정보를 한 페이지 조회한다.
+///
+/// # Arguments
+/// * `page_no`       - 페이지 번호 (1부터 시작)
+/// * `num_of_rows`   - 페이지당 요청 건수
 ///
 /// # Returns
-/// * `Ok(String)`  - API 응답 본문
-/// * `Err(String)` - 요청 실패 혹은 본문 읽기 실패
-pub async fn fetch_stores_api() -> Result<String, String> {
+/// * `Ok(HttpResponse)`    - API 응답 상태 코드와 본문
+/// * `Err(HttpError)`      - 요청 실패 혹은 본문 읽기 실패
+pub async fn fetch_stores_api(
+    page_no: u32,
+    num_of_rows: u32,
+) -> Result<HttpResponse, HttpError> {
     let service_key = get_env_value("PUBLIC_API_KEY");
     let url = format!(
-        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getStoreInfoSvc.do?serviceKey={}",
-        service_key
+        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getStoreInfoSvc.do?pageNo={}&numOfRows={}&serviceKey={}",
+        page_no, num_of_rows, service_key
     );
 
-    let client = Client::new();
-    let res = client
-        .get(&url)
-        .header(USER_AGENT, "StoreRader/1.0")
-        .send()
-        .await
-        .map_err(|e| format!("매장 API 요청 실패: {}", e))?;
-
-    if !res.status().is_success() {
-        return Err(format!("매장 API 오류 상태: {}", res.status()));
-    }
-
-    let response_body = res.text()
+    get_text(Upstream::PublicData, &url)
         .await
-        .map_err(|e| format!("매장 본문 읽기 실패: {}", e))?;
-
-    Ok(response_body)
+        .map_err(|e| e.context("매장 API 요청 실패"))
 }
 
 /// 특정 날짜와 매장을 기준으로 가격 정보를 조회한다.
@@ -91,64 +81,45 @@ pub async fn fetch_stores_api() -> Result<String, String> {
 /// * `store_id` - 매장 ID
 ///
 /// # Returns
-/// * `Ok(String)`  - API 응답 본문
-/// * `Err(String)` - 요청 실패 혹은 본문 읽기 실패
+/// * `Ok(HttpResponse)`    - API 응답 상태 코드와 본문
+/// * `Err(HttpError)`      - 요청 실패 혹은 본문 읽기 실패
 pub async fn fetch_prices_api(
     inspect_day: &str, 
     store_id: &str
-) -> Result<String, String> {
+) -> Result<HttpResponse, HttpError> {
     let service_key = get_env_value("PUBLIC_API_KEY");
     let url = format!(
         "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getProductPriceInfoSvc.do?goodInspectDay={}&entpId={}&ServiceKey={}",
         inspect_day, store_id, service_key
     );
 
-    let client = Client::new();
-    let res = client
-        .get(&url)
-        .header(USER_AGENT, "StoreRader/1.0")
-        .send()
-        .await
-        .map_err(|e| format!("가격 API 요청 실패: {}", e))?;
-
-    if !res.status().is_success() {
-        return Err(format!("가격 API 오류 상태: {}", res.status()));
-    }
-
-    let response_body = res.text()
+    get_text(Upstream::PublicData, &url)
         .await
-        .map_err(|e| format!("가격 본문 읽기 실패: {}", e))?;
-
-    Ok(response_body)
+        .map_err(|e| e.context("가격 API 요청 실패"))
 }
 
-/// 공공데이터포털에서 지역 코드 정보를 조회한다.
+/// 공공데이터포털에서 분류별 표준 코드 정보를 한 페이지 조회한다.
+///
+/// # Arguments
+/// * `class_code`    - 표준코드 분류 (AR: 지역, AL: 상품분류, UT: 단위, CT: 업태)
+/// * `page_no`       - 페이지 번호 (1부터 시작)
+/// * `num_of_rows`   - 페이지당 요청 건수
 ///
 /// # Returns
-/// * `Ok(String)`  - API 응답 본문
-/// * `Err(String)` - 요청 실패 혹은 본문 읽기 실패
-pub async fn fetch_region_codes_api() -> Result<String, String> {
+/// * `Ok(HttpResponse)`    - API 응답 상태 코드와 본문
+/// * `Err(HttpError)`      - 요청 실패 혹은 본문 읽기 실패
+pub async fn fetch_standard_codes_api(
+    class_code: &str,
+    page_no: u32,
+    num_of_rows: u32,
+) -> Result<HttpResponse, HttpError> {
     let service_key = get_env_value("PUBLIC_API_KEY");
     let url = format!(
-        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getStandardInfoSvc.do?classCode=AR&ServiceKey={}",
-        service_key
+        "http://openapi.price.go.kr/openApiImpl/ProductPriceInfoService/getStandardInfoSvc.do?classCode={}&pageNo={}&numOfRows={}&ServiceKey={}",
+        class_code, page_no, num_of_rows, service_key
     );
 
-    let client = Client::new();
-    let res = client
-        .get(&url)
-        .header(USER_AGENT, "StoreRader/1.0")
-        .send()
-        .await
-        .map_err(|e| format!("지역코드 API 요청 실패: {}", e))?;
-
-    if !res.status().is_success() {
-        return Err(format!("지역코드 API 오류 상태: {}", res.status()));
-    }
-
-    let respond_body = res.text()
+    get_text(Upstream::PublicData, &url)
         .await
-        .map_err(|e| format!("지역코드 본문 읽기 실패: {}", e))?;
-
-    Ok(respond_body)
+        .map_err(|e| e.context(&format!("표준코드({}) API 요청 실패", class_code)))
 }
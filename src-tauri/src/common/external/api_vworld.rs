@@ -4,69 +4,137 @@
  *     vWorld API를 이용해 주소 문자열을 위도.경도로 변환한다.
  *
  * Reponsibilities:
- *     1) geocode_with_vworld()
- *         - vWorld 주소 검색 API 호출
- *         - 위도.경도 값 반환
+ *     1) fetch_vworld_address()
+ *         - vWorld 주소 검색 API 호출 (도로명 / 지번 구분)
+ *         - 원본 응답(JSON) 반환
+ * 
+ *     2) parse_vworld_point()
+ *         - 원본 응답에서 위도.경도 값 추출
+ *         - NOT_FOUND만 결과 없음, 오류 / 알 수 없는 상태는 에러로 구분
 ************************************************************************/
 
-use reqwest::{Client, header::USER_AGENT};
 use serde_json::Value;
 use std::env;
 
-/// vWorld API를 이용해 주소 문자열을 위도.경도로 변환한다.
+use crate::common::external::http_client::{get_text, HttpError, HttpResponse, Upstream};
+
+/// vWorld 주소 검색 유형
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddressType {
+    /// 도로명 주소 (type=road)
+    Road,
+    /// 지번 주소 (type=parcel)
+    Parcel,
+}
+
+impl AddressType {
+    /// vWorld 요청의 type 파라미터 값을 반환한다.
+    pub fn as_param(&self) -> &'static str {
+        match self {
+            AddressType::Road => "road",
+            AddressType::Parcel => "parcel",
+        }
+    }
+}
+
+/// vWorld 주소 검색 API를 호출하여 원본 응답을 반환한다.
 /// 
 /// # Arguments
-/// * `addr` - 좌표를 구할 도로명 주소 또는 지번 주소
+/// * `addr`            - 좌표를 구할 주소
+/// * `address_type`    - 주소 검색 유형 (도로명 / 지번)
 /// 
 /// # Returns
-/// * `Ok(Some((lat, lon)))`    - 변환 성공
-/// * `OK(None)`                - 결과 없음
-/// * `Err(String)`             - 요청 또는 파싱 오류
-pub async fn geocode_with_vworld(
-    addr: &str
-) -> Result<Option<(f64, f64)>, String> {
+/// * `Ok(HttpResponse)`    - API 응답 상태 코드와 본문(JSON)
+/// * `Err(HttpError)`      - 요청 실패
+pub async fn fetch_vworld_address(
+    addr: &str,
+    address_type: AddressType,
+) -> Result<HttpResponse, HttpError> {
     let api_key = env::var("VWORLD_API_KEY")
-        .map_err(|_| "VWORLD_API_KEY 환경 변수를 찾을 수 없습니다.".to_string())?;
+        .map_err(|_| HttpError::from("VWORLD_API_KEY 환경 변수를 찾을 수 없습니다.".to_string()))?;
 
     let url = format!(
-        "https://api.vworld.kr/req/address?service=address&request=getCoord&version=2.0&crs=epsg:4326&address={}&refine=true&simple=false&type=road&key={}",
+        "https://api.vworld.kr/req/address?service=address&request=getCoord&version=2.0&crs=epsg:4326&address={}&refine=true&simple=false&type={}&key={}",
         urlencoding::encode(addr),
+        address_type.as_param(),
         api_key
     );
 
-    let client = Client::new();
-    let res = client
-        .get(&url)
-        .header(USER_AGENT, "StoreRader/1.0")
-        .send()
+    get_text(Upstream::VWorld, &url)
         .await
-        .map_err(|e| format!("vWorld 요청 실패: {}", e))?;
+        .map_err(|e| e.context("vWorld 요청 실패"))
+}
 
-    let text = res
-        .text()
-        .await
-        .map_err(|e| format!("본문 읽기 실패: {}", e))?;
-
-    let json: Value = serde_json::from_str(&text).map_err(|e| format!("JSON 파싱 실패: {}", e))?;
-
-    if let Some(status) = json["response"]["status"].as_str() {
-        if status == "OK" {
-            if let Some(point) = json["response"]["result"]["point"].as_object() {
-                let x = point["x"]
-                    .as_str()
-                    .unwrap_or("0")
-                    .parse::<f64>()
-                    .unwrap_or(0.0);
-                let y = point["y"]
-                    .as_str()
-                    .unwrap_or("0")
-                    .parse::<f64>()
-                    .unwrap_or(0.0);
-                // vWorld 응답 구조는 x=경도, y=위도라서 순서를 바꿔 반환한다.
-                return Ok(Some((y, x)));
-            }
+/// vWorld 주소 검색 응답에서 위도.경도를 추출한다.
+/// 결과 없음은 응답 상태가 NOT_FOUND일 때뿐이며, ERROR(호출 한도 초과, 인증키 오류, 서버 오류 등)나
+/// 알 수 없는 상태는 요청 실패로 보고 에러로 반환하여 결과 없음으로 캐시되지 않게 한다.
+/// 
+/// # Arguments
+/// * `text` - vWorld 주소 검색 API 응답 본문(JSON)
+/// 
+/// # Returns
+/// * `Ok(Some((lat, lon)))`    - 변환 성공
+/// * `OK(None)`                - 결과 없음 (NOT_FOUND)
+/// * `Err(String)`             - 파싱 오류, 오류 응답 또는 알 수 없는 상태
+pub fn parse_vworld_point(
+    text: &str
+) -> Result<Option<(f64, f64)>, String> {
+    let json: Value = serde_json::from_str(text).map_err(|e| format!("JSON 파싱 실패: {}", e))?;
+    let response = &json["response"];
+
+    match response["status"].as_str() {
+        Some("OK") => {}
+        Some("NOT_FOUND") => return Ok(None),
+        Some("ERROR") => {
+            let error = &response["error"];
+            return Err(format!(
+                "vWorld 오류 응답 ({}): {}",
+                error["code"].as_str().unwrap_or("-"),
+                error["text"].as_str().unwrap_or("-")
+            ));
         }
+        Some(status) => return Err(format!("알 수 없는 vWorld 응답 상태: {}", status)),
+        None => return Err("vWorld 응답 상태 없음".to_string()),
+    }
+
+    let point = &response["result"]["point"];
+    let coord = |key: &str| point[key].as_str().and_then(|v| v.trim().parse::<f64>().ok());
+    match (coord("x"), coord("y")) {
+        // vWorld 응답 구조는 x=경도, y=위도라서 순서를 바꿔 반환한다.
+        (Some(x), Some(y)) => Ok(Some((y, x))),
+        _ => Err(format!("vWorld 좌표 형식 오류: {}", point)),
     }
+}
 
-    Ok(None)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ok_response_returns_lat_lon() {
+        let body = r#"{"response":{"status":"OK","result":{"point":{"x":"126.9779","y":"37.5663"}}}}"#;
+        assert_eq!(parse_vworld_point(body), Ok(Some((37.5663, 126.9779))));
+    }
+
+    #[test]
+    fn only_not_found_is_no_result() {
+        assert_eq!(parse_vworld_point(r#"{"response":{"status":"NOT_FOUND"}}"#), Ok(None));
+
+        let error = r#"{"response":{"status":"ERROR","error":{"code":"OVER_REQUEST_LIMIT","text":"일일 호출 한도 초과"}}}"#;
+        let err = parse_vworld_point(error).unwrap_err();
+        assert!(err.contains("OVER_REQUEST_LIMIT"), "{}", err);
+
+        assert!(parse_vworld_point(r#"{"response":{"status":"MAINTENANCE"}}"#).is_err());
+        assert!(parse_vworld_point(r#"{"response":{}}"#).is_err());
+        assert!(parse_vworld_point("<html>502 Bad Gateway</html>").is_err());
+    }
+
+    #[test]
+    fn malformed_point_is_an_error() {
+        let missing = r#"{"response":{"status":"OK","result":{}}}"#;
+        assert!(parse_vworld_point(missing).is_err());
+
+        let non_numeric = r#"{"response":{"status":"OK","result":{"point":{"x":"abc","y":"37.5"}}}}"#;
+        assert!(parse_vworld_point(non_numeric).is_err());
+    }
 }
@@ -0,0 +1,281 @@
+/************************************************************************
+ * File: common/external/archive.rs
+ * Description:
+ *     업스트림 원본 응답을 raw_payloads 테이블에 보관하고,
+ *     보관된 응답만으로 동기화를 다시 실행할 수 있는 데이터 소스 구현.
+ *     응답 본문은 gzip으로 압축하고 SHA-256 해시를 함께 저장한다.
+ *
+ * Reponsibilities:
+ *     1) content_hash()
+ *         - 응답 본문의 SHA-256 해시 (파싱 오류 메시지와 보관 응답을 연결)
+ * 
+ *     2) ArchivingSource
+ *         - 내부 데이터 소스를 호출하고 응답(또는 오류)을 보관
+ * 
+ *     3) ArchiveSource
+ *         - 보관된 응답 반환 (업스트림 호출 없음, 재처리용)
+************************************************************************/
+
+use async_trait::async_trait;
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+use std::{
+    io::{Read, Write},
+    sync::Arc,
+};
+
+use crate::common::{
+    entity::entity_raw_payload::RawPayloadEntity,
+    external::{
+        api_vworld::AddressType,
+        data_source::{Geocoder, PriceDataSource},
+        http_client::{HttpError, HttpResponse},
+    },
+    repository::repository_raw_payload::{find_latest_raw_payload, insert_raw_payload},
+};
+
+/// 응답 본문의 SHA-256 해시를 16진수 문자열로 반환한다.
+/// 
+/// # Arguments
+/// * `body` - 원본 응답
+/// 
+/// # Returns
+/// * `String` - 64자리 16진수 해시
+pub fn content_hash(
+    body: &str
+) -> String {
+    hex::encode(Sha256::digest(body.as_bytes()))
+}
+
+/// 응답 본문을 gzip으로 압축한다.
+fn compress(body: &str) -> Result<Vec<u8>, String> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(body.as_bytes())
+        .map_err(|e| format!("응답 압축 실패: {}", e))?;
+    encoder.finish().map_err(|e| format!("응답 압축 실패: {}", e))
+}
+
+/// gzip으로 압축된 응답 본문을 복원한다.
+fn decompress(body_gzip: &[u8]) -> Result<String, String> {
+    let mut body = String::new();
+    GzDecoder::new(body_gzip)
+        .read_to_string(&mut body)
+        .map_err(|e| format!("응답 압축 해제 실패: {}", e))?;
+    Ok(body)
+}
+
+/// 주소 검색 요청 파라미터
+fn geocode_params(addr: &str, address_type: AddressType) -> Value {
+    json!({ "address": addr, "type": address_type.as_param() })
+}
+
+/// 목록 API 페이지 요청 파라미터
+fn page_params(page_no: u32, num_of_rows: u32) -> Value {
+    json!({ "page_no": page_no, "num_of_rows": num_of_rows })
+}
+
+/// 표준코드 API 페이지 요청 파라미터
+fn standard_code_params(class_code: &str, page_no: u32, num_of_rows: u32) -> Value {
+    json!({ "class_code": class_code, "page_no": page_no, "num_of_rows": num_of_rows })
+}
+
+/// 내부 데이터 소스를 호출하면서 원본 응답을 raw_payloads에 보관하는 데이터 소스
+///
+/// # Fields
+/// * `prices`      - 내부 가격 정보 데이터 소스
+/// * `geocoder`    - 내부 좌표 변환 데이터 소스
+/// * `pool`        - DB 커넥션 풀
+pub struct ArchivingSource {
+    prices: Arc<dyn PriceDataSource>,
+    geocoder: Arc<dyn Geocoder>,
+    pool: PgPool,
+}
+
+impl ArchivingSource {
+    /// 내부 데이터 소스와 DB 커넥션 풀을 지정하여 생성한다.
+    pub fn new(
+        prices: Arc<dyn PriceDataSource>,
+        geocoder: Arc<dyn Geocoder>,
+        pool: PgPool,
+    ) -> Self {
+        Self {
+            prices,
+            geocoder,
+            pool,
+        }
+    }
+
+    /// 요청 결과를 보관한다. 보관 실패는 동기화를 중단시키지 않는다.
+    ///
+    /// 정상 응답은 실제 상태 코드와 본문을, 실패한 요청은 마지막으로 받은
+    /// 상태 코드(응답을 받지 못했으면 없음)와 오류 메시지를 기록한다.
+    ///
+    /// # Arguments
+    /// * `upstream`    - 업스트림 이름 (public_data, vworld)
+    /// * `endpoint`    - 요청 종류
+    /// * `params`      - 요청 파라미터
+    /// * `result`      - 요청 결과
+    async fn archive(
+        &self,
+        upstream: &str,
+        endpoint: &str,
+        params: Value,
+        result: &Result<HttpResponse, HttpError>,
+    ) {
+        let mut payload = RawPayloadEntity {
+            id: 0,
+            upstream: upstream.to_string(),
+            endpoint: endpoint.to_string(),
+            params: params.to_string(),
+            http_status: None,
+            content_hash: None,
+            body_gzip: None,
+            body_size: None,
+            error: None,
+            fetched_at: chrono::Utc::now().naive_utc(),
+        };
+
+        match result {
+            Ok(res) => match compress(&res.body) {
+                Ok(body_gzip) => {
+                    payload.http_status = Some(res.status as i32);
+                    payload.content_hash = Some(content_hash(&res.body));
+                    payload.body_gzip = Some(body_gzip);
+                    payload.body_size = Some(res.body.len() as i32);
+                }
+                Err(e) => {
+                    tracing::warn!("{}", e);
+                    return;
+                }
+            },
+            Err(e) => {
+                payload.http_status = e.status.map(i32::from);
+                payload.error = Some(e.message.clone());
+            }
+        }
+
+        if let Err(e) = insert_raw_payload(&self.pool, &payload).await {
+            tracing::warn!("{}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl PriceDataSource for ArchivingSource {
+    async fn fetch_goods(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        let result = self.prices.fetch_goods(page_no, num_of_rows).await;
+        self.archive("public_data", "goods", page_params(page_no, num_of_rows), &result)
+            .await;
+        result
+    }
+
+    async fn fetch_stores(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        let result = self.prices.fetch_stores(page_no, num_of_rows).await;
+        self.archive("public_data", "stores", page_params(page_no, num_of_rows), &result)
+            .await;
+        result
+    }
+
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError> {
+        let result = self.prices.fetch_prices(inspect_day, store_id).await;
+        let params = json!({ "inspect_day": inspect_day, "store_id": store_id });
+        self.archive("public_data", "prices", params, &result).await;
+        result
+    }
+
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError> {
+        let result = self
+            .prices
+            .fetch_standard_codes(class_code, page_no, num_of_rows)
+            .await;
+        let params = standard_code_params(class_code, page_no, num_of_rows);
+        self.archive("public_data", "standard_codes", params, &result).await;
+        result
+    }
+}
+
+#[async_trait]
+impl Geocoder for ArchivingSource {
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError> {
+        let result = self.geocoder.fetch_address(addr, address_type).await;
+        self.archive("vworld", "geocode", geocode_params(addr, address_type), &result)
+            .await;
+        result
+    }
+}
+
+/// raw_payloads에 보관된 응답만 반환하는 데이터 소스 (업스트림을 호출하지 않음)
+///
+/// # Fields
+/// * `pool` - DB 커넥션 풀
+pub struct ArchiveSource {
+    pool: PgPool,
+}
+
+impl ArchiveSource {
+    /// DB 커넥션 풀을 지정하여 생성한다.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 요청 종류.파라미터가 같은 가장 최근 보관 응답을 복원한다.
+    ///
+    /// # Arguments
+    /// * `endpoint`    - 요청 종류
+    /// * `params`      - 요청 파라미터
+    ///
+    /// # Returns
+    /// * `Ok(HttpResponse)`    - 보관된 상태 코드와 원본 응답
+    /// * `Err(HttpError)`      - 보관된 응답 없음 또는 복원 실패
+    async fn load(&self, endpoint: &str, params: Value) -> Result<HttpResponse, HttpError> {
+        let payload = find_latest_raw_payload(&self.pool, endpoint, &params.to_string())
+            .await?
+            .ok_or_else(|| format!("보관된 응답 없음 ({} {})", endpoint, params))?;
+
+        Ok(HttpResponse {
+            status: payload.http_status.and_then(|s| u16::try_from(s).ok()).unwrap_or(200),
+            body: decompress(payload.body_gzip.as_deref().unwrap_or_default())?,
+        })
+    }
+}
+
+#[async_trait]
+impl PriceDataSource for ArchiveSource {
+    async fn fetch_goods(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        self.load("goods", page_params(page_no, num_of_rows)).await
+    }
+
+    async fn fetch_stores(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        self.load("stores", page_params(page_no, num_of_rows)).await
+    }
+
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError> {
+        self.load("prices", json!({ "inspect_day": inspect_day, "store_id": store_id }))
+            .await
+    }
+
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError> {
+        self.load("standard_codes", standard_code_params(class_code, page_no, num_of_rows))
+            .await
+    }
+}
+
+#[async_trait]
+impl Geocoder for ArchiveSource {
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError> {
+        self.load("geocode", geocode_params(addr, address_type)).await
+    }
+}
@@ -0,0 +1,198 @@
+/************************************************************************
+ * File: common/external/data_source.rs
+ * Description:
+ *     동기화 서비스가 사용하는 업스트림 데이터 소스 추상화.
+ *     실제 API 호출, 응답 녹화(record), 녹화 응답 재생(replay) 구현을
+ *     같은 트레이트로 교체해 사용할 수 있도록 한다.
+ *
+ * Reponsibilities:
+ *     1) PriceDataSource
+ *         - 상품 / 매장 / 가격 / 표준코드 원본 응답(XML)과 HTTP 상태 코드 조회
+ *         - 상품 / 매장 / 표준코드는 페이지 단위로 조회
+ * 
+ *     2) Geocoder
+ *         - 주소 검색 원본 응답(JSON) 조회 및 위도.경도 변환 (도로명 / 지번)
+ * 
+ *     3) LiveSource
+ *         - 공공데이터포털 / vWorld API를 실제로 호출하는 구현
+ * 
+ *     4) build_data_sources()
+ *         - DATA_SOURCE_MODE 설정에 맞는 구현을 생성
+ *         - 실제 API 응답을 raw_payloads에 보관 (RAW_PAYLOAD_ARCHIVE=false 로 끌 수 있음)
+************************************************************************/
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use std::sync::Arc;
+
+use crate::{
+    common::external::{
+        api_public_data::{
+            fetch_goods_api, fetch_prices_api, fetch_standard_codes_api, fetch_stores_api,
+        },
+        api_vworld::{fetch_vworld_address, parse_vworld_point, AddressType},
+        archive::ArchivingSource,
+        fixture::{FixtureStore, RecordingSource, ReplaySource},
+        http_client::{HttpError, HttpResponse},
+    },
+    config::data_source::{DataSourceConfig, DataSourceMode},
+};
+
+/// 한국소비자원 가격 정보 데이터 소스
+#[async_trait]
+pub trait PriceDataSource: Send + Sync {
+    /// 상품 정보 원본 응답을 한 페이지 조회한다.
+    async fn fetch_goods(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError>;
+
+    /// 매장 정보 원본 응답을 한 페이지 조회한다.
+    async fn fetch_stores(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError>;
+
+    /// 특정 조사일.매장의 가격 정보 원본 응답을 조회한다.
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError>;
+
+    /// 분류(classCode)별 표준 코드 원본 응답을 한 페이지 조회한다.
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError>;
+}
+
+/// 주소 → 좌표 변환 데이터 소스
+#[async_trait]
+pub trait Geocoder: Send + Sync {
+    /// 주소 검색 원본 응답을 조회한다.
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError>;
+
+    /// 주소 문자열을 위도.경도로 변환한다.
+    ///
+    /// # Arguments
+    /// * `addr`            - 좌표를 구할 주소
+    /// * `address_type`    - 주소 검색 유형 (도로명 / 지번)
+    ///
+    /// # Returns
+    /// * `Ok(Some((lat, lon)))`    - 변환 성공
+    /// * `Ok(None)`                - 결과 없음
+    /// * `Err(String)`             - 요청 또는 파싱 오류
+    async fn geocode(
+        &self,
+        addr: &str,
+        address_type: AddressType,
+    ) -> Result<Option<(f64, f64)>, String> {
+        let res = self.fetch_address(addr, address_type).await?;
+        parse_vworld_point(&res.body)
+    }
+}
+
+/// 동기화에 사용하는 데이터 소스 묶음
+///
+/// # Fields
+/// * `prices`      - 가격 정보 데이터 소스
+/// * `geocoder`    - 주소 → 좌표 변환 데이터 소스
+#[derive(Clone)]
+pub struct DataSources {
+    pub prices: Arc<dyn PriceDataSource>,
+    pub geocoder: Arc<dyn Geocoder>,
+}
+
+/// 공공데이터포털 / vWorld API를 실제로 호출하는 데이터 소스
+pub struct LiveSource;
+
+#[async_trait]
+impl PriceDataSource for LiveSource {
+    async fn fetch_goods(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        fetch_goods_api(page_no, num_of_rows).await
+    }
+
+    async fn fetch_stores(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        fetch_stores_api(page_no, num_of_rows).await
+    }
+
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError> {
+        fetch_prices_api(inspect_day, store_id).await
+    }
+
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError> {
+        fetch_standard_codes_api(class_code, page_no, num_of_rows).await
+    }
+}
+
+#[async_trait]
+impl Geocoder for LiveSource {
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError> {
+        fetch_vworld_address(addr, address_type).await
+    }
+}
+
+/// 설정된 모드에 맞는 데이터 소스를 생성한다.
+///
+/// # Arguments
+/// * `config`  - 데이터 소스 설정
+/// * `pool`    - DB 커넥션 풀 (원본 응답 보관용)
+///
+/// # Returns
+/// * `DataSources` - 가격 정보 / 좌표 변환 데이터 소스
+pub fn build_data_sources(config: &DataSourceConfig, pool: &PgPool) -> DataSources {
+    match config.mode {
+        DataSourceMode::Live => live_sources(config, pool),
+        DataSourceMode::Record => {
+            tracing::info!("데이터 소스: record ({})", config.dir.display());
+            let live = live_sources(config, pool);
+            let recording = Arc::new(RecordingSource::new(
+                live.prices,
+                live.geocoder,
+                FixtureStore::new(&config.dir),
+            ));
+            DataSources {
+                prices: recording.clone(),
+                geocoder: recording,
+            }
+        }
+        DataSourceMode::Replay => {
+            tracing::info!("데이터 소스: replay ({})", config.dir.display());
+            let replay = Arc::new(ReplaySource::new(
+                FixtureStore::new(&config.dir),
+                config.replay_missing_prices_empty,
+            ));
+            DataSources {
+                prices: replay.clone(),
+                geocoder: replay,
+            }
+        }
+    }
+}
+
+/// 실제 API를 호출하는 데이터 소스를 생성한다.
+/// 원본 응답 보관이 켜져 있으면 보관 데이터 소스로 감싼다.
+///
+/// # Arguments
+/// * `config`  - 데이터 소스 설정
+/// * `pool`    - DB 커넥션 풀 (원본 응답 보관용)
+///
+/// # Returns
+/// * `DataSources` - 가격 정보 / 좌표 변환 데이터 소스
+fn live_sources(config: &DataSourceConfig, pool: &PgPool) -> DataSources {
+    if !config.archive {
+        let live = Arc::new(LiveSource);
+        return DataSources {
+            prices: live.clone(),
+            geocoder: live,
+        };
+    }
+
+    let archiving = Arc::new(ArchivingSource::new(
+        Arc::new(LiveSource),
+        Arc::new(LiveSource),
+        pool.clone(),
+    ));
+    DataSources {
+        prices: archiving.clone(),
+        geocoder: archiving,
+    }
+}
@@ -0,0 +1,282 @@
+/************************************************************************
+ * File: common/external/fixture.rs
+ * Description:
+ *     업스트림 원본 응답을 디스크에 녹화하고 재생하는 데이터 소스 구현.
+ *     녹화된 응답만으로 전체 동기화 파이프라인을
+ *     네트워크와 API 키 없이 실행할 수 있도록 한다.
+ *
+ * Reponsibilities:
+ *     1) FixtureStore
+ *         - 요청 종류.키별 응답 파일 경로 결정, 저장 및 로드
+ *         - 파일 구조: {dir}/goods/{page_no}.xml, stores/{page_no}.xml,
+ *                      standard_codes/{class_code}/{page_no}.xml,
+ *                      prices/{inspect_day}_{store_id}.xml,
+ *                      geocode/{sha256(주소)}.json (도로명),
+ *                      geocode/parcel_{sha256(주소)}.json (지번)
+ * 
+ *     2) RecordingSource
+ *         - 내부 데이터 소스를 호출하고 응답을 저장
+ * 
+ *     3) ReplaySource
+ *         - 저장된 응답 반환 (없으면 에러)
+ *         - 녹화되지 않은 가격 응답은 옵션을 켠 경우에만 빈 응답으로 반환
+************************************************************************/
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use crate::common::external::{
+    api_vworld::AddressType,
+    data_source::{Geocoder, PriceDataSource},
+    http_client::{HttpError, HttpResponse},
+};
+
+/// 녹화된 응답에 붙이는 HTTP 상태 코드 (녹화는 성공 응답만 저장한다)
+const REPLAY_STATUS: u16 = 200;
+
+/// 원본 응답 파일 저장소
+///
+/// # Fields
+/// * `dir` - 저장 경로
+pub struct FixtureStore {
+    dir: PathBuf,
+}
+
+impl FixtureStore {
+    /// 저장 경로를 지정하여 생성한다.
+    pub fn new(dir: &Path) -> Self {
+        Self {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    /// 페이지별 상품 정보 응답 파일 경로
+    fn goods_path(&self, page_no: u32) -> PathBuf {
+        self.dir.join("goods").join(format!("{}.xml", page_no))
+    }
+
+    /// 페이지별 매장 정보 응답 파일 경로
+    fn stores_path(&self, page_no: u32) -> PathBuf {
+        self.dir.join("stores").join(format!("{}.xml", page_no))
+    }
+
+    /// 분류.페이지별 표준 코드 응답 파일 경로
+    fn standard_codes_path(&self, class_code: &str, page_no: u32) -> PathBuf {
+        self.dir
+            .join("standard_codes")
+            .join(class_code)
+            .join(format!("{}.xml", page_no))
+    }
+
+    /// 조사일.매장별 가격 정보 응답 파일 경로
+    fn prices_path(&self, inspect_day: &str, store_id: &str) -> PathBuf {
+        self.dir
+            .join("prices")
+            .join(format!("{}_{}.xml", inspect_day, store_id))
+    }
+
+    /// 주소별 주소 검색 응답 파일 경로 (주소는 SHA-256 해시로 파일명 생성)
+    fn geocode_path(&self, addr: &str, address_type: AddressType) -> PathBuf {
+        let hash = hex::encode(Sha256::digest(addr.as_bytes()));
+        let file_name = match address_type {
+            AddressType::Road => format!("{}.json", hash),
+            AddressType::Parcel => format!("parcel_{}.json", hash),
+        };
+        self.dir.join("geocode").join(file_name)
+    }
+
+    /// 응답을 파일로 저장한다.
+    ///
+    /// # Arguments
+    /// * `path` - 저장 경로
+    /// * `body` - 원본 응답
+    ///
+    /// # Returns
+    /// * `Ok(())`      - 저장 성공
+    /// * `Err(String)` - 저장 실패
+    async fn save(&self, path: &Path, body: &str) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| format!("녹화 경로 생성 실패 ({}): {}", parent.display(), e))?;
+        }
+
+        tokio::fs::write(path, body)
+            .await
+            .map_err(|e| format!("응답 녹화 실패 ({}): {}", path.display(), e))
+    }
+
+    /// 저장된 응답을 읽어 반환한다.
+    ///
+    /// # Arguments
+    /// * `path` - 저장 경로
+    ///
+    /// # Returns
+    /// * `Ok(String)`  - 저장된 원본 응답
+    /// * `Err(String)` - 녹화된 응답 없음 또는 읽기 실패
+    async fn load(&self, path: &Path) -> Result<String, String> {
+        tokio::fs::read_to_string(path)
+            .await
+            .map_err(|e| format!("녹화된 응답 없음 ({}): {}", path.display(), e))
+    }
+}
+
+/// 내부 데이터 소스를 호출하면서 원본 응답을 녹화하는 데이터 소스
+///
+/// # Fields
+/// * `prices`      - 내부 가격 정보 데이터 소스
+/// * `geocoder`    - 내부 좌표 변환 데이터 소스
+/// * `store`       - 응답 저장소
+pub struct RecordingSource {
+    prices: Arc<dyn PriceDataSource>,
+    geocoder: Arc<dyn Geocoder>,
+    store: FixtureStore,
+}
+
+impl RecordingSource {
+    /// 내부 데이터 소스와 응답 저장소를 지정하여 생성한다.
+    pub fn new(
+        prices: Arc<dyn PriceDataSource>,
+        geocoder: Arc<dyn Geocoder>,
+        store: FixtureStore,
+    ) -> Self {
+        Self {
+            prices,
+            geocoder,
+            store,
+        }
+    }
+
+    /// 응답을 녹화한다. 녹화 실패는 동기화를 중단시키지 않는다.
+    async fn record(&self, path: PathBuf, res: &HttpResponse) {
+        if let Err(e) = self.store.save(&path, &res.body).await {
+            tracing::warn!("{}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl PriceDataSource for RecordingSource {
+    async fn fetch_goods(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        let res = self.prices.fetch_goods(page_no, num_of_rows).await?;
+        self.record(self.store.goods_path(page_no), &res).await;
+        Ok(res)
+    }
+
+    async fn fetch_stores(&self, page_no: u32, num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        let res = self.prices.fetch_stores(page_no, num_of_rows).await?;
+        self.record(self.store.stores_path(page_no), &res).await;
+        Ok(res)
+    }
+
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError> {
+        let res = self.prices.fetch_prices(inspect_day, store_id).await?;
+        self.record(self.store.prices_path(inspect_day, store_id), &res)
+            .await;
+        Ok(res)
+    }
+
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError> {
+        let res = self
+            .prices
+            .fetch_standard_codes(class_code, page_no, num_of_rows)
+            .await?;
+        self.record(self.store.standard_codes_path(class_code, page_no), &res)
+            .await;
+        Ok(res)
+    }
+}
+
+#[async_trait]
+impl Geocoder for RecordingSource {
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError> {
+        let res = self.geocoder.fetch_address(addr, address_type).await?;
+        self.record(self.store.geocode_path(addr, address_type), &res)
+            .await;
+        Ok(res)
+    }
+}
+
+/// 녹화된 원본 응답만 반환하는 데이터 소스
+///
+/// # Fields
+/// * `store`                   - 응답 저장소
+/// * `missing_prices_empty`    - 녹화되지 않은 가격 응답을 조사 데이터 없음(빈 응답)으로 볼지 여부
+pub struct ReplaySource {
+    store: FixtureStore,
+    missing_prices_empty: bool,
+}
+
+impl ReplaySource {
+    /// 응답 저장소와 녹화되지 않은 가격 응답 처리 방식을 지정하여 생성한다.
+    pub fn new(store: FixtureStore, missing_prices_empty: bool) -> Self {
+        Self {
+            store,
+            missing_prices_empty,
+        }
+    }
+
+    /// 녹화된 응답을 성공 응답으로 반환한다.
+    async fn replay(&self, path: &Path) -> Result<HttpResponse, HttpError> {
+        let body = self.store.load(path).await?;
+
+        Ok(HttpResponse {
+            status: REPLAY_STATUS,
+            body,
+        })
+    }
+}
+
+#[async_trait]
+impl PriceDataSource for ReplaySource {
+    async fn fetch_goods(&self, page_no: u32, _num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        self.replay(&self.store.goods_path(page_no)).await
+    }
+
+    async fn fetch_stores(&self, page_no: u32, _num_of_rows: u32) -> Result<HttpResponse, HttpError> {
+        self.replay(&self.store.stores_path(page_no)).await
+    }
+
+    /// 녹화되지 않은 조사일.매장은 기본적으로 에러로 반환한다.
+    /// 녹화가 빠진 매장이 조용히 "조사 데이터 없음"으로 처리되지 않도록,
+    /// 빈 응답 처리는 REPLAY_MISSING_PRICES_AS_EMPTY로 명시한 경우에만 한다.
+    async fn fetch_prices(&self, inspect_day: &str, store_id: &str) -> Result<HttpResponse, HttpError> {
+        let path = self.store.prices_path(inspect_day, store_id);
+
+        if self.missing_prices_empty && !tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(HttpResponse {
+                status: REPLAY_STATUS,
+                body: String::new(),
+            });
+        }
+
+        self.replay(&path).await
+    }
+
+    async fn fetch_standard_codes(
+        &self,
+        class_code: &str,
+        page_no: u32,
+        _num_of_rows: u32,
+    ) -> Result<HttpResponse, HttpError> {
+        self.replay(&self.store.standard_codes_path(class_code, page_no))
+            .await
+    }
+}
+
+#[async_trait]
+impl Geocoder for ReplaySource {
+    async fn fetch_address(&self, addr: &str, address_type: AddressType) -> Result<HttpResponse, HttpError> {
+        self.replay(&self.store.geocode_path(addr, address_type))
+            .await
+    }
+}
@@ -0,0 +1,388 @@
+/************************************************************************
+ * File: common/external/http_client.rs
+ * Description:
+ *     외부 API(공공데이터포털, vWorld) 호출에 공용으로 사용하는 HTTP 클라이언트.
+ *     커넥션 풀을 공유하며, 타임아웃 / 재시도 / 서킷 브레이커를 적용한다.
+ *
+ * Reponsibilities:
+ *     1) get_text()
+ *         - GET 요청 후 응답 상태 코드와 본문 반환
+ *         - 일시적 오류(네트워크, 5xx, 429) 시 지수 백오프 + 지터로 재시도
+ *         - 서비스키 호출 한도 초과 응답 시 일정 시간 요청 중단 후 재시도
+ *
+ *     2) CircuitBreaker
+ *         - 업스트림별 연속 실패 횟수 누적
+ *         - 임계값 도달 시 일정 시간 동안 해당 업스트림 요청을 멈춤
+************************************************************************/
+
+use rand::Rng;
+use reqwest::{header::USER_AGENT, Client, StatusCode};
+use std::{
+    fmt,
+    sync::{Mutex, OnceLock},
+};
+use tokio::time::{sleep, sleep_until, Duration, Instant};
+
+use crate::config::http::{load_http_client_config, HttpClientConfig};
+
+/// 공용 HTTP 클라이언트와 설정
+static CLIENT: OnceLock<(Client, HttpClientConfig)> = OnceLock::new();
+
+/// 공공데이터포털 서킷 브레이커
+static PUBLIC_DATA_BREAKER: CircuitBreaker = CircuitBreaker::new();
+
+/// vWorld 서킷 브레이커
+static VWORLD_BREAKER: CircuitBreaker = CircuitBreaker::new();
+
+/// 서비스키 호출 한도 초과를 나타내는 응답 코드
+/// - 공공데이터포털: LIMITED_NUMBER_OF_SERVICE_REQUESTS_EXCEEDS_ERROR
+/// - vWorld: OVER_REQUEST_LIMIT
+const QUOTA_MARKERS: [&str; 2] = ["LIMITED_NUMBER_OF_SERVICE_REQUESTS_EXCEEDS", "OVER_REQUEST_LIMIT"];
+
+/// 재시도해도 해결되지 않는 서비스키 오류 응답 코드
+const FATAL_MARKERS: [&str; 2] = ["SERVICE_KEY_IS_NOT_REGISTERED_ERROR", "INVALID_KEY"];
+
+/// 호출 대상 업스트림
+#[derive(Debug, Clone, Copy)]
+pub enum Upstream {
+    PublicData,
+    VWorld,
+}
+
+impl Upstream {
+    /// 업스트림별 서킷 브레이커를 반환한다.
+    fn breaker(&self) -> &'static CircuitBreaker {
+        match self {
+            Upstream::PublicData => &PUBLIC_DATA_BREAKER,
+            Upstream::VWorld => &VWORLD_BREAKER,
+        }
+    }
+
+    /// 로그에 사용할 업스트림 이름을 반환한다.
+    fn name(&self) -> &'static str {
+        match self {
+            Upstream::PublicData => "공공데이터포털",
+            Upstream::VWorld => "vWorld",
+        }
+    }
+}
+
+/// 업스트림 응답
+///
+/// # Fields
+/// * `status`  - HTTP 상태 코드
+/// * `body`    - 응답 본문
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// 업스트림 요청 실패
+///
+/// # Fields
+/// * `status`  - 마지막으로 받은 HTTP 상태 코드 (응답을 받지 못했으면 None)
+/// * `message` - 오류 메시지
+#[derive(Debug, Clone)]
+pub struct HttpError {
+    pub status: Option<u16>,
+    pub message: String,
+}
+
+impl HttpError {
+    /// 상태 코드를 지정하여 생성한다.
+    fn with_status(status: StatusCode, message: String) -> Self {
+        Self {
+            status: Some(status.as_u16()),
+            message,
+        }
+    }
+
+    /// 상태 코드는 유지한 채 메시지 앞에 요청 맥락을 덧붙인다.
+    pub fn context(self, prefix: &str) -> Self {
+        Self {
+            status: self.status,
+            message: format!("{}: {}", prefix, self.message),
+        }
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl From<String> for HttpError {
+    fn from(message: String) -> Self {
+        Self {
+            status: None,
+            message,
+        }
+    }
+}
+
+impl From<HttpError> for String {
+    fn from(e: HttpError) -> Self {
+        e.message
+    }
+}
+
+/// 한 번의 요청 시도 실패 유형
+enum AttemptError {
+    /// 네트워크 오류, 5xx, 429 등 재시도로 회복 가능한 오류
+    Transient(HttpError),
+    /// 서비스키 호출 한도 초과
+    Quota(HttpError),
+    /// 4xx, 서비스키 미등록 등 재시도해도 회복되지 않는 오류
+    Fatal(HttpError),
+}
+
+/// 연속 실패 횟수를 기준으로 업스트림 요청을 일시 중단하는 서킷 브레이커
+///
+/// # Fields
+/// * `state` - (연속 실패 횟수, 요청 재개 시각)
+pub struct CircuitBreaker {
+    state: Mutex<(u32, Option<Instant>)>,
+}
+
+impl CircuitBreaker {
+    /// 닫힌 상태의 서킷 브레이커를 생성한다.
+    const fn new() -> Self {
+        Self {
+            state: Mutex::new((0, None)),
+        }
+    }
+
+    /// 브레이커가 열려 있으면 요청 재개 시각을 반환한다.
+    fn open_until(&self) -> Option<Instant> {
+        let state = self.state.lock().unwrap();
+        state.1.filter(|until| *until > Instant::now())
+    }
+
+    /// 요청 성공을 기록하고 브레이커를 닫는다.
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        *state = (0, None);
+    }
+
+    /// 요청 실패를 기록하고, 연속 실패가 임계값에 도달하면 브레이커를 연다.
+    ///
+    /// # Returns
+    /// * `bool` - 이번 실패로 브레이커가 열렸는지 여부
+    fn record_failure(&self, threshold: u32, cooldown: Duration) -> bool {
+        let mut state = self.state.lock().unwrap();
+        state.0 += 1;
+
+        if state.0 >= threshold {
+            state.1 = Some(Instant::now() + cooldown);
+            return true;
+        }
+        false
+    }
+
+    /// 지정한 시간 동안 브레이커를 강제로 연다. (호출 한도 초과 등)
+    fn trip(&self, duration: Duration) {
+        let mut state = self.state.lock().unwrap();
+        let until = Instant::now() + duration;
+        state.1 = Some(state.1.map_or(until, |current| current.max(until)));
+    }
+}
+
+/// 공용 HTTP 클라이언트와 설정을 반환한다. 최초 호출 시 한 번만 생성한다.
+fn shared_client() -> &'static (Client, HttpClientConfig) {
+    CLIENT.get_or_init(|| {
+        let config = load_http_client_config();
+        let client = Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .read_timeout(config.read_timeout)
+            .build()
+            .expect("HTTP 클라이언트 생성 실패");
+
+        (client, config)
+    })
+}
+
+/// 업스트림에 GET 요청을 보내고 응답 상태 코드와 본문을 반환한다.
+///
+/// 일시적 오류는 최대 HTTP_MAX_RETRIES회까지 지수 백오프 + 지터로 재시도한다.
+/// 서킷 브레이커가 열려 있으면 요청을 보내지 않고 재개 시각까지 대기한다.
+///
+/// # Arguments
+/// * `upstream`    - 호출 대상 업스트림
+/// * `url`         - 요청 URL
+///
+/// # Returns
+/// * `Ok(HttpResponse)`    - 응답 상태 코드와 본문
+/// * `Err(HttpError)`      - 재시도 후에도 실패하거나 회복 불가능한 오류 (마지막 상태 코드 포함)
+pub async fn get_text(
+    upstream: Upstream,
+    url: &str,
+) -> Result<HttpResponse, HttpError> {
+    let (client, config) = shared_client();
+    let breaker = upstream.breaker();
+    let mut last_error = HttpError::from(String::new());
+
+    for attempt in 0..=config.max_retries {
+        if let Some(until) = breaker.open_until() {
+            tracing::warn!(
+                "{} 요청 일시 중단 — {}초 후 재개",
+                upstream.name(),
+                until.saturating_duration_since(Instant::now()).as_secs()
+            );
+            sleep_until(until).await;
+        }
+
+        match send_once(client, url).await {
+            Ok(response) => {
+                breaker.record_success();
+                return Ok(response);
+            }
+            Err(AttemptError::Fatal(e)) => return Err(e),
+            Err(AttemptError::Quota(e)) => {
+                tracing::warn!(
+                    "{} 호출 한도 초과 — {}초 동안 요청 중단",
+                    upstream.name(),
+                    config.quota_backoff.as_secs()
+                );
+                breaker.trip(config.quota_backoff);
+                last_error = e;
+            }
+            Err(AttemptError::Transient(e)) => {
+                if breaker.record_failure(config.breaker_threshold, config.breaker_cooldown) {
+                    tracing::warn!(
+                        "{} 연속 실패 — {}초 동안 요청 중단",
+                        upstream.name(),
+                        config.breaker_cooldown.as_secs()
+                    );
+                }
+
+                if attempt < config.max_retries {
+                    let delay = backoff_delay(config, attempt);
+                    tracing::debug!(
+                        "{} 요청 재시도 {}/{} ({}ms 후): {}",
+                        upstream.name(),
+                        attempt + 1,
+                        config.max_retries,
+                        delay.as_millis(),
+                        e
+                    );
+                    sleep(delay).await;
+                }
+                last_error = e;
+            }
+        }
+    }
+
+    Err(last_error.context(&format!("{}회 시도 후 실패", config.max_retries + 1)))
+}
+
+/// GET 요청을 한 번 보내고 결과를 분류한다.
+/// 요청 URL에는 서비스키(serviceKey / key)가 들어 있으므로,
+/// 오류 메시지는 URL을 제외하고 만든다. (sync_runs / raw_payloads / 응답에 그대로 저장되기 때문)
+///
+/// # Arguments
+/// * `client`  - 공용 HTTP 클라이언트
+/// * `url`     - 요청 URL
+///
+/// # Returns
+/// * `Ok(HttpResponse)`    - 응답 상태 코드와 본문
+/// * `Err(AttemptError)`   - 실패 유형
+async fn send_once(
+    client: &Client,
+    url: &str,
+) -> Result<HttpResponse, AttemptError> {
+    let res = client
+        .get(url)
+        .header(USER_AGENT, "StoreRader/1.0")
+        .send()
+        .await
+        .map_err(|e| AttemptError::Transient(format!("요청 실패: {}", e.without_url()).into()))?;
+
+    let status = res.status();
+    let error = |message: String| HttpError::with_status(status, message);
+
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        return Err(AttemptError::Quota(error(format!("오류 상태: {}", status))));
+    }
+    if status.is_server_error() {
+        return Err(AttemptError::Transient(error(format!("오류 상태: {}", status))));
+    }
+    if !status.is_success() {
+        return Err(AttemptError::Fatal(error(format!("오류 상태: {}", status))));
+    }
+
+    let body = res.text().await.map_err(|e| {
+        AttemptError::Transient(error(format!("본문 읽기 실패: {}", e.without_url())))
+    })?;
+
+    if let Some(marker) = QUOTA_MARKERS.iter().find(|m| body.contains(*m)) {
+        return Err(AttemptError::Quota(error(format!("호출 한도 초과: {}", marker))));
+    }
+    if let Some(marker) = FATAL_MARKERS.iter().find(|m| body.contains(*m)) {
+        return Err(AttemptError::Fatal(error(format!("서비스키 오류: {}", marker))));
+    }
+
+    Ok(HttpResponse {
+        status: status.as_u16(),
+        body,
+    })
+}
+
+/// 재시도 대기 시간을 계산한다.
+/// base * 2^attempt 를 상한으로 자른 뒤, 50~100% 구간의 지터를 적용한다.
+///
+/// # Arguments
+/// * `config`  - HTTP 클라이언트 설정
+/// * `attempt` - 지금까지 실패한 시도 번호 (0부터 시작)
+fn backoff_delay(config: &HttpClientConfig, attempt: u32) -> Duration {
+    let exp = config
+        .backoff_base
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(config.backoff_max);
+    let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+
+    exp.mul_f64(jitter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(base_ms: u64, max_ms: u64) -> HttpClientConfig {
+        HttpClientConfig {
+            connect_timeout: Duration::from_secs(1),
+            read_timeout: Duration::from_secs(1),
+            max_retries: 3,
+            backoff_base: Duration::from_millis(base_ms),
+            backoff_max: Duration::from_millis(max_ms),
+            breaker_threshold: 5,
+            breaker_cooldown: Duration::from_secs(1),
+            quota_backoff: Duration::from_secs(1),
+        }
+    }
+
+    fn assert_jittered(delay: Duration, full: Duration) {
+        assert!(delay >= full.mul_f64(0.5) && delay <= full, "{:?} not in 50~100% of {:?}", delay, full);
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_with_jitter() {
+        let config = config(100, 10_000);
+
+        for _ in 0..100 {
+            assert_jittered(backoff_delay(&config, 0), Duration::from_millis(100));
+            assert_jittered(backoff_delay(&config, 1), Duration::from_millis(200));
+            assert_jittered(backoff_delay(&config, 3), Duration::from_millis(800));
+        }
+    }
+
+    #[test]
+    fn backoff_is_capped_and_does_not_overflow() {
+        let config = config(100, 1_000);
+
+        for attempt in [4, 10, 31, 32, 64, u32::MAX] {
+            assert_jittered(backoff_delay(&config, attempt), Duration::from_millis(1_000));
+        }
+    }
+}
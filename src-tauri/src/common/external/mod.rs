@@ -1,2 +1,7 @@
 pub mod api_public_data;
 pub mod api_vworld;
+pub mod archive;
+pub mod data_source;
+pub mod fixture;
+pub mod http_client;
+pub mod rate_limiter;
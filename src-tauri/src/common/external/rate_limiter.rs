@@ -0,0 +1,57 @@
+/************************************************************************
+ * File: common/external/rate_limiter.rs
+ * Description:
+ *     외부 API 호출 빈도를 초당 요청 수 기준으로 제한한다.
+ *
+ * Reponsibilities:
+ *     1) RateLimiter::acquire()
+ *         - 다음 요청 슬롯까지 대기한 뒤 반환
+************************************************************************/
+
+use std::sync::Mutex;
+use tokio::time::{sleep_until, Duration, Instant};
+
+/// 요청 간 최소 간격을 보장하는 단순 레이트 리미터
+///
+/// # Fields
+/// * `interval`    - 요청 간 최소 간격 (None이면 제한 없음)
+/// * `next_slot`   - 다음 요청이 허용되는 시각
+pub struct RateLimiter {
+    interval: Option<Duration>,
+    next_slot: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// 초당 요청 수를 기준으로 레이트 리미터를 생성한다.
+    ///
+    /// # Arguments
+    /// * `requests_per_second` - 초당 최대 요청 수 (0 이하이면 제한 없음)
+    pub fn new(requests_per_second: f64) -> Self {
+        let interval = if requests_per_second > 0.0 {
+            Some(Duration::from_secs_f64(1.0 / requests_per_second))
+        } else {
+            None
+        };
+
+        Self {
+            interval,
+            next_slot: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// 다음 요청 슬롯을 예약하고 해당 시각까지 대기한다.
+    pub async fn acquire(&self) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        let slot = {
+            let mut next_slot = self.next_slot.lock().unwrap();
+            let slot = (*next_slot).max(Instant::now());
+            *next_slot = slot + interval;
+            slot
+        };
+
+        sleep_until(slot).await;
+    }
+}
@@ -0,0 +1,240 @@
+/************************************************************************
+ * File: common/geo/coord_transform.rs
+ * Description:
+ *     공공데이터 매장 정보에 포함된 지도 좌표(xMapCoord / yMapCoord)를
+ *     지도에 표시할 수 있는 WGS84 위도.경도로 변환한다.
+ *
+ * Reponsibilities:
+ *     1) upstream_to_wgs84()
+ *         - 업스트림 좌표를 WGS84로 변환 (이미 경위도면 그대로 사용)
+ *         - 국내 범위를 벗어나는 좌표는 버림 (국외 경위도를 TM으로 오인하지 않음)
+ * 
+ *     2) tm_to_wgs84()
+ *         - 중부원점 TM 좌표(GRS80, EPSG:5181) → WGS84 역변환
+************************************************************************/
+
+/// GRS80 타원체 장반경 (m)
+const GRS80_A: f64 = 6_378_137.0;
+
+/// GRS80 타원체 편평률
+const GRS80_F: f64 = 1.0 / 298.257_222_101;
+
+/// 중부원점 위도 / 경도 (도)
+const ORIGIN_LAT: f64 = 38.0;
+const ORIGIN_LON: f64 = 127.0;
+
+/// 중부원점 가산 수치 (m)
+const FALSE_EASTING: f64 = 200_000.0;
+const FALSE_NORTHING: f64 = 500_000.0;
+
+/// 국내 좌표 유효 범위 (위도 / 경도)
+const KOREA_LAT_RANGE: (f64, f64) = (33.0, 39.0);
+const KOREA_LON_RANGE: (f64, f64) = (124.0, 132.0);
+
+/// 업스트림 지도 좌표를 WGS84 위도.경도로 변환한다.
+/// 값이 이미 경위도 범위이면 그대로 사용하고 (국내 범위 밖의 경위도는 버림),
+/// 그 외에는 중부원점 TM 좌표로 보고 역변환한다.
+/// 
+/// # Arguments
+/// * `x` - 업스트림 xMapCoord
+/// * `y` - 업스트림 yMapCoord
+/// 
+/// # Returns
+/// * `Some((lat, lon))`    - 변환 성공
+/// * `None`                - 좌표 없음 또는 국내 범위를 벗어남
+pub fn upstream_to_wgs84(
+    x: Option<f64>,
+    y: Option<f64>,
+) -> Option<(f64, f64)> {
+    let (x, y) = (x?, y?);
+    if x == 0.0 || y == 0.0 {
+        return None;
+    }
+
+    // 경위도로 들어온 경우 (x=경도, y=위도 또는 그 반대)
+    if is_in_korea(y, x) {
+        return Some((y, x));
+    }
+    if is_in_korea(x, y) {
+        return Some((x, y));
+    }
+    // 경위도 형태지만 국내 범위 밖인 값을 TM 좌표로 잘못 해석하지 않는다.
+    if x.abs() <= 180.0 && y.abs() <= 180.0 {
+        return None;
+    }
+
+    let (lat, lon) = tm_to_wgs84(x, y);
+    is_in_korea(lat, lon).then_some((lat, lon))
+}
+
+/// 중부원점 TM 좌표(GRS80, EPSG:5181)를 WGS84 위도.경도로 역변환한다.
+/// 
+/// # Arguments
+/// * `x` - 동쪽 방향 좌표 (m)
+/// * `y` - 북쪽 방향 좌표 (m)
+/// 
+/// # Returns
+/// * `(f64, f64)` - (lat, lon)
+pub fn tm_to_wgs84(
+    x: f64,
+    y: f64,
+) -> (f64, f64) {
+    let e2 = 2.0 * GRS80_F - GRS80_F * GRS80_F;
+    let ep2 = e2 / (1.0 - e2);
+    let lat0 = ORIGIN_LAT.to_radians();
+    let lon0 = ORIGIN_LON.to_radians();
+
+    let m = meridian_arc(lat0, e2) + (y - FALSE_NORTHING);
+    let mu = m / (GRS80_A * (1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0));
+    let e1 = (1.0 - (1.0 - e2).sqrt()) / (1.0 + (1.0 - e2).sqrt());
+
+    // 기준 위도 (footpoint latitude)
+    let phi1 = mu
+        + (3.0 * e1 / 2.0 - 27.0 * e1.powi(3) / 32.0) * (2.0 * mu).sin()
+        + (21.0 * e1.powi(2) / 16.0 - 55.0 * e1.powi(4) / 32.0) * (4.0 * mu).sin()
+        + (151.0 * e1.powi(3) / 96.0) * (6.0 * mu).sin()
+        + (1097.0 * e1.powi(4) / 512.0) * (8.0 * mu).sin();
+
+    let (sin1, cos1, tan1) = (phi1.sin(), phi1.cos(), phi1.tan());
+    let c1 = ep2 * cos1 * cos1;
+    let t1 = tan1 * tan1;
+    let n1 = GRS80_A / (1.0 - e2 * sin1 * sin1).sqrt();
+    let r1 = GRS80_A * (1.0 - e2) / (1.0 - e2 * sin1 * sin1).powf(1.5);
+    let d = (x - FALSE_EASTING) / n1;
+
+    let lat = phi1
+        - (n1 * tan1 / r1)
+            * (d.powi(2) / 2.0
+                - (5.0 + 3.0 * t1 + 10.0 * c1 - 4.0 * c1 * c1 - 9.0 * ep2) * d.powi(4) / 24.0
+                + (61.0 + 90.0 * t1 + 298.0 * c1 + 45.0 * t1 * t1 - 252.0 * ep2 - 3.0 * c1 * c1)
+                    * d.powi(6)
+                    / 720.0);
+    let lon = lon0
+        + (d - (1.0 + 2.0 * t1 + c1) * d.powi(3) / 6.0
+            + (5.0 - 2.0 * c1 + 28.0 * t1 - 3.0 * c1 * c1 + 8.0 * ep2 + 24.0 * t1 * t1) * d.powi(5)
+                / 120.0)
+            / cos1;
+
+    (lat.to_degrees(), lon.to_degrees())
+}
+
+/// 적도에서 주어진 위도까지의 자오선 호장을 계산한다.
+/// 
+/// # Arguments
+/// * `phi` - 위도 (라디안)
+/// * `e2`  - 이심률 제곱
+fn meridian_arc(
+    phi: f64,
+    e2: f64,
+) -> f64 {
+    GRS80_A
+        * ((1.0 - e2 / 4.0 - 3.0 * e2.powi(2) / 64.0 - 5.0 * e2.powi(3) / 256.0) * phi
+            - (3.0 * e2 / 8.0 + 3.0 * e2.powi(2) / 32.0 + 45.0 * e2.powi(3) / 1024.0) * (2.0 * phi).sin()
+            + (15.0 * e2.powi(2) / 256.0 + 45.0 * e2.powi(3) / 1024.0) * (4.0 * phi).sin()
+            - (35.0 * e2.powi(3) / 3072.0) * (6.0 * phi).sin())
+}
+
+/// 위도.경도가 국내 범위 안에 있는지 확인한다.
+fn is_in_korea(
+    lat: f64,
+    lon: f64,
+) -> bool {
+    (KOREA_LAT_RANGE.0..=KOREA_LAT_RANGE.1).contains(&lat)
+        && (KOREA_LON_RANGE.0..=KOREA_LON_RANGE.1).contains(&lon)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 기준 변환: 자오선 호장을 수치 적분으로 구하는 TM 정변환 (Snyder, USGS PP 1395 식 8-9 ~ 8-10).
+    /// 검증 대상인 역변환과 급수 전개를 공유하지 않는다.
+    fn reference_wgs84_to_tm(lat: f64, lon: f64) -> (f64, f64) {
+        let e2 = 2.0 * GRS80_F - GRS80_F * GRS80_F;
+        let ep2 = e2 / (1.0 - e2);
+        let arc = |phi: f64| {
+            // 심프슨 적분: M(φ) = ∫ a(1-e²) / (1-e² sin²φ)^(3/2) dφ
+            let n = 2_000;
+            let h = phi / n as f64;
+            let f = |p: f64| GRS80_A * (1.0 - e2) / (1.0 - e2 * p.sin().powi(2)).powf(1.5);
+            let mut sum = f(0.0) + f(phi);
+            for i in 1..n {
+                sum += f(i as f64 * h) * if i % 2 == 1 { 4.0 } else { 2.0 };
+            }
+            sum * h / 3.0
+        };
+
+        let phi = lat.to_radians();
+        let n = GRS80_A / (1.0 - e2 * phi.sin().powi(2)).sqrt();
+        let t = phi.tan().powi(2);
+        let c = ep2 * phi.cos().powi(2);
+        let a = (lon - ORIGIN_LON).to_radians() * phi.cos();
+
+        let x = FALSE_EASTING
+            + n * (a + (1.0 - t + c) * a.powi(3) / 6.0
+                + (5.0 - 18.0 * t + t * t + 72.0 * c - 58.0 * ep2) * a.powi(5) / 120.0);
+        let y = FALSE_NORTHING
+            + arc(phi)
+            - arc(ORIGIN_LAT.to_radians())
+            + n * phi.tan()
+                * (a.powi(2) / 2.0
+                    + (5.0 - t + 9.0 * c + 4.0 * c * c) * a.powi(4) / 24.0
+                    + (61.0 - 58.0 * t + t * t + 600.0 * c - 330.0 * ep2) * a.powi(6) / 720.0);
+        (x, y)
+    }
+
+    fn assert_close(actual: (f64, f64), expected: (f64, f64)) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-6 && (actual.1 - expected.1).abs() < 1e-6,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn tm_origin_maps_to_central_origin() {
+        assert_close(tm_to_wgs84(FALSE_EASTING, FALSE_NORTHING), (ORIGIN_LAT, ORIGIN_LON));
+    }
+
+    #[test]
+    fn tm_matches_reference_transform_across_korea() {
+        // 서울시청, 부산시청, 제주시청, 강릉시청, 목포시청
+        let points = [
+            (37.566_3, 126.977_9),
+            (35.179_6, 129.075_6),
+            (33.499_6, 126.531_2),
+            (37.752_1, 128.876_1),
+            (34.811_8, 126.392_2),
+        ];
+        for (lat, lon) in points {
+            let (x, y) = reference_wgs84_to_tm(lat, lon);
+            assert_close(tm_to_wgs84(x, y), (lat, lon));
+            assert_close(upstream_to_wgs84(Some(x), Some(y)).unwrap(), (lat, lon));
+        }
+    }
+
+    #[test]
+    fn lat_lon_passthrough_in_either_order() {
+        assert_eq!(upstream_to_wgs84(Some(126.9779), Some(37.5663)), Some((37.5663, 126.9779)));
+        assert_eq!(upstream_to_wgs84(Some(37.5663), Some(126.9779)), Some((37.5663, 126.9779)));
+    }
+
+    #[test]
+    fn missing_or_zero_coords_are_rejected() {
+        assert_eq!(upstream_to_wgs84(None, Some(37.5)), None);
+        assert_eq!(upstream_to_wgs84(Some(127.0), None), None);
+        assert_eq!(upstream_to_wgs84(Some(0.0), Some(0.0)), None);
+        assert_eq!(upstream_to_wgs84(Some(127.0), Some(0.0)), None);
+    }
+
+    #[test]
+    fn coords_outside_korea_are_rejected() {
+        // 도쿄 경위도
+        assert_eq!(upstream_to_wgs84(Some(139.6917), Some(35.6895)), None);
+        // 중부원점에서 동쪽으로 약 800km 떨어진 TM 좌표
+        assert_eq!(upstream_to_wgs84(Some(1_000_000.0), Some(500_000.0)), None);
+        // 중부원점에서 남쪽으로 약 900km 떨어진 TM 좌표
+        assert_eq!(upstream_to_wgs84(Some(200_000.0), Some(-400_000.0)), None);
+    }
+}
@@ -0,0 +1 @@
+pub mod coord_transform;
\ No newline at end of file
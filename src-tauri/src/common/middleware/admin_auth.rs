@@ -0,0 +1,235 @@
+/************************************************************************
+ * File: common/middleware/admin_auth.rs
+ * Description:
+ *     관리자 전용 라우트(/sync/... 등)에 적용하는 인증 미들웨어.
+ *     관리자 권한 JWT 또는 정적 관리자 API 키(ADMIN_API_KEY)를 요구하며,
+ *     모든 접근 시도를 admin_audit_log에 기록한다.
+ *
+ * Responsibilities:
+ *     1) require_admin()
+ *         - X-Admin-Key 헤더 또는 Authorization(Bearer JWT)로 관리자 인증
+ *         - 인증 실패 시 401 / 권한 부족 시 403 반환
+ *         - 허용 / 거부 결과와 응답 코드를 감사 로그로 저장
+ * 
+ *     2) authenticate_admin()
+ *         - 요청 헤더로부터 관리자 여부 판별
+ * 
+ *     3) api_key_matches()
+ *         - 관리자 API 키 비교 (해시 비교로 길이 / 타이밍 노출 방지)
+ * 
+ *     4) write_audit_log()
+ *         - 감사 로그 저장 (실패 시 경고만 기록)
+************************************************************************/
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::Utc;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+use crate::{
+    common::{
+        entity::entity_admin_audit_log::AdminAuditLogEntity,
+        repository::repository_admin_audit_log::insert_admin_audit_log,
+        token::extract_token::extract_token,
+    },
+    config::env::get_env_value,
+    domain::auth::service::decode_jwt,
+};
+
+/// 관리자 API 키를 전달하는 헤더 이름
+const ADMIN_KEY_HEADER: &str = "X-Admin-Key";
+
+/// 관리자 인증에 성공한 주체
+///
+/// # Fields
+/// * `actor`       - 요청자 식별 값 ("user:{id}" | "api_key")
+/// * `auth_method` - 인증 방식 ("jwt" | "api_key")
+struct AdminPrincipal {
+    actor: String,
+    auth_method: &'static str,
+}
+
+/// 관리자 인증 실패 정보
+///
+/// # Fields
+/// * `status`      - 응답 코드 (401 | 403)
+/// * `actor`       - 식별된 요청자 (권한 부족인 경우)
+/// * `auth_method` - 시도한 인증 방식
+/// * `reason`      - 실패 사유
+struct AdminDenied {
+    status: StatusCode,
+    actor: Option<String>,
+    auth_method: Option<&'static str>,
+    reason: String,
+}
+
+/// 관리자 권한을 확인한 뒤 요청을 다음 핸들러로 전달한다.
+/// 허용 / 거부 여부와 관계없이 모든 시도를 admin_audit_log에 기록한다.
+///
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `req`     - HTTP 요청
+/// * `next`    - 다음 미들웨어 / 핸들러
+///
+/// # Returns
+/// * 핸들러 응답 (인증 성공)
+/// * `401 UNAUTHORIZED`    - 인증 정보 없음 / 유효하지 않음
+/// * `403 FORBIDDEN`       - 관리자 권한 없음
+pub async fn require_admin(
+    State(pool): State<PgPool>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().map(|q| q.to_string());
+
+    match authenticate_admin(req.headers()) {
+        Ok(principal) => {
+            let response = next.run(req).await;
+            tracing::info!(
+                "관리자 요청 허용: {} {} ({}, {})",
+                method,
+                path,
+                principal.actor,
+                response.status()
+            );
+            write_audit_log(
+                &pool,
+                AdminAuditLogEntity {
+                    id: 0,
+                    actor: Some(principal.actor),
+                    auth_method: Some(principal.auth_method.to_string()),
+                    method,
+                    path,
+                    query,
+                    status_code: response.status().as_u16() as i32,
+                    allowed: true,
+                    reason: None,
+                    created_at: Utc::now().naive_utc(),
+                },
+            )
+            .await;
+
+            response
+        }
+        Err(denied) => {
+            tracing::warn!("관리자 요청 거부: {} {} ({})", method, path, denied.reason);
+            write_audit_log(
+                &pool,
+                AdminAuditLogEntity {
+                    id: 0,
+                    actor: denied.actor,
+                    auth_method: denied.auth_method.map(|m| m.to_string()),
+                    method,
+                    path,
+                    query,
+                    status_code: denied.status.as_u16() as i32,
+                    allowed: false,
+                    reason: Some(denied.reason.clone()),
+                    created_at: Utc::now().naive_utc(),
+                },
+            )
+            .await;
+
+            (denied.status, Json(json!({ "message": denied.reason }))).into_response()
+        }
+    }
+}
+
+/// 요청 헤더로부터 관리자 여부를 판별한다.
+/// X-Admin-Key 헤더가 있으면 API 키로, 없으면 Authorization 헤더의 JWT role로 판별한다.
+///
+/// # Arguments
+/// * `headers` - HTTP 요청의 HeaderMap
+///
+/// # Returns
+/// * `Ok(AdminPrincipal)`  - 관리자 인증 성공
+/// * `Err(AdminDenied)`    - 인증 실패 / 권한 부족
+fn authenticate_admin(headers: &HeaderMap) -> Result<AdminPrincipal, AdminDenied> {
+    if let Some(key) = headers.get(ADMIN_KEY_HEADER) {
+        let expected = get_env_value("ADMIN_API_KEY");
+        let provided = key.to_str().unwrap_or_default();
+
+        if expected.is_empty() || !api_key_matches(provided, &expected) {
+            return Err(AdminDenied {
+                status: StatusCode::UNAUTHORIZED,
+                actor: None,
+                auth_method: Some("api_key"),
+                reason: "관리자 API 키가 유효하지 않습니다".into(),
+            });
+        }
+
+        return Ok(AdminPrincipal {
+            actor: "api_key".into(),
+            auth_method: "api_key",
+        });
+    }
+
+    let token = extract_token(headers).map_err(|_| AdminDenied {
+        status: StatusCode::UNAUTHORIZED,
+        actor: None,
+        auth_method: None,
+        reason: "관리자 인증 정보가 없습니다".into(),
+    })?;
+
+    let claims = decode_jwt(token).map_err(|e| AdminDenied {
+        status: StatusCode::UNAUTHORIZED,
+        actor: None,
+        auth_method: Some("jwt"),
+        reason: e,
+    })?;
+
+    let actor = format!("user:{}", claims.sub);
+    if !claims.is_admin() {
+        return Err(AdminDenied {
+            status: StatusCode::FORBIDDEN,
+            actor: Some(actor),
+            auth_method: Some("jwt"),
+            reason: "관리자 권한이 없습니다".into(),
+        });
+    }
+
+    Ok(AdminPrincipal {
+        actor,
+        auth_method: "jwt",
+    })
+}
+
+/// 전달된 API 키와 설정된 관리자 API 키가 일치하는지 확인한다.
+/// 원문 대신 SHA-256 해시를 비교해 키 길이 / 일치 위치가 응답 시간으로 드러나지 않게 한다.
+///
+/// # Arguments
+/// * `provided` - 요청 헤더의 API 키
+/// * `expected` - ADMIN_API_KEY 설정 값
+///
+/// # Returns
+/// * `bool` - 일치 여부
+fn api_key_matches(provided: &str, expected: &str) -> bool {
+    let provided = Sha256::digest(provided.as_bytes());
+    let expected = Sha256::digest(expected.as_bytes());
+
+    provided
+        .iter()
+        .zip(expected.iter())
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+/// 감사 로그를 저장한다. 저장 실패는 요청 처리에 영향을 주지 않고 경고만 남긴다.
+///
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `log`     - 저장할 접근 기록
+async fn write_audit_log(pool: &PgPool, log: AdminAuditLogEntity) {
+    if let Err(e) = insert_admin_audit_log(pool, &log).await {
+        tracing::warn!("{}", e);
+    }
+}
@@ -0,0 +1 @@
+pub mod admin_auth;
\ No newline at end of file
@@ -1,4 +1,6 @@
 pub mod entity;
 pub mod external;
+pub mod geo;
+pub mod middleware;
 pub mod repository;
 pub mod token;
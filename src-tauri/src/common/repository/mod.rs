@@ -1,9 +1,22 @@
+pub mod repository_admin;
+pub mod repository_admin_audit_log;
+pub mod repository_geocode_cache;
 pub mod repository_good;
+pub mod repository_inspect_day;
 pub mod repository_join;
+pub mod repository_lock;
+pub mod repository_master_history;
 pub mod repository_price;
+pub mod repository_raw_payload;
 pub mod repository_region;
+pub mod repository_standard_code;
 pub mod repository_store;
+pub mod repository_sync_quarantine;
+pub mod repository_sync_run;
 pub mod repository_user_preference;
 pub mod repository_user_selection_log;
 pub mod repository_user;
 pub mod repostiory_price_change;
+
+/// 일괄 저장 시 한 번의 쿼리로 처리할 최대 행 수
+pub const BULK_CHUNK_SIZE: usize = 1000;
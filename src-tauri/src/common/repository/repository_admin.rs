@@ -0,0 +1,136 @@
+/************************************************************************
+ * File: common/repository/repository_admin.rs
+ * Description:
+ *     운영 / 점검용 조회 로직을 담당한다.
+ *     (마이그레이션 적용 기록, 데이터 품질 집계)
+ *
+ * Responsibilities:
+ *     1) find_applied_migrations()
+ *         - _sqlx_migrations 테이블의 적용 기록 조회
+ * 
+ *     2) find_data_quality_counts()
+ *         - 매장 / 상품 / 최근 조사일 가격 데이터 품질 집계
+ * 
+ *     3) count_quarantine_by_reason()
+ *         - 기간 내 항목 종류 / 사유별 격리 항목 수 집계
+************************************************************************/
+
+use chrono::NaiveDateTime;
+use sqlx::PgPool;
+
+use crate::domain::admin::dto::dto_res::{DataQualityCountsRes, QuarantineCountRes};
+
+/// 적용된 마이그레이션 기록을 버전 순으로 조회한다.
+/// 마이그레이션을 한 번도 실행하지 않아 기록 테이블이 없으면 빈 목록을 반환한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Vec<(version, description, success, installed_on)>)`  - 적용 기록
+/// * `Err(String)`                                             - 조회 실패
+pub async fn find_applied_migrations(
+    pool: &PgPool
+) -> Result<Vec<(i64, String, bool, NaiveDateTime)>, String> {
+    let exists: bool = sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| format!("마이그레이션 기록 테이블 확인 실패: {}", e))?;
+
+    if !exists {
+        return Ok(Vec::new());
+    }
+
+    sqlx::query_as::<_, (i64, String, bool, NaiveDateTime)>(
+        "
+        SELECT
+            version,
+            description,
+            success,
+            installed_on::timestamp
+        FROM _sqlx_migrations
+        ORDER BY version ASC
+        ",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("마이그레이션 기록 조회 실패: {}", e))
+}
+
+/// 매장 / 상품 / 최근 조사일 가격 데이터의 품질 지표를 집계한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(DataQualityCountsRes)`    - 데이터 품질 집계
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_data_quality_counts(
+    pool: &PgPool
+) -> Result<DataQualityCountsRes, String> {
+    sqlx::query_as::<_, DataQualityCountsRes>(
+        "
+        WITH latest AS (
+            SELECT MAX(inspect_day) AS inspect_day
+            FROM prices
+        )
+        SELECT
+            (SELECT COUNT(*) FROM stores) AS stores_total,
+            (SELECT COUNT(*) FROM stores WHERE NOT is_active) AS stores_inactive,
+            (SELECT COUNT(*) FROM stores WHERE is_active AND geocode_status = 'manual_review') AS stores_manual_review,
+            (SELECT COUNT(*) FROM stores WHERE is_active AND (x_coord IS NULL OR y_coord IS NULL)) AS stores_without_coords,
+            (SELECT COUNT(*) FROM goods) AS goods_total,
+            (SELECT COUNT(*) FROM goods WHERE NOT is_active) AS goods_inactive,
+            latest.inspect_day AS latest_inspect_day,
+            (SELECT COUNT(*) FROM prices p WHERE p.inspect_day = latest.inspect_day) AS latest_price_rows,
+            (SELECT COUNT(DISTINCT p.store_id) FROM prices p WHERE p.inspect_day = latest.inspect_day) AS latest_price_stores,
+            (
+                SELECT COUNT(*)
+                FROM prices p
+                WHERE p.inspect_day = latest.inspect_day
+                    AND NOT EXISTS (SELECT 1 FROM goods g WHERE g.good_id = p.good_id)
+            ) AS latest_unknown_goods,
+            (
+                SELECT COUNT(*)
+                FROM prices p
+                WHERE p.inspect_day = latest.inspect_day
+                    AND NOT EXISTS (SELECT 1 FROM stores s WHERE s.store_id = p.store_id)
+            ) AS latest_unknown_stores
+        FROM latest
+        ",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("데이터 품질 집계 실패: {}", e))
+}
+
+/// 최근 기간 동안 격리된 항목 수를 항목 종류 / 사유별로 집계한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// * `days` - 오늘부터 거슬러 올라갈 일수
+/// 
+/// # Returns
+/// * `Ok(Vec<QuarantineCountRes>)` - 사유별 격리 항목 수 (많은 순)
+/// * `Err(String)`                 - 조회 실패
+pub async fn count_quarantine_by_reason(
+    pool: &PgPool,
+    days: i64,
+) -> Result<Vec<QuarantineCountRes>, String> {
+    sqlx::query_as::<_, QuarantineCountRes>(
+        "
+        SELECT
+            entity,
+            reason,
+            COUNT(*) AS count
+        FROM sync_quarantine
+        WHERE created_at >= NOW() - make_interval(days => $1::int)
+        GROUP BY entity, reason
+        ORDER BY count DESC, entity, reason
+        ",
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("격리 항목 집계 실패: {}", e))
+}
@@ -0,0 +1,57 @@
+/************************************************************************
+ * File: common/repository/repository_admin_audit_log.rs
+ * Description:
+ *     admin_audit_log 테이블에 대한 DB 연산 로직을 담당한다.
+ *     관리자 전용 라우트에 대한 모든 접근 시도(허용 / 거부)를 기록한다.
+ *
+ * Responsibilities:
+ *     1) insert_admin_audit_log()
+ *         - 관리자 라우트 접근 기록 저장
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::common::entity::entity_admin_audit_log::AdminAuditLogEntity;
+
+/// 관리자 라우트 접근 기록을 저장한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `log`     - 저장할 접근 기록 (id, created_at은 DB에서 생성)
+/// 
+/// # Returns
+/// * `Ok(())`      - 저장 성공
+/// * `Err(String)` - 저장 실패
+pub async fn insert_admin_audit_log(
+    pool: &PgPool,
+    log: &AdminAuditLogEntity,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        INSERT INTO admin_audit_log (
+            actor,
+            auth_method,
+            method,
+            path,
+            query,
+            status_code,
+            allowed,
+            reason
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        ",
+    )
+    .bind(&log.actor)
+    .bind(&log.auth_method)
+    .bind(&log.method)
+    .bind(&log.path)
+    .bind(&log.query)
+    .bind(log.status_code)
+    .bind(log.allowed)
+    .bind(&log.reason)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("관리자 접근 기록 저장 실패: {}", e))?;
+
+    Ok(())
+}
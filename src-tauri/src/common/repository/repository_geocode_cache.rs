@@ -0,0 +1,103 @@
+/************************************************************************
+ * File: common/repository/repository_geocode_cache.rs
+ * Description:
+ *     geocode_cache 테이블에 대한 DB 연산 로직을 담당한다.
+ *     정규화된 주소별 좌표 변환 결과(성공 / 결과 없음)를 보관한다.
+ *
+ * Responsibilities:
+ *     1) find_geocode_cache_by_keys()
+ *         - 여러 주소 키의 캐시 항목 일괄 조회
+ * 
+ *     2) upsert_geocode_cache()
+ *         - 좌표 변환 결과 저장/갱신
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::common::entity::entity_geocode_cache::GeocodeCacheEntity;
+
+/// 여러 주소 키에 해당하는 캐시 항목을 한 번에 조회한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// * `keys` - 정규화된 주소 키 목록
+/// 
+/// # Returns
+/// * `Ok(Vec<GeocodeCacheEntity>)` - 캐시 항목 목록
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_geocode_cache_by_keys(
+    pool: &PgPool,
+    keys: &[String],
+) -> Result<Vec<GeocodeCacheEntity>, String> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    sqlx::query_as::<_, GeocodeCacheEntity>(
+        "
+        SELECT *
+        FROM geocode_cache
+        WHERE address_key = ANY($1)
+        ",
+    )
+    .bind(keys)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("좌표 캐시 조회 실패: {}", e))
+}
+
+/// 좌표 변환 결과를 캐시에 저장한다.
+/// 동일한 주소 키가 존재하면 결과와 조회 시각을 갱신한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `address_key` - 정규화된 주소 키
+/// * `address`     - 조회에 사용한 원본 주소
+/// * `coords`      - 변환 결과 (lat, lon). None이면 결과 없음으로 저장
+/// * `provider`    - 좌표 제공자
+/// 
+/// # Returns
+/// * `Ok(())`      - 저장 성공
+/// * `Err(String)` - 저장 실패
+pub async fn upsert_geocode_cache(
+    pool: &PgPool,
+    address_key: &str,
+    address: &str,
+    coords: Option<(f64, f64)>,
+    provider: &str,
+) -> Result<(), String> {
+    let status = if coords.is_some() { "found" } else { "not_found" };
+
+    sqlx::query(
+        "
+        INSERT INTO geocode_cache (
+            address_key, 
+            address, 
+            status, 
+            lat, 
+            lon, 
+            provider
+        )
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (address_key)
+        DO UPDATE SET 
+            address = EXCLUDED.address, 
+            status = EXCLUDED.status, 
+            lat = EXCLUDED.lat, 
+            lon = EXCLUDED.lon, 
+            provider = EXCLUDED.provider, 
+            geocoded_at = NOW()
+        ",
+    )
+    .bind(address_key)
+    .bind(address)
+    .bind(status)
+    .bind(coords.map(|c| c.0))
+    .bind(coords.map(|c| c.1))
+    .bind(provider)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("좌표 캐시 저장 실패: {}", e))?;
+
+    Ok(())
+}
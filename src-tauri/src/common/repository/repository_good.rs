@@ -4,84 +4,381 @@
  *     goods 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) insert_or_update_good()  
- *         - 상품 정보 저장
+ *     1) insert_or_update_goods_bulk()  
+ *         - 상품 정보 일괄 저장 및 변경 이력(master_history) 기록
  * 
- *     2) get_all_goods()          
- *         - 상품 정보 전체 조회
+ *     2) deactivate_goods_not_in()
+ *         - 최신 전체 목록에 없는 상품 비활성화 및 변경 이력 기록
+ * 
+ *     3) find_all_goods()          
+ *         - 상품 정보 전체 조회 (비활성 상품 포함 여부 / 상품분류 코드 필터 선택)
+ * 
+ *     4) find_goods_for_search_index()
+ *         - 검색 인덱스용 활성 상품명 / 인기도 조회
+ * 
+ *     5) search_goods()
+ *         - 부분 일치 / trigram 유사도 / 초성 일치 상품 검색 (관련도 → 인기도 순)
+ * 
+ *     6) find_good_ids_by_name()
+ *         - 상품명이 일치하는 전체 상품 ID 조회
 ************************************************************************/
 
 use sqlx::PgPool;
 
-use crate::common::entity::entity_good::GoodEntity;
+use crate::common::{
+    entity::entity_good::{GoodEntity, GoodSearchEntity},
+    repository::BULK_CHUNK_SIZE,
+};
 
-/// goods 테이블에 상품 정보를 저장한다.
-/// 동일한 good_id가 존재하면 업데이트한다.
+/// goods 테이블에 여러 상품 정보를 저장한다.
+/// 동일한 good_id가 존재하면 업데이트하며,
+/// 저장된 상품은 활성 상태로 표시하고 마지막 확인 시각을 갱신한다.
+/// 기존 값과 달라지는 필드는 갱신 전에 master_history에 기존 값 / 새 값을 기록한다.
+/// BULK_CHUNK_SIZE 단위로 나누어 하나의 트랜잭션 안에서 처리한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
-/// * `good` - 저장 / 업데이트할 상품 엔터티
+/// * `pool`    - DB 커넥션 풀
+/// * `goods`   - 저장 / 업데이트할 상품 엔터티 목록
+/// * `run_id`  - 변경을 일으킨 동기화 실행 기록 ID
 /// 
 /// # Returns
-/// * `Ok(())`        - 저장/업데이트 성공  
+/// * `Ok(u64)`       - 저장/업데이트된 행 수
 /// * `Err(String)`   - 삽입 실패
-pub async fn insert_or_update_good (
+pub async fn insert_or_update_goods_bulk(
     pool: &PgPool, 
-    good: &GoodEntity
-) -> Result<(), String> {
-    sqlx::query(
-        "
-        INSERT INTO goods (
-            good_id, 
-            good_name, 
-            total_cnt, 
-            total_div_code
+    goods: &[GoodEntity],
+    run_id: i32,
+) -> Result<u64, String> {
+    if goods.is_empty() {
+        return Ok(0);
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let mut affected = 0;
+
+    for chunk in goods.chunks(BULK_CHUNK_SIZE) {
+        let good_ids: Vec<String> = chunk.iter().map(|g| g.good_id.clone()).collect();
+        let good_names: Vec<String> = chunk.iter().map(|g| g.good_name.clone()).collect();
+        let total_cnts: Vec<Option<i32>> = chunk.iter().map(|g| g.total_cnt).collect();
+        let total_div_codes: Vec<Option<String>> =
+            chunk.iter().map(|g| g.total_div_code.clone()).collect();
+        let category_codes: Vec<Option<String>> =
+            chunk.iter().map(|g| g.category_code.clone()).collect();
+        let unit_codes: Vec<Option<String>> = chunk.iter().map(|g| g.unit_code.clone()).collect();
+
+        let result = sqlx::query(
+            "
+            WITH incoming AS (
+                SELECT DISTINCT ON (good_id)
+                    good_id, 
+                    good_name, 
+                    total_cnt, 
+                    total_div_code,
+                    category_code,
+                    unit_code
+                FROM UNNEST(
+                    $1::varchar[], 
+                    $2::varchar[], 
+                    $3::int[], 
+                    $4::varchar[],
+                    $5::varchar[],
+                    $6::varchar[]
+                ) WITH ORDINALITY AS t(
+                    good_id, 
+                    good_name, 
+                    total_cnt, 
+                    total_div_code, 
+                    category_code,
+                    unit_code,
+                    ord
+                )
+                ORDER BY good_id, ord DESC
+            ),
+            history AS (
+                INSERT INTO master_history (run_id, entity, entity_id, field, old_value, new_value)
+                SELECT $7, 'good', g.good_id, f.field, f.old_value, f.new_value
+                FROM incoming i
+                JOIN goods g ON g.good_id = i.good_id
+                CROSS JOIN LATERAL (
+                    VALUES
+                        ('good_name', g.good_name::text, i.good_name::text),
+                        ('total_cnt', g.total_cnt::text, i.total_cnt::text),
+                        ('total_div_code', g.total_div_code::text, i.total_div_code::text),
+                        ('category_code', g.category_code::text, i.category_code::text),
+                        ('unit_code', g.unit_code::text, i.unit_code::text),
+                        ('is_active', g.is_active::text, 'true')
+                ) AS f(field, old_value, new_value)
+                WHERE f.old_value IS DISTINCT FROM f.new_value
+            )
+            INSERT INTO goods (
+                good_id, 
+                good_name, 
+                total_cnt, 
+                total_div_code,
+                category_code,
+                unit_code
+            )
+            SELECT
+                good_id, 
+                good_name, 
+                total_cnt, 
+                total_div_code,
+                category_code,
+                unit_code
+            FROM incoming
+            ON CONFLICT (good_id)
+            DO UPDATE SET
+                good_name = EXCLUDED.good_name,
+                total_cnt = EXCLUDED.total_cnt,
+                total_div_code = EXCLUDED.total_div_code,
+                category_code = EXCLUDED.category_code,
+                unit_code = EXCLUDED.unit_code,
+                updated_at = NOW(),
+                is_active = TRUE,
+                last_seen_at = NOW()
+            ",
         )
-        VALUES (
-            $1, 
-            $2, 
-            $3, 
-            $4
+        .bind(good_ids)
+        .bind(good_names)
+        .bind(total_cnts)
+        .bind(total_div_codes)
+        .bind(category_codes)
+        .bind(unit_codes)
+        .bind(run_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("상품 데이터 일괄 업데이트 실패: {}", e))?;
+
+        affected += result.rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
+
+    Ok(affected)
+}
+
+/// 최신 전체 목록에 없는 활성 상품을 비활성 상태로 표시하고 변경 이력을 기록한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `seen_ids`    - 최신 전체 목록에 포함된 good_id 목록
+/// * `run_id`      - 변경을 일으킨 동기화 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 비활성화된 상품 수
+/// * `Err(String)` - 갱신 실패
+pub async fn deactivate_goods_not_in(
+    pool: &PgPool,
+    seen_ids: &[String],
+    run_id: i32,
+) -> Result<u64, String> {
+    let deactivated: i64 = sqlx::query_scalar(
+        "
+        WITH deactivated AS (
+            UPDATE goods
+            SET is_active = FALSE,
+                updated_at = NOW()
+            WHERE is_active
+                AND good_id <> ALL($1::varchar[])
+            RETURNING good_id
+        ),
+        history AS (
+            INSERT INTO master_history (run_id, entity, entity_id, field, old_value, new_value)
+            SELECT $2, 'good', good_id, 'is_active', 'true', 'false'
+            FROM deactivated
         )
-        ON CONFLICT (good_id)
-        DO UPDATE SET
-            good_name = EXCLUDED.good_name,
-            total_cnt = EXCLUDED.total_cnt,
-            total_div_code = EXCLUDED.total_div_code,
-            updated_at = NOW()
+        SELECT COUNT(*) FROM deactivated
         ",
     )
-    .bind(&good.good_id)
-    .bind(&good.good_name)
-    .bind(good.total_cnt)
-    .bind(&good.total_div_code)
-    .fetch_optional(pool)
+    .bind(seen_ids)
+    .bind(run_id)
+    .fetch_one(pool)
     .await
-    .map_err(|e| format!("상품 데이터 업데이트 실패: {}", e))?;
+    .map_err(|e| format!("상품 비활성화 실패: {}", e))?;
 
-    Ok(())
+    Ok(deactivated as u64)
 }
 
 /// 전체 상품 목록을 조회한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`                - DB 커넥션 풀
+/// * `include_inactive`    - 업스트림 목록에서 사라진 비활성 상품 포함 여부
+/// * `category_code`       - 상품분류 코드 (없으면 전체)
 /// 
 /// # Returns
 /// * `Ok(Vec<GoodEntity>)` - 전체 상품 목록  
 /// * `Err(String)`         - 조회 실패
 pub async fn find_all_goods(
-    pool: &PgPool
+    pool: &PgPool,
+    include_inactive: bool,
+    category_code: Option<&str>,
 ) -> Result<Vec<GoodEntity>, String> {
     let rows = sqlx::query_as::<_, GoodEntity>(
         "
         SELECT * FROM goods
+        WHERE ($1 OR is_active)
+            AND ($2::varchar IS NULL OR category_code = $2)
         ORDER BY good_id::int ASC
         ",
     )
+    .bind(include_inactive)
+    .bind(category_code)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("쿼리 실패: {}", e))?;
 
     Ok(rows)
 }
+
+/// 검색 인덱스를 만들기 위해 활성 상품의 상품명과 인기도(사용자 선택 수)를 조회한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Vec<GoodSearchEntity>)`   - 활성 상품 목록 (relevance는 0)
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_goods_for_search_index(
+    pool: &PgPool
+) -> Result<Vec<GoodSearchEntity>, String> {
+    let rows = sqlx::query_as::<_, GoodSearchEntity>(
+        "
+        SELECT
+            g.good_id,
+            g.good_name,
+            g.category_code,
+            0::float8 AS relevance,
+            COALESCE(p.cnt, 0) AS popularity
+        FROM goods g
+        LEFT JOIN (
+            SELECT
+                good_id,
+                COUNT(*) AS cnt
+            FROM user_selection_log
+            GROUP BY good_id
+        ) p
+            ON p.good_id = g.good_id
+        WHERE g.is_active
+        ",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("검색 인덱스용 상품 조회 실패: {}", e))?;
+
+    Ok(rows)
+}
+
+/// 활성 상품을 상품명 부분 일치 / trigram 유사도 / 초성 일치로 검색한다.
+/// 
+/// 관련도는 완전 일치(1.0) > 앞부분 일치(0.9) > 부분 일치(0.8) > 초성 일치(0.7) >
+/// trigram 유사도(0~0.7) 순이며, 관련도가 같으면 인기도(사용자 선택 수)가 높은 순으로 정렬한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `q`               - 검색어
+/// * `choseong_ids`    - 초성 검색으로 일치한 상품 ID 목록
+/// * `limit`           - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Vec<GoodSearchEntity>)`   - 검색 결과
+/// * `Err(String)`                 - 조회 실패
+pub async fn search_goods(
+    pool: &PgPool,
+    q: &str,
+    choseong_ids: &[String],
+    limit: i64,
+) -> Result<Vec<GoodSearchEntity>, String> {
+    // LIKE 패턴의 특수 문자는 그대로 검색되도록 이스케이프한다.
+    let escaped = q
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    let rows = sqlx::query_as::<_, GoodSearchEntity>(
+        "
+        WITH matched AS (
+            SELECT
+                g.good_id,
+                g.good_name,
+                g.category_code,
+                CASE
+                    WHEN LOWER(g.good_name) = LOWER($1) THEN 1.0
+                    WHEN g.good_name ILIKE $2 || '%' THEN 0.9
+                    WHEN g.good_name ILIKE '%' || $2 || '%' THEN 0.8
+                    WHEN g.good_id = ANY($3) THEN 0.7
+                    ELSE similarity(g.good_name, $1) * 0.7
+                END::float8 AS relevance
+            FROM goods g
+            WHERE g.is_active
+                AND (
+                    g.good_name ILIKE '%' || $2 || '%'
+                    OR g.good_name % $1
+                    OR g.good_id = ANY($3)
+                )
+        )
+        SELECT
+            m.good_id,
+            m.good_name,
+            m.category_code,
+            m.relevance,
+            COALESCE(p.cnt, 0) AS popularity
+        FROM matched m
+        LEFT JOIN (
+            SELECT
+                good_id,
+                COUNT(*) AS cnt
+            FROM user_selection_log
+            WHERE good_id IN (SELECT good_id FROM matched)
+            GROUP BY good_id
+        ) p
+            ON p.good_id = m.good_id
+        ORDER BY m.relevance DESC, popularity DESC, m.good_name ASC
+        LIMIT $4
+        ",
+    )
+    .bind(q)
+    .bind(escaped)
+    .bind(choseong_ids)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("상품 검색 실패: {}", e))?;
+
+    Ok(rows)
+}
+
+/// 상품명이 정확히 일치하는 모든 상품 ID를 조회한다.
+/// 같은 이름의 상품이 여러 개일 수 있으므로 전부 반환한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `good_name`   - 상품명
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 상품 ID 목록
+/// * `Err(String)`     - 조회 실패
+pub async fn find_good_ids_by_name(
+    pool: &PgPool,
+    good_name: &str,
+) -> Result<Vec<String>, String> {
+    let ids = sqlx::query_scalar::<_, String>(
+        "
+        SELECT good_id
+        FROM goods
+        WHERE good_name = $1
+        ORDER BY good_id
+        ",
+    )
+    .bind(good_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("상품 ID 조회 실패: {}", e))?;
+
+    Ok(ids)
+}
@@ -0,0 +1,231 @@
+/************************************************************************
+ * File: common/repository/repository_inspect_day.rs
+ * Description:
+ *     inspect_days 테이블에 대한 DB 연산 로직을 담당한다.
+ *     공공데이터에 게시된 조사일과 조사일별 가격 동기화 상태를 관리한다.
+ *
+ * Responsibilities:
+ *     1) insert_inspect_days_if_not_exists()
+ *         - 새로 발견한 조사일 일괄 등록
+ * 
+ *     2) mark_inspect_day_syncing()
+ *         - 조사일 가격 동기화 시작 상태 기록
+ * 
+ *     3) finish_inspect_day()
+ *         - 조사일 가격 동기화 결과 상태 기록
+ * 
+ *     4) find_inspect_days_between()
+ *         - 기간 내 조사일 목록 조회
+ * 
+ *     5) find_all_inspect_days()
+ *         - 데이터가 있는 전체 조사일 목록 조회
+ * 
+ *     6) find_latest_inspect_day()
+ *         - 데이터가 있는 가장 최근 조사일 조회
+************************************************************************/
+
+use sqlx::{PgPool, Row};
+
+use crate::common::entity::entity_inspect_day::InspectDayEntity;
+
+/// 조사일 목록을 'discovered' 상태로 등록한다.
+/// 이미 등록된 조사일은 건드리지 않는다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `days`    - 조사일 목록(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 새로 등록된 조사일 목록
+/// * `Err(String)`     - 등록 실패
+pub async fn insert_inspect_days_if_not_exists(
+    pool: &PgPool,
+    days: &[String],
+) -> Result<Vec<String>, String> {
+    if days.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let rows = sqlx::query(
+        "
+        INSERT INTO inspect_days (inspect_day)
+        SELECT UNNEST($1::varchar[])
+        ON CONFLICT (inspect_day) DO NOTHING
+        RETURNING inspect_day
+        ",
+    )
+    .bind(days)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("조사일 등록 실패: {}", e))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| row.get::<String, _>("inspect_day"))
+        .collect())
+}
+
+/// 조사일의 가격 동기화 시작을 기록한다.
+/// 등록되지 않은 조사일이면 새로 등록한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// * `run_id`      - 가격 동기화 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(())`      - 기록 성공
+/// * `Err(String)` - 기록 실패
+pub async fn mark_inspect_day_syncing(
+    pool: &PgPool,
+    inspect_day: &str,
+    run_id: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        INSERT INTO inspect_days (
+            inspect_day, 
+            status, 
+            last_run_id
+        )
+        VALUES ($1, 'syncing', $2)
+        ON CONFLICT (inspect_day)
+        DO UPDATE SET 
+            status = 'syncing', 
+            last_run_id = EXCLUDED.last_run_id, 
+            updated_at = NOW()
+        ",
+    )
+    .bind(inspect_day)
+    .bind(run_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("조사일 동기화 상태 기록 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// 조사일의 가격 동기화 결과를 기록한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `inspect_day`     - 조사일(YYYYMMDD)
+/// * `status`          - 결과 상태 ("synced" | "partial" | "failed" | "no_data")
+/// * `saved_prices`    - 저장된 가격 데이터 수
+/// * `failed_stores`   - 동기화에 실패한 매장 수
+/// 
+/// # Returns
+/// * `Ok(())`      - 기록 성공
+/// * `Err(String)` - 기록 실패
+pub async fn finish_inspect_day(
+    pool: &PgPool,
+    inspect_day: &str,
+    status: &str,
+    saved_prices: i32,
+    failed_stores: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        UPDATE inspect_days
+        SET 
+            status = $2, 
+            saved_prices = $3, 
+            failed_stores = $4, 
+            synced_at = CASE 
+                WHEN $2 IN ('synced', 'partial') THEN NOW() 
+                ELSE synced_at 
+            END,
+            updated_at = NOW()
+        WHERE inspect_day = $1
+        ",
+    )
+    .bind(inspect_day)
+    .bind(status)
+    .bind(saved_prices)
+    .bind(failed_stores)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("조사일 동기화 결과 기록 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// 기간 내에 등록된 조사일 목록을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `from`    - 시작 조사일(YYYYMMDD)
+/// * `to`      - 종료 조사일(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(Vec<InspectDayEntity>)`   - 조사일 목록 (오름차순)
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_inspect_days_between(
+    pool: &PgPool,
+    from: &str,
+    to: &str,
+) -> Result<Vec<InspectDayEntity>, String> {
+    sqlx::query_as::<_, InspectDayEntity>(
+        "
+        SELECT *
+        FROM inspect_days
+        WHERE inspect_day BETWEEN $1 AND $2
+        ORDER BY inspect_day
+        ",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("조사일 목록 조회 실패: {}", e))
+}
+
+/// 조사 데이터가 있는 전체 조사일 목록을 최신순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Vec<InspectDayEntity>)`   - 조사일 목록 (내림차순)
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_all_inspect_days(
+    pool: &PgPool
+) -> Result<Vec<InspectDayEntity>, String> {
+    sqlx::query_as::<_, InspectDayEntity>(
+        "
+        SELECT *
+        FROM inspect_days
+        WHERE status <> 'no_data'
+        ORDER BY inspect_day DESC
+        ",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("조사일 목록 조회 실패: {}", e))
+}
+
+/// 조사 데이터가 있는 가장 최근 조사일을 조회한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Some(InspectDayEntity))`  - 가장 최근 조사일
+/// * `Ok(None)`                    - 등록된 조사일 없음
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_latest_inspect_day(
+    pool: &PgPool
+) -> Result<Option<InspectDayEntity>, String> {
+    sqlx::query_as::<_, InspectDayEntity>(
+        "
+        SELECT *
+        FROM inspect_days
+        WHERE status <> 'no_data'
+        ORDER BY inspect_day DESC
+        LIMIT 1
+        ",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("최근 조사일 조회 실패: {}", e))
+}
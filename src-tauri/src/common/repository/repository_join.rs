@@ -4,8 +4,8 @@
  *     여러 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) find_latest_prices_by_good_name()    
- *         - 상품명 기준 매장별 최신 가격 조회
+ *     1) find_latest_prices()    
+ *         - 상품 ID 목록 기준 (상품, 매장)별 최신 가격 조회 (매장 / 지역 / 비활성 매장 포함 여부 선택)
  * 
  *     2) find_user_selection_logs()           
  *         - 사용자 선택 로그 조회
@@ -15,58 +15,97 @@
  * 
  *     4) find_price_rise_top()                
  *         - 가격 상승 상위 50개 조회
+ * 
+ *     5) find_latest_prices_with_distance()
+ *         - 상품 기준 매장별 최신 가격과 기준 좌표로부터의 거리 조회
+ * 
+ *     6) find_latest_basket_prices_nearby()
+ *         - 여러 상품의 (상품, 매장)별 최신 가격을 기준 좌표 반경 내 매장에 한해 조회
 ************************************************************************/
 
-use crate::domain::{
-    user_log::dto::dto_res::UserSelectionLogRes, price::dto::dto_res::PriceRes,
-    price_change::dto::dto_res::PriceTrendRes,
+use crate::{
+    common::{
+        entity::entity_price::{GoodStorePriceEntity, StorePriceDistanceEntity},
+        repository::{
+            repository_region::REGION_MAX_DEPTH,
+            repository_store::{bounding_box_deltas, EARTH_RADIUS_KM},
+        },
+    },
+    domain::{
+        user_log::dto::dto_res::UserSelectionLogRes, price::dto::dto_res::PriceRes,
+        price_change::dto::dto_res::PriceTrendRes,
+    },
 };
 use sqlx::PgPool;
 
-/// 상품명을 기준으로 매장별 최신 가격 정보를 조회한다.
+/// 상품 ID 목록을 기준으로 (상품, 매장)별 최신 가격 정보를 조회한다.
+/// 매장 목록이나 지역 코드(모든 하위 지역 포함)를 지정하면 해당 매장만 조회한다.
+/// 업스트림 목록에서 사라진 비활성 매장은 include_inactive일 때만 포함한다.
 ///
 /// # Arguments
 /// * `pool`        - DB 커넥션 풀
-/// * `good_name`   - 조회할 상품명
+/// * `good_ids`    - 조회할 상품 ID 목록
+/// * `store_ids`   - 매장 ID 목록 (없으면 전체)
+/// * `region_code` - 지역 코드 (없으면 전체)
+/// * `include_inactive`    - 비활성 매장 포함 여부
 /// 
 /// # Returns
-/// * `Ok(Vec<PriceRes>)`   - 매장별 최신 가격 목록
+/// * `Ok(Vec<PriceRes>)`   - (상품, 매장)별 최신 가격 목록
 /// * `Err(String)`         - 조회 실패
-pub async fn find_latest_prices_by_good_name(
+pub async fn find_latest_prices(
     pool: &PgPool,
-    good_name: &str,
+    good_ids: &[String],
+    store_ids: Option<&[String]>,
+    region_code: Option<&str>,
+    include_inactive: bool,
 ) -> Result<Vec<PriceRes>, String> {
+    if good_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
     let rows = sqlx::query_as::<_, PriceRes>(
         "
-        SELECT 
-            p.store_id, 
-            p.price, 
-            p.inspect_day
-        FROM prices p
-        JOIN (
-            SELECT 
-                store_id, 
-                MAX(inspect_day) AS latest_day
-            FROM prices
-            WHERE good_id = (
-                SELECT good_id 
-                FROM goods 
-                WHERE good_name = $1 
-                LIMIT 1
-            )
-            GROUP BY store_id
-        ) latest
-            ON p.store_id = latest.store_id 
-            AND p.inspect_day = latest.latest_day
-        WHERE p.good_id = (
-            SELECT good_id 
-            FROM goods 
-            WHERE good_name = $1 
-            LIMIT 1
+        WITH RECURSIVE scope AS (
+            SELECT $3::varchar AS code, 0 AS depth
+            WHERE $3::varchar IS NOT NULL
+            UNION
+            SELECT r.code, s.depth + 1
+            FROM regions r
+            JOIN scope s ON r.parent_code = s.code
+            WHERE s.depth < $4
+        ),
+        latest AS (
+            SELECT DISTINCT ON (p.good_id, p.store_id)
+                p.good_id,
+                p.store_id,
+                p.price,
+                p.inspect_day,
+                p.is_one_plus_one = 'Y' AS is_one_plus_one,
+                p.is_discount = 'Y' AS is_discount,
+                p.discount_start,
+                p.discount_end
+            FROM prices p
+            WHERE p.good_id = ANY($1)
+                AND ($2::varchar[] IS NULL OR p.store_id = ANY($2))
+            ORDER BY p.good_id, p.store_id, p.inspect_day DESC
         )
+        SELECT l.*
+        FROM latest l
+        JOIN stores s ON s.store_id = l.store_id
+        WHERE ($5 OR s.is_active)
+            AND (
+                $3::varchar IS NULL
+                OR s.area_code IN (SELECT code FROM scope)
+                OR s.area_detail_code IN (SELECT code FROM scope)
+            )
+        ORDER BY l.good_id, l.store_id
         ",
     )
-    .bind(good_name)
+    .bind(good_ids)
+    .bind(store_ids)
+    .bind(region_code)
+    .bind(REGION_MAX_DEPTH)
+    .bind(include_inactive)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("가격 데이터 조회 실패: {}", e))?;
@@ -215,3 +254,161 @@ pub async fn fetch_price_rise_top(
 
     Ok(rows)
 }
+
+/// 상품 ID를 기준으로 활성 매장별 최신 가격과 기준 좌표로부터의 대원 거리(km)를 조회한다.
+/// 반경을 지정하면 반경 밖 매장은 제외한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `good_id`     - 조회할 상품 ID
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (km, 없으면 전체)
+/// 
+/// # Returns
+/// * `Ok(Vec<StorePriceDistanceEntity>)`   - 매장별 최신 가격 및 거리 목록
+/// * `Err(String)`                         - 조회 실패
+pub async fn find_latest_prices_with_distance(
+    pool: &PgPool,
+    good_id: &str,
+    lat: f64,
+    lon: f64,
+    radius_km: Option<f64>,
+) -> Result<Vec<StorePriceDistanceEntity>, String> {
+    let rows = sqlx::query_as::<_, StorePriceDistanceEntity>(
+        "
+        WITH latest AS (
+            SELECT DISTINCT ON (p.store_id)
+                p.store_id,
+                p.price,
+                p.inspect_day
+            FROM prices p
+            WHERE p.good_id = $1
+            ORDER BY p.store_id, p.inspect_day DESC
+        ),
+        candidates AS (
+            SELECT
+                s.store_id,
+                s.store_name,
+                s.x_coord,
+                s.y_coord,
+                l.price,
+                l.inspect_day,
+                2 * $4 * ASIN(LEAST(1.0, SQRT(
+                    POWER(SIN(RADIANS(s.x_coord - $2) / 2), 2)
+                    + COS(RADIANS($2)) * COS(RADIANS(s.x_coord))
+                        * POWER(SIN(RADIANS(s.y_coord - $3) / 2), 2)
+                ))) AS distance_km
+            FROM latest l
+            JOIN stores s
+                ON s.store_id = l.store_id
+            WHERE s.is_active
+                AND s.x_coord IS NOT NULL
+                AND s.y_coord IS NOT NULL
+        )
+        SELECT *
+        FROM candidates
+        WHERE $5::float8 IS NULL OR distance_km <= $5
+        ORDER BY store_id::bigint ASC
+        ",
+    )
+    .bind(good_id)
+    .bind(lat)
+    .bind(lon)
+    .bind(EARTH_RADIUS_KM)
+    .bind(radius_km)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("매장별 최신 가격 / 거리 조회 실패: {}", e))?;
+
+    Ok(rows)
+}
+
+/// 여러 상품에 대해 (상품, 매장)별 최신 가격을 기준 좌표 반경 내 활성 매장에 한해 조회한다.
+/// 위도 / 경도 범위(bounding box)로 매장을 먼저 거른 뒤 대원 거리로 반경 밖 매장을 제외한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `good_ids`    - 조회할 상품 ID 목록
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (km)
+/// 
+/// # Returns
+/// * `Ok(Vec<GoodStorePriceEntity>)`   - (상품, 매장)별 최신 가격 및 거리 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn find_latest_basket_prices_nearby(
+    pool: &PgPool,
+    good_ids: &[String],
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+) -> Result<Vec<GoodStorePriceEntity>, String> {
+    if good_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let (lat_delta, lon_delta) = bounding_box_deltas(lat, radius_km);
+
+    let rows = sqlx::query_as::<_, GoodStorePriceEntity>(
+        "
+        WITH nearby AS (
+            SELECT
+                s.store_id,
+                s.store_name,
+                s.x_coord,
+                s.y_coord,
+                2 * $4 * ASIN(LEAST(1.0, SQRT(
+                    POWER(SIN(RADIANS(s.x_coord - $2) / 2), 2)
+                    + COS(RADIANS($2)) * COS(RADIANS(s.x_coord))
+                        * POWER(SIN(RADIANS(s.y_coord - $3) / 2), 2)
+                ))) AS distance_km
+            FROM stores s
+            WHERE s.is_active
+                AND s.x_coord BETWEEN $2 - $6 AND $2 + $6
+                AND s.y_coord BETWEEN $3 - $7 AND $3 + $7
+        ),
+        latest AS (
+            SELECT DISTINCT ON (p.good_id, p.store_id)
+                p.good_id,
+                p.store_id,
+                p.price,
+                p.inspect_day
+            FROM prices p
+            JOIN nearby n
+                ON n.store_id = p.store_id
+            WHERE p.good_id = ANY($1)
+                AND n.distance_km <= $5
+            ORDER BY p.good_id, p.store_id, p.inspect_day DESC
+        )
+        SELECT
+            l.good_id,
+            g.good_name,
+            n.store_id,
+            n.store_name,
+            n.x_coord,
+            n.y_coord,
+            l.price,
+            l.inspect_day,
+            n.distance_km
+        FROM latest l
+        JOIN nearby n
+            ON n.store_id = l.store_id
+        JOIN goods g
+            ON g.good_id = l.good_id
+        ORDER BY n.distance_km ASC, n.store_id, l.good_id
+        ",
+    )
+    .bind(good_ids)
+    .bind(lat)
+    .bind(lon)
+    .bind(EARTH_RADIUS_KM)
+    .bind(radius_km)
+    .bind(lat_delta)
+    .bind(lon_delta)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("장바구니 상품 가격 조회 실패: {}", e))?;
+
+    Ok(rows)
+}
@@ -0,0 +1,106 @@
+/************************************************************************
+ * File: common/repository/repository_lock.rs
+ * Description:
+ *     PostgreSQL advisory lock을 이용한 인스턴스 간 작업 잠금을 담당한다.
+ *
+ * Responsibilities:
+ *     1) AdvisoryLock
+ *         - 잠금을 보유한 커넥션 보관
+ *         - 해제되지 않은 채 drop되면 커넥션을 풀에서 분리해 닫음
+ * 
+ *     2) try_lock()
+ *         - 전용 커넥션에서 세션 단위 advisory lock 획득 시도
+ * 
+ *     3) unlock()
+ *         - 획득한 advisory lock 해제
+************************************************************************/
+
+use sqlx::{pool::PoolConnection, PgPool, Postgres, Row};
+
+/// 정기 동기화 파이프라인 잠금 키
+pub const SYNC_PIPELINE_LOCK_KEY: i64 = 7_301_001;
+
+/// 세션 단위 advisory lock을 보유한 커넥션
+///
+/// 잠금이 해제되지 않은 커넥션이 풀로 돌아가면 이후 그 커넥션을 빌려 쓰는
+/// 다른 작업이 잠금을 계속 보유하게 된다. 따라서 unlock()을 거치지 않고
+/// drop되는 경우(패닉, 태스크 취소)에는 커넥션을 풀에서 분리해 닫아
+/// 세션 종료와 함께 잠금이 풀리도록 한다.
+///
+/// # Fields
+/// * `conn`    - 잠금을 보유한 커넥션 (해제 후 None)
+/// * `key`     - 잠금 키
+pub struct AdvisoryLock {
+    conn: Option<PoolConnection<Postgres>>,
+    key: i64,
+}
+
+impl Drop for AdvisoryLock {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            tracing::warn!("advisory lock {} 해제 전 종료 — 잠금 커넥션을 닫음", self.key);
+            drop(conn.detach());
+        }
+    }
+}
+
+/// 전용 커넥션을 하나 확보하여 세션 단위 advisory lock 획득을 시도한다.
+/// 잠금은 해당 커넥션에 묶이므로, 작업이 끝날 때까지 반환된 AdvisoryLock을 보관해야 한다.
+/// 프로세스가 비정상 종료되면 세션이 끊기면서 잠금도 함께 해제된다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `key`     - 잠금 키
+/// 
+/// # Returns
+/// * `Ok(Some(AdvisoryLock))`  - 잠금 획득 성공
+/// * `Ok(None)`                - 다른 세션이 이미 잠금을 보유 중
+/// * `Err(String)`             - 조회 실패
+pub async fn try_lock(
+    pool: &PgPool,
+    key: i64,
+) -> Result<Option<AdvisoryLock>, String> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| format!("잠금용 커넥션 획득 실패: {}", e))?;
+
+    let row = sqlx::query("SELECT pg_try_advisory_lock($1) AS locked")
+        .bind(key)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| format!("advisory lock 획득 실패: {}", e))?;
+
+    if row.get::<bool, _>("locked") {
+        Ok(Some(AdvisoryLock { conn: Some(conn), key }))
+    } else {
+        Ok(None)
+    }
+}
+
+/// try_lock()으로 획득한 advisory lock을 해제한다.
+/// 해제 쿼리가 실패하면 잠금이 남은 커넥션을 풀로 돌려보내지 않고 닫는다.
+/// 
+/// # Arguments
+/// * `lock`    - try_lock()으로 획득한 잠금
+/// 
+/// # Returns
+/// * `Ok(())`      - 해제 성공
+/// * `Err(String)` - 해제 실패 (커넥션은 닫혀 잠금이 풀림)
+pub async fn unlock(mut lock: AdvisoryLock) -> Result<(), String> {
+    let Some(mut conn) = lock.conn.take() else {
+        return Ok(());
+    };
+
+    let result = sqlx::query("SELECT pg_advisory_unlock($1)")
+        .bind(lock.key)
+        .execute(&mut *conn)
+        .await;
+
+    if let Err(e) = result {
+        drop(conn.detach());
+        return Err(format!("advisory lock 해제 실패: {}", e));
+    }
+
+    Ok(())
+}
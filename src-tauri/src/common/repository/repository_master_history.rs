@@ -0,0 +1,56 @@
+/************************************************************************
+ * File: common/repository/repository_master_history.rs
+ * Description:
+ *     master_history 테이블에 대한 DB 연산 로직을 담당한다.
+ *     매장 / 상품 기준 정보의 필드 변경 이력을 추가 전용으로 보관하며,
+ *     이력 기록은 repository_store / repository_good의 저장 쿼리에서 함께 수행한다.
+ *
+ * Responsibilities:
+ *     1) find_master_history()
+ *         - 특정 매장 / 상품의 변경 이력 조회
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::common::entity::entity_master_history::MasterHistoryEntity;
+
+/// 변경 이력 조회 시 기본 조회 수
+pub const HISTORY_DEFAULT_LIMIT: i64 = 100;
+
+/// 변경 이력 조회 시 최대 조회 수
+pub const HISTORY_MAX_LIMIT: i64 = 1000;
+
+/// 특정 매장 / 상품의 변경 이력을 최신순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `entity`      - 항목 종류 ("store" | "good")
+/// * `entity_id`   - store_id 또는 good_id
+/// * `limit`       - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Vec<MasterHistoryEntity>)`    - 변경 이력 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn find_master_history(
+    pool: &PgPool,
+    entity: &str,
+    entity_id: &str,
+    limit: i64,
+) -> Result<Vec<MasterHistoryEntity>, String> {
+    sqlx::query_as::<_, MasterHistoryEntity>(
+        "
+        SELECT *
+        FROM master_history
+        WHERE entity = $1
+            AND entity_id = $2
+        ORDER BY changed_at DESC, id DESC
+        LIMIT $3
+        ",
+    )
+    .bind(entity)
+    .bind(entity_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("변경 이력 조회 실패: {}", e))
+}
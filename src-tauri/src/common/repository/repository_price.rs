@@ -4,32 +4,62 @@
  *     prices 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) insert_or_update_price() 
- *         - 가격 정보 저장
+ *     1) insert_prices_bulk() 
+ *         - 가격 정보 일괄 저장
  * 
  *     2) find_prev_day()          
  *         - 기준일 이전의 가장 최근 inspect_day 조회
+ * 
+ *     3) find_next_day()
+ *         - 기준일 이후의 가장 이른 inspect_day 조회
+ * 
+ *     4) find_price_days_between()
+ *         - 기간 내 가격 데이터가 있는 inspect_day 목록 조회
 ************************************************************************/
 
 use crate::common::entity::entity_price::PriceEntity;
-use sqlx::Row;
 use sqlx::{PgPool, Result};
 
-/// prices 테이블에 가격 정보를 저장한다.
-/// 동일한 (good_id, store_id, inspect_day) 조합이 존재하면 업데이트한다.
+/// prices 테이블에 여러 가격 정보를 한 번의 쿼리로 저장한다.
+/// 동일한 (good_id, store_id, inspect_day) 조합이 존재하면 업데이트하며,
+/// 입력 안에서 키가 중복되면 마지막 항목을 사용한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
-/// * `price` - 저장 / 업데이트할 가격 엔터티
+/// * `pool`    - DB 커넥션 풀
+/// * `prices`  - 저장 / 업데이트할 가격 엔터티 목록
 /// 
 /// # Returns
-/// * `Ok(())`        - 저장/업데이트 성공  
+/// * `Ok(u64)`       - 저장/업데이트된 행 수
 /// * `Err(String)`   - 삽입 실패
-pub async fn insert_price_to_db(
+pub async fn insert_prices_bulk(
     pool: &PgPool, 
-    price: &PriceEntity
-) -> Result<(), String> {
-    sqlx::query_as::<_, PriceEntity>(
+    prices: &[PriceEntity]
+) -> Result<u64, String> {
+    if prices.is_empty() {
+        return Ok(0);
+    }
+
+    let mut good_ids = Vec::with_capacity(prices.len());
+    let mut store_ids = Vec::with_capacity(prices.len());
+    let mut inspect_days = Vec::with_capacity(prices.len());
+    let mut values = Vec::with_capacity(prices.len());
+    let mut one_plus_ones = Vec::with_capacity(prices.len());
+    let mut discounts = Vec::with_capacity(prices.len());
+    let mut discount_starts = Vec::with_capacity(prices.len());
+    let mut discount_ends = Vec::with_capacity(prices.len());
+
+    for price in prices {
+        good_ids.push(price.good_id.clone());
+        store_ids.push(price.store_id.clone());
+        inspect_days.push(price.inspect_day.clone());
+        values.push(price.price);
+        one_plus_ones.push(price.is_one_plus_one.clone());
+        discounts.push(price.is_discount.clone());
+        discount_starts.push(price.discount_start.clone());
+        discount_ends.push(price.discount_end.clone());
+    }
+
+    let result = sqlx::query(
         "
         INSERT INTO prices(
             good_id, 
@@ -42,17 +72,37 @@ pub async fn insert_price_to_db(
             discount_end, 
             created_at
         )
-        VALUES (
-            $1, 
-            $2, 
-            $3, 
-            $4, 
-            $5, 
-            $6, 
-            $7, 
-            $8, 
+        SELECT DISTINCT ON (good_id, store_id, inspect_day)
+            good_id, 
+            store_id, 
+            inspect_day, 
+            price, 
+            is_one_plus_one, 
+            is_discount, 
+            discount_start, 
+            discount_end, 
             NOW()
+        FROM UNNEST(
+            $1::varchar[], 
+            $2::varchar[], 
+            $3::varchar[], 
+            $4::int[], 
+            $5::varchar[], 
+            $6::varchar[], 
+            $7::varchar[], 
+            $8::varchar[]
+        ) WITH ORDINALITY AS t(
+            good_id, 
+            store_id, 
+            inspect_day, 
+            price, 
+            is_one_plus_one, 
+            is_discount, 
+            discount_start, 
+            discount_end, 
+            ord
         )
+        ORDER BY good_id, store_id, inspect_day, ord DESC
         ON CONFLICT (
             good_id, 
             store_id, 
@@ -67,19 +117,19 @@ pub async fn insert_price_to_db(
             created_at = NOW()
         ",
     )
-    .bind(&price.good_id)
-    .bind(&price.store_id)
-    .bind(&price.inspect_day)
-    .bind(&price.price)
-    .bind(&price.is_one_plus_one)
-    .bind(&price.is_discount)
-    .bind(&price.discount_start)
-    .bind(&price.discount_end)
-    .fetch_optional(pool)
+    .bind(good_ids)
+    .bind(store_ids)
+    .bind(inspect_days)
+    .bind(values)
+    .bind(one_plus_ones)
+    .bind(discounts)
+    .bind(discount_starts)
+    .bind(discount_ends)
+    .execute(pool)
     .await
-    .map_err(|e| format!("가격 데이터 업데이트 실패: {}", e))?;
+    .map_err(|e| format!("가격 데이터 일괄 업데이트 실패: {}", e))?;
 
-    Ok(())
+    Ok(result.rows_affected())
 }
 
 /// 기준일(latest_day)보다 이전 날짜 중 가장 최근의 inspect_day 값을 조회한다.
@@ -90,12 +140,13 @@ pub async fn insert_price_to_db(
 /// 
 /// # Returns
 /// * `Ok(Some(String))`    - 이전 inspect_day 조회 성공
+/// * `Ok(None)`            - 이전 조사일 없음
 /// * `Err(String)`         - 조회 실패
 pub async fn find_prev_day(
     pool: &PgPool, 
     latest_day: &str
-) -> Result<String, String> {
-    let row = sqlx::query(
+) -> Result<Option<String>, String> {
+    sqlx::query_scalar::<_, String>(
         "
         SELECT 
             inspect_day
@@ -106,11 +157,68 @@ pub async fn find_prev_day(
         ",
     )
     .bind(latest_day)
-    .fetch_one(pool)
+    .fetch_optional(pool)
     .await
-    .map_err(|e| format!("가격 데이터 업데이트 실패: {}", e))?;
+    .map_err(|e| format!("이전 조사일 조회 실패: {}", e))
+}
 
-    let prev_day = row.get::<String, _>("inspect_day");
+/// 기준일(day)보다 이후 날짜 중 가장 이른 inspect_day 값을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `day`     - 기준 날짜(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(Some(String))`    - 다음 inspect_day 조회 성공
+/// * `Ok(None)`            - 다음 조사일 없음
+/// * `Err(String)`         - 조회 실패
+pub async fn find_next_day(
+    pool: &PgPool, 
+    day: &str
+) -> Result<Option<String>, String> {
+    sqlx::query_scalar::<_, String>(
+        "
+        SELECT 
+            inspect_day
+        FROM prices
+        WHERE inspect_day > $1
+        ORDER BY inspect_day ASC
+        LIMIT 1
+        ",
+    )
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("다음 조사일 조회 실패: {}", e))
+}
 
-    Ok(prev_day)
+/// 기간 내 가격 데이터가 있는 inspect_day 목록을 오름차순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `from`    - 시작 조사일(YYYYMMDD)
+/// * `to`      - 종료 조사일(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 조사일 목록
+/// * `Err(String)`     - 조회 실패
+pub async fn find_price_days_between(
+    pool: &PgPool, 
+    from: &str,
+    to: &str,
+) -> Result<Vec<String>, String> {
+    sqlx::query_scalar::<_, String>(
+        "
+        SELECT DISTINCT
+            inspect_day
+        FROM prices
+        WHERE inspect_day BETWEEN $1 AND $2
+        ORDER BY inspect_day ASC
+        ",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("기간 내 조사일 조회 실패: {}", e))
 }
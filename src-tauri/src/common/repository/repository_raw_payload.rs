@@ -0,0 +1,167 @@
+/************************************************************************
+ * File: common/repository/repository_raw_payload.rs
+ * Description:
+ *     raw_payloads 테이블에 대한 DB 연산 로직을 담당한다.
+ *     업스트림 원본 응답을 요청 종류 / 파라미터 / 시각 / 상태 / 해시와 함께 보관한다.
+ *
+ * Responsibilities:
+ *     1) insert_raw_payload()
+ *         - 원본 응답 보관
+ * 
+ *     2) find_latest_raw_payload()
+ *         - 요청 종류.파라미터가 같은 가장 최근 정상 응답 조회
+ * 
+ *     3) count_raw_payloads_by_inspect_day()
+ *         - 특정 조사일의 보관된 가격 응답 수 조회
+ * 
+ *     4) delete_expired_raw_payloads()
+ *         - 보관 기간이 지난 원본 응답 삭제
+************************************************************************/
+
+use sqlx::{PgPool, Row};
+
+use crate::common::entity::entity_raw_payload::RawPayloadEntity;
+
+/// 업스트림 원본 응답을 보관한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `payload` - 보관할 원본 응답 (id, fetched_at은 DB에서 생성)
+/// 
+/// # Returns
+/// * `Ok(())`      - 저장 성공
+/// * `Err(String)` - 저장 실패
+pub async fn insert_raw_payload(
+    pool: &PgPool,
+    payload: &RawPayloadEntity,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        INSERT INTO raw_payloads (
+            upstream, 
+            endpoint, 
+            params, 
+            http_status, 
+            content_hash, 
+            body_gzip, 
+            body_size, 
+            error
+        )
+        VALUES ($1, $2, $3::jsonb, $4, $5, $6, $7, $8)
+        ",
+    )
+    .bind(&payload.upstream)
+    .bind(&payload.endpoint)
+    .bind(&payload.params)
+    .bind(payload.http_status)
+    .bind(&payload.content_hash)
+    .bind(&payload.body_gzip)
+    .bind(payload.body_size)
+    .bind(&payload.error)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("원본 응답 보관 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// 요청 종류와 파라미터가 같은 가장 최근의 정상 응답을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `endpoint`    - 요청 종류 (goods, stores, prices, standard_codes, geocode)
+/// * `params`      - 요청 파라미터 (JSON 문자열)
+/// 
+/// # Returns
+/// * `Ok(Some(RawPayloadEntity))`  - 보관된 응답
+/// * `Ok(None)`                    - 보관된 응답 없음
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_latest_raw_payload(
+    pool: &PgPool,
+    endpoint: &str,
+    params: &str,
+) -> Result<Option<RawPayloadEntity>, String> {
+    sqlx::query_as::<_, RawPayloadEntity>(
+        "
+        SELECT
+            id,
+            upstream,
+            endpoint,
+            params::text AS params,
+            http_status,
+            content_hash,
+            body_gzip,
+            body_size,
+            error,
+            fetched_at
+        FROM raw_payloads
+        WHERE endpoint = $1
+            AND params = $2::jsonb
+            AND body_gzip IS NOT NULL
+        ORDER BY fetched_at DESC, id DESC
+        LIMIT 1
+        ",
+    )
+    .bind(endpoint)
+    .bind(params)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("원본 응답 조회 실패: {}", e))
+}
+
+/// 특정 조사일에 대해 보관된 가격 정상 응답 수를 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `inspect_day` - 조사일 (YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(i64)`     - 보관된 응답 수
+/// * `Err(String)` - 조회 실패
+pub async fn count_raw_payloads_by_inspect_day(
+    pool: &PgPool,
+    inspect_day: &str,
+) -> Result<i64, String> {
+    let row = sqlx::query(
+        "
+        SELECT COUNT(*) AS cnt
+        FROM raw_payloads
+        WHERE endpoint = 'prices'
+            AND params->>'inspect_day' = $1
+            AND body_gzip IS NOT NULL
+        ",
+    )
+    .bind(inspect_day)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("원본 응답 수 조회 실패: {}", e))?;
+
+    Ok(row.get::<i64, _>("cnt"))
+}
+
+/// 보관 기간이 지난 원본 응답을 삭제한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `retention_days`  - 보관 기간 (일)
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 삭제된 응답 수
+/// * `Err(String)` - 삭제 실패
+pub async fn delete_expired_raw_payloads(
+    pool: &PgPool,
+    retention_days: i32,
+) -> Result<u64, String> {
+    let result = sqlx::query(
+        "
+        DELETE FROM raw_payloads
+        WHERE fetched_at < NOW() - make_interval(days => $1)
+        ",
+    )
+    .bind(retention_days)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("원본 응답 정리 실패: {}", e))?;
+
+    Ok(result.rows_affected())
+}
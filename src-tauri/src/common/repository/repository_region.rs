@@ -4,56 +4,111 @@
  *     regions 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) insert_region_codes_if_not_exists()   
- *         - 지역 코드 저장
+ *     1) upsert_regions_bulk()   
+ *         - 지역 코드 일괄 저장 / 갱신 (지역명, 상위 지역, 단계 변경 반영)
  * 
  *     2) get_all_region_codes()                
  *         - 전체 지역 코드 조회
+ * 
+ *     3) find_region_children()
+ *         - 특정 지역의 바로 아래 하위 지역 조회 (지역 미지정 시 최상위 지역)
+ * 
+ *     4) find_region_ancestors()
+ *         - 특정 지역의 상위 지역 전체 조회 (최상위 지역부터)
 ************************************************************************/
 
 use sqlx::PgPool;
 
-use crate::common::entity::entity_region::RegionEntity;
+use crate::common::{entity::entity_region::RegionEntity, repository::BULK_CHUNK_SIZE};
 
-/// 지역 코드를 DB에 삽입한다
+/// 상위 / 하위 지역을 재귀 조회할 때 따라가는 최대 단계 (잘못된 순환 참조 방지)
+pub const REGION_MAX_DEPTH: i32 = 32;
+
+/// 여러 지역 코드를 저장한다.
+/// 동일한 code가 존재하면 지역명 / 상위 지역 / 단계를 갱신하며,
+/// 입력 안에서 코드가 중복되면 마지막 항목을 사용한다.
+/// 상위 지역이 먼저 저장되도록 단계(level) 순으로 정렬된 목록을 받아
+/// BULK_CHUNK_SIZE 단위로 나누어 하나의 트랜잭션 안에서 처리한다.
 /// 
 /// # Arguments
 /// * `pool`    - DB 커넥션 풀
-/// * `regions` - 저장할지역 코드 엔터티
+/// * `regions` - 저장 / 업데이트할 지역 코드 엔터티 목록 (level 오름차순)
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 성공
+/// * `Ok(u64)`     - 새로 저장되었거나 값이 바뀐 행 수
 /// * `Err(String)` - 쿼리 실패
-pub async fn insert_region_codes_if_not_exists(
+pub async fn upsert_regions_bulk(
     pool: &PgPool, 
-    region: &RegionEntity
-) -> Result<(), String> {
-    sqlx::query_as::<_, RegionEntity>(
-        "
-        INSERT INTO regions (
-            code, 
-            name, 
-            parent_code, 
-            level
-        )
-        VALUES (
-            $1, 
-            $2, 
-            $3, 
-            $4
+    regions: &[RegionEntity]
+) -> Result<u64, String> {
+    if regions.is_empty() {
+        return Ok(0);
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let mut affected = 0;
+
+    for chunk in regions.chunks(BULK_CHUNK_SIZE) {
+        let codes: Vec<String> = chunk.iter().map(|r| r.code.clone()).collect();
+        let names: Vec<String> = chunk.iter().map(|r| r.name.clone()).collect();
+        let parent_codes: Vec<Option<String>> = chunk.iter().map(|r| r.parent_code.clone()).collect();
+        let levels: Vec<i16> = chunk.iter().map(|r| r.level).collect();
+
+        let result = sqlx::query(
+            "
+            INSERT INTO regions (
+                code, 
+                name, 
+                parent_code, 
+                level
+            )
+            SELECT DISTINCT ON (code)
+                code, 
+                name, 
+                parent_code, 
+                level
+            FROM UNNEST(
+                $1::varchar[], 
+                $2::varchar[], 
+                $3::varchar[], 
+                $4::smallint[]
+            ) WITH ORDINALITY AS t(
+                code, 
+                name, 
+                parent_code, 
+                level, 
+                ord
+            )
+            ORDER BY code, ord DESC
+            ON CONFLICT (code)
+            DO UPDATE SET
+                name = EXCLUDED.name,
+                parent_code = EXCLUDED.parent_code,
+                level = EXCLUDED.level,
+                updated_at = NOW()
+            WHERE (regions.name, regions.parent_code, regions.level)
+                IS DISTINCT FROM (EXCLUDED.name, EXCLUDED.parent_code, EXCLUDED.level)
+            ",
         )
-        ON CONFLICT (code)
-        DO NOTHING",
-    )
-    .bind(&region.code)
-    .bind(&region.name)
-    .bind(&region.parent_code)
-    .bind(&region.level)
-    .fetch_optional(pool)
-    .await
-    .map_err(|e| format!("지역코드 데이터 업데이트 실패: {}", e))?;
+        .bind(codes)
+        .bind(names)
+        .bind(parent_codes)
+        .bind(levels)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("지역코드 데이터 업데이트 실패: {}", e))?;
+
+        affected += result.rows_affected();
+    }
 
-    Ok(())
+    tx.commit()
+        .await
+        .map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
+
+    Ok(affected)
 }
 
 /// 전체 지역 코드 목록을 조회한다.
@@ -80,3 +135,77 @@ pub async fn get_all_region_codes(
 
     Ok(rows)
 }
+
+/// 특정 지역의 바로 아래 하위 지역 목록을 조회한다.
+/// 지역을 지정하지 않으면 상위 지역이 없는 최상위 지역 목록을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `parent_code` - 상위 지역 코드 (없으면 최상위)
+/// 
+/// # Returns
+/// * `Ok(Vec<RegionEntity>)`   - 하위 지역 목록
+/// * `Err(String)`             - 조회 실패
+pub async fn find_region_children(
+    pool: &PgPool,
+    parent_code: Option<&str>,
+) -> Result<Vec<RegionEntity>, String> {
+    sqlx::query_as::<_, RegionEntity>(
+        "
+        SELECT * 
+        FROM regions
+        WHERE parent_code IS NOT DISTINCT FROM $1
+        ORDER BY code::int ASC
+        ",
+    )
+    .bind(parent_code)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("하위 지역 조회 실패: {}", e))
+}
+
+/// 특정 지역의 상위 지역 전체를 최상위 지역부터 차례로 조회한다. (자기 자신 제외)
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `code`    - 지역 코드
+/// 
+/// # Returns
+/// * `Ok(Vec<RegionEntity>)`   - 상위 지역 목록 (최상위 → 바로 위 지역)
+/// * `Err(String)`             - 조회 실패
+pub async fn find_region_ancestors(
+    pool: &PgPool,
+    code: &str,
+) -> Result<Vec<RegionEntity>, String> {
+    sqlx::query_as::<_, RegionEntity>(
+        "
+        WITH RECURSIVE ancestors AS (
+            SELECT 
+                r.*, 
+                0 AS depth
+            FROM regions r
+            WHERE r.code = $1
+            UNION ALL
+            SELECT 
+                p.*, 
+                a.depth + 1
+            FROM regions p
+            JOIN ancestors a ON p.code = a.parent_code
+            WHERE a.depth < $2
+        )
+        SELECT 
+            code, 
+            name, 
+            parent_code, 
+            level
+        FROM ancestors
+        WHERE depth > 0
+        ORDER BY depth DESC
+        ",
+    )
+    .bind(code)
+    .bind(REGION_MAX_DEPTH)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("상위 지역 조회 실패: {}", e))
+}
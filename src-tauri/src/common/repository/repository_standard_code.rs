@@ -0,0 +1,139 @@
+/************************************************************************
+ * File: common/repository/repository_standard_code.rs
+ * Description:
+ *     표준코드 분류별 테이블(good_categories, units, store_types, regions)에 대한
+ *     DB 연산 로직을 담당한다.
+ *
+ * Responsibilities:
+ *     1) upsert_standard_codes_bulk()
+ *         - 분류별 표준코드 일괄 저장 / 갱신 (지역코드 제외)
+ * 
+ *     2) find_standard_codes()
+ *         - 분류별 표준코드 전체 조회
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::{
+    common::{entity::entity_standard_code::StandardCodeEntity, repository::BULK_CHUNK_SIZE},
+    domain::sync::dto::dto_req::StandardCodeClass,
+};
+
+/// 분류에 해당하는 테이블에 여러 표준코드를 저장한다.
+/// 동일한 code가 존재하면 코드명 / 상위 코드를 갱신하며,
+/// 입력 안에서 코드가 중복되면 마지막 항목을 사용한다.
+/// 지역코드는 계층(level) 정보가 필요하므로 regions 전용 저장 로직을 사용한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `class`   - 표준코드 분류
+/// * `codes`   - 저장 / 업데이트할 표준코드 엔터티 목록
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 저장/업데이트된 행 수
+/// * `Err(String)` - 지역코드 분류이거나 저장 실패
+pub async fn upsert_standard_codes_bulk(
+    pool: &PgPool,
+    class: StandardCodeClass,
+    codes: &[StandardCodeEntity],
+) -> Result<u64, String> {
+    if class == StandardCodeClass::Region {
+        return Err(String::from("지역코드는 regions 전용 저장 로직을 사용해야 함"));
+    }
+    if codes.is_empty() {
+        return Ok(0);
+    }
+
+    // 테이블명은 StandardCodeClass가 정한 고정값만 사용한다.
+    let sql = format!(
+        "
+        INSERT INTO {table} (
+            code, 
+            name, 
+            parent_code
+        )
+        SELECT DISTINCT ON (code)
+            code, 
+            name, 
+            parent_code
+        FROM UNNEST(
+            $1::varchar[], 
+            $2::varchar[], 
+            $3::varchar[]
+        ) WITH ORDINALITY AS t(
+            code, 
+            name, 
+            parent_code, 
+            ord
+        )
+        ORDER BY code, ord DESC
+        ON CONFLICT (code)
+        DO UPDATE SET
+            name = EXCLUDED.name,
+            parent_code = EXCLUDED.parent_code,
+            updated_at = NOW()
+        ",
+        table = class.table()
+    );
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let mut affected = 0;
+
+    for chunk in codes.chunks(BULK_CHUNK_SIZE) {
+        let code_values: Vec<String> = chunk.iter().map(|c| c.code.clone()).collect();
+        let names: Vec<String> = chunk.iter().map(|c| c.name.clone()).collect();
+        let parent_codes: Vec<Option<String>> =
+            chunk.iter().map(|c| c.parent_code.clone()).collect();
+
+        let result = sqlx::query(&sql)
+            .bind(code_values)
+            .bind(names)
+            .bind(parent_codes)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| format!("{} 일괄 업데이트 실패: {}", class.label(), e))?;
+
+        affected += result.rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
+
+    Ok(affected)
+}
+
+/// 분류에 해당하는 표준코드 전체 목록을 코드 순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `class`   - 표준코드 분류
+/// 
+/// # Returns
+/// * `Ok(Vec<StandardCodeEntity>)` - 표준코드 목록
+/// * `Err(String)`                 - 조회 실패
+pub async fn find_standard_codes(
+    pool: &PgPool,
+    class: StandardCodeClass,
+) -> Result<Vec<StandardCodeEntity>, String> {
+    // 테이블명은 StandardCodeClass가 정한 고정값만 사용한다.
+    let sql = format!(
+        "
+        SELECT 
+            code, 
+            name, 
+            parent_code
+        FROM {table}
+        ORDER BY code ASC
+        ",
+        table = class.table()
+    );
+
+    sqlx::query_as::<_, StandardCodeEntity>(&sql)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("{} 조회 실패: {}", class.label(), e))
+}
@@ -4,88 +4,292 @@
  *     stores 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) insert_or_update_store()  
- *         - 매장 정보 저장
+ *     1) insert_or_update_stores_bulk()  
+ *         - 매장 정보 일괄 저장 및 변경 이력(master_history) 기록
  * 
- *     2) get_all_store_id()        
- *         - 전체 매장 ID 목록 조회
+ *     2) deactivate_stores_not_in()
+ *         - 최신 전체 목록에 없는 매장 비활성화 및 변경 이력 기록
  * 
- *     3) get_all_stores()          
- *         - 좌표가 존재하는 전체 매장 조회
+ *     3) get_all_store_id() / get_active_stores_id()
+ *         - 전체 / 활성 매장 ID 목록 조회
+ * 
+ *     4) sample_active_stores_id()
+ *         - 지역별로 고르게 섞은 활성 매장 표본 조회
+ * 
+ *     5) get_all_stores()          
+ *         - 좌표가 존재하는 전체 매장 조회 (비활성 매장 포함 여부 / 업태 코드 / 지역 코드 필터 선택)
+ * 
+ *     6) find_stores_nearby()
+ *         - 기준 좌표 반경 내 활성 매장을 거리순으로 조회 (범위 사전 필터 + 대원 거리)
+ * 
+ *     7) find_active_stores_by_geocode_status()
+ *         - 좌표 변환 상태별 활성 매장 조회
+ * 
+ *     8) update_store_geocode()
+ *         - 매장 좌표 / 좌표 변환 상태 갱신 및 변경 이력 기록
 ************************************************************************/
 
 use sqlx:: {PgPool, Row};
 
-use crate::common::entity::entity_store::StoreEntity;
+use crate::common::{
+    entity::entity_store::{StoreDistanceEntity, StoreEntity},
+    repository::{repository_region::REGION_MAX_DEPTH, BULK_CHUNK_SIZE},
+};
 
-/// stores 테이블에 매장 정보를 저장한다.
-/// 동일한 store_id가 존재하면 업데이트한다.
+/// stores 테이블에 여러 매장 정보를 저장한다.
+/// 동일한 store_id가 존재하면 업데이트하며,
+/// keep_previous_coords가 설정된 매장(주소 검색 요청 실패)은 기존 좌표와 좌표 변환 상태를 유지하고,
+/// 그 외에는 좌표를 구하지 못했더라도 전달된 값(NULL 좌표, manual_review)으로 덮어쓴다.
+/// 저장된 매장은 활성 상태로 표시하고 마지막 확인 시각을 갱신한다.
+/// 기존 값과 달라지는 필드는 갱신 전에 master_history에 기존 값 / 새 값을 기록한다.
+/// BULK_CHUNK_SIZE 단위로 나누어 하나의 트랜잭션 안에서 처리한다.
 ///
 /// # Arguments
 /// * `pool`    - DB 커넥션 풀
-/// * `store`   - 저장 / 업데이트할 매장 엔터티
+/// * `stores`  - 저장 / 업데이트할 매장 엔터티 목록
+/// * `run_id`  - 변경을 일으킨 동기화 실행 기록 ID
 /// 
 /// # Returns
-/// * `Ok(())`        - 저장/업데이트 성공  
+/// * `Ok(u64)`       - 저장/업데이트된 행 수
 /// * `Err(String)`   - 삽입 실패
-pub async fn insert_or_update_store(
+pub async fn insert_or_update_stores_bulk(
     pool: &PgPool, 
-    store: &StoreEntity
-) -> Result<(), String> {
-    sqlx::query_as::<_, StoreEntity>(
-        "
-        INSERT INTO stores (
-            store_id, 
-            store_name, 
-            tel_no, post_no, 
-            jibun_addr, 
-            road_addr, 
-            x_coord, 
-            y_coord, 
-            area_code, 
-            area_detail_code
+    stores: &[StoreEntity],
+    run_id: i32,
+) -> Result<u64, String> {
+    if stores.is_empty() {
+        return Ok(0);
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let mut affected = 0;
+
+    for chunk in stores.chunks(BULK_CHUNK_SIZE) {
+        let store_ids: Vec<String> = chunk.iter().map(|s| s.store_id.clone()).collect();
+        let store_names: Vec<String> = chunk.iter().map(|s| s.store_name.clone()).collect();
+        let tel_nos: Vec<Option<String>> = chunk.iter().map(|s| s.tel_no.clone()).collect();
+        let post_nos: Vec<Option<String>> = chunk.iter().map(|s| s.post_no.clone()).collect();
+        let jibun_addrs: Vec<String> = chunk.iter().map(|s| s.jibun_addr.clone()).collect();
+        let road_addrs: Vec<String> = chunk.iter().map(|s| s.road_addr.clone()).collect();
+        let x_coords: Vec<Option<f64>> = chunk.iter().map(|s| s.x_coord).collect();
+        let y_coords: Vec<Option<f64>> = chunk.iter().map(|s| s.y_coord).collect();
+        let area_codes: Vec<String> = chunk.iter().map(|s| s.area_code.clone()).collect();
+        let area_detail_codes: Vec<String> =
+            chunk.iter().map(|s| s.area_detail_code.clone()).collect();
+        let geocode_statuses: Vec<String> =
+            chunk.iter().map(|s| s.geocode_status.clone()).collect();
+        let store_type_codes: Vec<Option<String>> =
+            chunk.iter().map(|s| s.store_type_code.clone()).collect();
+        let keep_previous_coords: Vec<bool> =
+            chunk.iter().map(|s| s.keep_previous_coords).collect();
+
+        let result = sqlx::query(
+            "
+            WITH incoming AS (
+                SELECT DISTINCT ON (store_id)
+                    store_id, 
+                    store_name, 
+                    tel_no, 
+                    post_no, 
+                    jibun_addr, 
+                    road_addr, 
+                    x_coord, 
+                    y_coord, 
+                    area_code, 
+                    area_detail_code,
+                    geocode_status,
+                    store_type_code,
+                    keep_previous_coords
+                FROM UNNEST(
+                $1::varchar[], 
+                $2::varchar[], 
+                $3::varchar[], 
+                $4::varchar[], 
+                $5::varchar[], 
+                $6::varchar[], 
+                $7::float8[], 
+                $8::float8[], 
+                $9::varchar[], 
+                $10::varchar[],
+                $11::varchar[],
+                $12::varchar[],
+                $14::bool[]
+                ) WITH ORDINALITY AS t(
+                    store_id, 
+                    store_name, 
+                    tel_no, 
+                    post_no, 
+                    jibun_addr, 
+                    road_addr, 
+                    x_coord, 
+                    y_coord, 
+                    area_code, 
+                    area_detail_code, 
+                    geocode_status,
+                    store_type_code,
+                    keep_previous_coords,
+                    ord
+                )
+                ORDER BY store_id, ord DESC
+            ),
+            resolved AS (
+                SELECT
+                    i.store_id, 
+                    i.store_name, 
+                    i.tel_no, 
+                    i.post_no, 
+                    i.jibun_addr, 
+                    i.road_addr, 
+                    CASE WHEN i.keep_previous_coords AND s.x_coord IS NOT NULL
+                        THEN s.x_coord ELSE i.x_coord END AS x_coord,
+                    CASE WHEN i.keep_previous_coords AND s.x_coord IS NOT NULL
+                        THEN s.y_coord ELSE i.y_coord END AS y_coord,
+                    i.area_code, 
+                    i.area_detail_code,
+                    CASE WHEN i.keep_previous_coords AND s.x_coord IS NOT NULL
+                        THEN s.geocode_status ELSE i.geocode_status END AS geocode_status,
+                    i.store_type_code
+                FROM incoming i
+                LEFT JOIN stores s ON s.store_id = i.store_id
+            ),
+            history AS (
+                INSERT INTO master_history (run_id, entity, entity_id, field, old_value, new_value)
+                SELECT $13, 'store', s.store_id, f.field, f.old_value, f.new_value
+                FROM resolved i
+                JOIN stores s ON s.store_id = i.store_id
+                CROSS JOIN LATERAL (
+                    VALUES
+                        ('store_name', s.store_name::text, i.store_name::text),
+                        ('tel_no', s.tel_no::text, i.tel_no::text),
+                        ('post_no', s.post_no::text, i.post_no::text),
+                        ('jibun_addr', s.jibun_addr::text, i.jibun_addr::text),
+                        ('road_addr', s.road_addr::text, i.road_addr::text),
+                        ('x_coord', s.x_coord::text, i.x_coord::text),
+                        ('y_coord', s.y_coord::text, i.y_coord::text),
+                        ('area_code', s.area_code::text, i.area_code::text),
+                        ('area_detail_code', s.area_detail_code::text, i.area_detail_code::text),
+                        ('store_type_code', s.store_type_code::text, i.store_type_code::text),
+                        ('is_active', s.is_active::text, 'true')
+                ) AS f(field, old_value, new_value)
+                WHERE f.old_value IS DISTINCT FROM f.new_value
+            )
+            INSERT INTO stores (
+                store_id, 
+                store_name, 
+                tel_no, 
+                post_no, 
+                jibun_addr, 
+                road_addr, 
+                x_coord, 
+                y_coord, 
+                area_code, 
+                area_detail_code,
+                geocode_status,
+                store_type_code
+            )
+            SELECT
+                store_id, 
+                store_name, 
+                tel_no, 
+                post_no, 
+                jibun_addr, 
+                road_addr, 
+                x_coord, 
+                y_coord, 
+                area_code, 
+                area_detail_code,
+                geocode_status,
+                store_type_code
+            FROM resolved
+            ON CONFLICT (store_id)
+            DO UPDATE SET
+                store_name = EXCLUDED.store_name,
+                tel_no = EXCLUDED.tel_no,
+                post_no = EXCLUDED.post_no,
+                jibun_addr = EXCLUDED.jibun_addr,
+                road_addr = EXCLUDED.road_addr,
+                x_coord = EXCLUDED.x_coord,
+                y_coord = EXCLUDED.y_coord,
+                updated_at = NOW(),
+                area_code = EXCLUDED.area_code,
+                area_detail_code = EXCLUDED.area_detail_code,
+                store_type_code = EXCLUDED.store_type_code,
+                geocode_status = EXCLUDED.geocode_status,
+                is_active = TRUE,
+                last_seen_at = NOW()
+            ",
         )
-        VALUES (
-            $1, 
-            $2, 
-            $3, 
-            $4, 
-            $5, 
-            $6, 
-            $7, 
-            $8, 
-            $9, 
-            $10
+        .bind(store_ids)
+        .bind(store_names)
+        .bind(tel_nos)
+        .bind(post_nos)
+        .bind(jibun_addrs)
+        .bind(road_addrs)
+        .bind(x_coords)
+        .bind(y_coords)
+        .bind(area_codes)
+        .bind(area_detail_codes)
+        .bind(geocode_statuses)
+        .bind(store_type_codes)
+        .bind(run_id)
+        .bind(keep_previous_coords)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("매장 데이터 일괄 업데이트 실패: {}", e))?;
+
+        affected += result.rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
+
+    Ok(affected)
+}
+
+/// 최신 전체 목록에 없는 활성 매장을 비활성 상태로 표시하고 변경 이력을 기록한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `seen_ids`    - 최신 전체 목록에 포함된 store_id 목록
+/// * `run_id`      - 변경을 일으킨 동기화 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 비활성화된 매장 수
+/// * `Err(String)` - 갱신 실패
+pub async fn deactivate_stores_not_in(
+    pool: &PgPool,
+    seen_ids: &[String],
+    run_id: i32,
+) -> Result<u64, String> {
+    let deactivated: i64 = sqlx::query_scalar(
+        "
+        WITH deactivated AS (
+            UPDATE stores
+            SET is_active = FALSE,
+                updated_at = NOW()
+            WHERE is_active
+                AND store_id <> ALL($1::varchar[])
+            RETURNING store_id
+        ),
+        history AS (
+            INSERT INTO master_history (run_id, entity, entity_id, field, old_value, new_value)
+            SELECT $2, 'store', store_id, 'is_active', 'true', 'false'
+            FROM deactivated
         )
-        ON CONFLICT (store_id)
-        DO UPDATE SET
-            store_name = EXCLUDED.store_name,
-            tel_no = EXCLUDED.tel_no,
-            post_no = EXCLUDED.post_no,
-            jibun_addr = EXCLUDED.jibun_addr,
-            road_addr = EXCLUDED.road_addr,
-            x_coord = EXCLUDED.x_coord,
-            y_coord = EXCLUDED.y_coord,
-            updated_at = NOW(),
-            area_code = EXCLUDED.area_code,
-            area_detail_code = EXCLUDED.area_detail_code
+        SELECT COUNT(*) FROM deactivated
         ",
     )
-    .bind(&store.store_id)
-    .bind(&store.store_name)
-    .bind(&store.tel_no)
-    .bind(&store.post_no)
-    .bind(&store.jibun_addr)
-    .bind(&store.road_addr)
-    .bind(&store.x_coord)
-    .bind(&store.y_coord)
-    .bind(&store.area_code)
-    .bind(&store.area_detail_code)
-    .fetch_optional(pool)
+    .bind(seen_ids)
+    .bind(run_id)
+    .fetch_one(pool)
     .await
-    .map_err(|e| format!("매장 데이터 업데이트 실패: {}", e))?;
+    .map_err(|e| format!("매장 비활성화 실패: {}", e))?;
 
-    Ok(())
+    Ok(deactivated as u64)
 }
 
 /// 전체 매장의 store_id를 조회한다.
@@ -117,30 +321,350 @@ pub async fn get_all_stores_id(
     Ok(ids)
 }
 
+/// 활성 매장의 store_id를 조회한다.
+/// 업스트림 목록에서 사라진 비활성 매장은 가격 동기화 / 조사일 탐색 대상에서 제외한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 활성 매장 store_id 리스트
+/// * `Err(String)`     - 조회 실패
+pub async fn get_active_stores_id(
+    pool: &PgPool
+) -> Result<Vec<String>, String> {
+    let rows = sqlx::query(
+        "
+        SELECT store_id 
+        FROM stores
+        WHERE is_active
+        ORDER BY store_id::bigint ASC
+        ",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("활성 매장 목록 조회 실패: {}", e))?;
+
+    let ids = rows
+        .into_iter()
+        .map(|row| row.get::<String, _>("store_id"))
+        .collect();
+    Ok(ids)
+}
+
+/// 활성 매장 중 표본 매장의 store_id를 무작위로 조회한다.
+/// 지역(area_code)마다 한 곳씩 먼저 고른 뒤 부족하면 두 번째 매장을 고르는 식으로,
+/// 특정 지역이나 ID 구간에 몰리지 않도록 고르게 섞는다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `limit`   - 표본 매장 수
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 표본 매장 store_id 리스트 (최대 limit개)
+/// * `Err(String)`     - 조회 실패
+pub async fn sample_active_stores_id(
+    pool: &PgPool,
+    limit: i64,
+) -> Result<Vec<String>, String> {
+    let rows = sqlx::query(
+        "
+        SELECT store_id
+        FROM (
+            SELECT 
+                store_id,
+                ROW_NUMBER() OVER (PARTITION BY area_code ORDER BY random()) AS area_rank
+            FROM stores
+            WHERE is_active
+        ) ranked
+        ORDER BY area_rank, random()
+        LIMIT $1
+        ",
+    )
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("표본 매장 조회 실패: {}", e))?;
+
+    let ids = rows
+        .into_iter()
+        .map(|row| row.get::<String, _>("store_id"))
+        .collect();
+    Ok(ids)
+}
+
 
 /// 좌표가 존재하는 전체 매장 목록을 조회한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`                - DB 커넥션 풀
+/// * `include_inactive`    - 업스트림 목록에서 사라진 비활성 매장 포함 여부
+/// * `store_type_code`     - 업태 코드 (없으면 전체)
+/// * `region_code`         - 지역 코드 (없으면 전체, 지정 시 모든 하위 지역 포함)
 /// 
 /// # Returns
 /// * `Ok(<Vec<StoreEntity>)`   - 매장 목록
 /// * `Err(String)`             - 조회 실패
 pub async fn get_all_stores(
-    pool: &PgPool
+    pool: &PgPool,
+    include_inactive: bool,
+    store_type_code: Option<&str>,
+    region_code: Option<&str>,
 ) -> Result<Vec<StoreEntity>, String> {
     let rows = sqlx::query_as::<_, StoreEntity>(
         "
+        WITH RECURSIVE scope AS (
+            SELECT $3::varchar AS code, 0 AS depth
+            WHERE $3::varchar IS NOT NULL
+            UNION
+            SELECT r.code, s.depth + 1
+            FROM regions r
+            JOIN scope s ON r.parent_code = s.code
+            WHERE s.depth < $4
+        )
         SELECT * 
         FROM stores
         WHERE x_coord IS NOT NULL 
             AND y_coord IS NOT NULL
+            AND ($1 OR is_active)
+            AND ($2::varchar IS NULL OR store_type_code = $2)
+            AND (
+                $3::varchar IS NULL
+                OR area_code IN (SELECT code FROM scope)
+                OR area_detail_code IN (SELECT code FROM scope)
+            )
         ORDER BY store_id::bigint ASC
         ",
     )
+    .bind(include_inactive)
+    .bind(store_type_code)
+    .bind(region_code)
+    .bind(REGION_MAX_DEPTH)
     .fetch_all(pool)
     .await
     .map_err(|e| format!("쿼리 실패: {}", e))?;
 
     Ok(rows)
 }
+
+/// 지구 반경 (km)
+pub const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// 기준 위도에서 반경을 덮는 위도 / 경도 범위(bounding box)의 반폭(도)을 계산한다.
+/// 위도 1도의 거리는 일정하고, 경도 1도의 거리는 위도가 높을수록 짧아진다.
+/// 경도 반폭은 반경 원의 동서 끝(기준 위도보다 극 쪽에 있음)까지 덮도록 asin(sin(r/R) / cos(lat))로 구하며,
+/// 원이 극을 포함하면 경도 전체(180도)를 사용한다.
+/// 
+/// # Arguments
+/// * `lat`         - 기준 위도
+/// * `radius_km`   - 반경 (km)
+/// 
+/// # Returns
+/// * `(f64, f64)` - (위도 범위 반폭, 경도 범위 반폭)
+pub fn bounding_box_deltas(
+    lat: f64,
+    radius_km: f64,
+) -> (f64, f64) {
+    let angle = radius_km / EARTH_RADIUS_KM;
+    let lat_delta = angle.to_degrees();
+    let ratio = angle.sin() / lat.to_radians().cos().max(1e-6);
+    let lon_delta = if angle >= std::f64::consts::FRAC_PI_2 || ratio >= 1.0 {
+        180.0
+    } else {
+        ratio.asin().to_degrees()
+    };
+
+    (lat_delta, lon_delta)
+}
+
+/// 기준 좌표에서 반경 내에 있는 활성 매장을 가까운 순으로 조회한다.
+/// 
+/// 위도 / 경도 범위(bounding box)로 후보를 먼저 거른 뒤
+/// 하버사인 공식으로 대원 거리를 계산하여 반경 밖 매장을 제외한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (km)
+/// * `limit`       - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Vec<StoreDistanceEntity>)`    - 매장 및 기준 좌표로부터의 거리 (km) 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn find_stores_nearby(
+    pool: &PgPool,
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+    limit: i64,
+) -> Result<Vec<StoreDistanceEntity>, String> {
+    let (lat_delta, lon_delta) = bounding_box_deltas(lat, radius_km);
+
+    let rows = sqlx::query_as::<_, StoreDistanceEntity>(
+        "
+        WITH candidates AS (
+            SELECT
+                s.*,
+                2 * $3 * ASIN(LEAST(1.0, SQRT(
+                    POWER(SIN(RADIANS(s.x_coord - $1) / 2), 2)
+                    + COS(RADIANS($1)) * COS(RADIANS(s.x_coord))
+                        * POWER(SIN(RADIANS(s.y_coord - $2) / 2), 2)
+                ))) AS distance_km
+            FROM stores s
+            WHERE s.is_active
+                AND s.x_coord BETWEEN $1 - $5 AND $1 + $5
+                AND s.y_coord BETWEEN $2 - $6 AND $2 + $6
+        )
+        SELECT *
+        FROM candidates
+        WHERE distance_km <= $4
+        ORDER BY distance_km ASC, store_id::bigint ASC
+        LIMIT $7
+        ",
+    )
+    .bind(lat)
+    .bind(lon)
+    .bind(EARTH_RADIUS_KM)
+    .bind(radius_km)
+    .bind(lat_delta)
+    .bind(lon_delta)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("근처 매장 조회 실패: {}", e))?;
+
+    Ok(rows)
+}
+
+/// 좌표 변환 상태가 일치하는 활성 매장 목록을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `geocode_status`  - 좌표 변환 상태 ("road" | "parcel" | "upstream" | "manual_review")
+/// 
+/// # Returns
+/// * `Ok(Vec<StoreEntity>)`    - 매장 목록
+/// * `Err(String)`             - 조회 실패
+pub async fn find_active_stores_by_geocode_status(
+    pool: &PgPool,
+    geocode_status: &str,
+) -> Result<Vec<StoreEntity>, String> {
+    sqlx::query_as::<_, StoreEntity>(
+        "
+        SELECT *
+        FROM stores
+        WHERE geocode_status = $1
+            AND is_active
+        ORDER BY store_id::bigint ASC
+        ",
+    )
+    .bind(geocode_status)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("좌표 변환 상태별 매장 조회 실패: {}", e))
+}
+
+/// 매장의 좌표와 좌표 변환 상태를 갱신하고, 달라진 값은 변경 이력에 기록한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `store_id`        - 매장 ID
+/// * `coords`          - 새 좌표 (위도, 경도)
+/// * `geocode_status`  - 새 좌표 변환 상태
+/// * `run_id`          - 변경을 일으킨 동기화 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(())`      - 갱신 성공
+/// * `Err(String)` - 갱신 실패
+pub async fn update_store_geocode(
+    pool: &PgPool,
+    store_id: &str,
+    coords: (f64, f64),
+    geocode_status: &str,
+    run_id: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        WITH history AS (
+            INSERT INTO master_history (run_id, entity, entity_id, field, old_value, new_value)
+            SELECT $5, 'store', s.store_id, f.field, f.old_value, f.new_value
+            FROM stores s
+            CROSS JOIN LATERAL (
+                VALUES
+                    ('x_coord', s.x_coord::text, $2::float8::text),
+                    ('y_coord', s.y_coord::text, $3::float8::text)
+            ) AS f(field, old_value, new_value)
+            WHERE s.store_id = $1
+                AND f.old_value IS DISTINCT FROM f.new_value
+        )
+        UPDATE stores
+        SET x_coord = $2,
+            y_coord = $3,
+            geocode_status = $4,
+            updated_at = NOW()
+        WHERE store_id = $1
+        ",
+    )
+    .bind(store_id)
+    .bind(coords.0)
+    .bind(coords.1)
+    .bind(geocode_status)
+    .bind(run_id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("매장 좌표 갱신 실패: {}", e))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounding_box_at_equator_is_square() {
+        let (lat_delta, lon_delta) = bounding_box_deltas(0.0, 1.0);
+
+        assert!((lat_delta - 0.008_993_2).abs() < 1e-6, "{}", lat_delta);
+        assert!((lon_delta - lat_delta).abs() < 1e-12);
+    }
+
+    /// 하버사인 대원 거리 (km)
+    fn haversine_km(lat1: f64, lon1: f64, lat2: f64, lon2: f64) -> f64 {
+        let d_lat = (lat2 - lat1).to_radians() / 2.0;
+        let d_lon = (lon2 - lon1).to_radians() / 2.0;
+        let h = d_lat.sin().powi(2) + lat1.to_radians().cos() * lat2.to_radians().cos() * d_lon.sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+    }
+
+    #[test]
+    fn bounding_box_widens_longitude_with_latitude() {
+        let (lat_delta, lon_delta) = bounding_box_deltas(60.0, 5.0);
+        assert!((lon_delta - 2.0 * lat_delta).abs() < 1e-4, "{} {}", lat_delta, lon_delta);
+        assert!((haversine_km(60.0, 0.0, 60.0 + lat_delta, 0.0) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bounding_box_reaches_east_west_extremes_of_circle() {
+        // 반경 원의 동서 끝은 기준 위도보다 극 쪽에 있으며, 그 경도 차가 경도 반폭과 같아야 한다.
+        for (lat, radius_km) in [(37.5, 10.0), (37.5, 500.0), (-70.0, 300.0)] {
+            let (_, lon_delta) = bounding_box_deltas(lat, radius_km);
+            let angle = radius_km / EARTH_RADIUS_KM;
+            let tangent_lat = (lat.to_radians().sin() / angle.cos()).asin().to_degrees();
+
+            let distance = haversine_km(lat, 0.0, tangent_lat, lon_delta);
+            assert!((distance - radius_km).abs() < 1e-6, "{} {} → {}", lat, radius_km, distance);
+            // 같은 위도에서 경도 반폭만큼 떨어진 지점은 반경 밖에 있다.
+            assert!(haversine_km(lat, 0.0, lat, lon_delta) > radius_km);
+        }
+    }
+
+    #[test]
+    fn bounding_box_longitude_is_capped_near_poles() {
+        assert_eq!(bounding_box_deltas(90.0, 1.0).1, 180.0);
+        assert_eq!(bounding_box_deltas(-89.999_999, 50.0).1, 180.0);
+        // 기준 위도에서 극까지보다 반경이 길면 경도 전체를 덮는다.
+        assert_eq!(bounding_box_deltas(85.0, 600.0).1, 180.0);
+    }
+}
@@ -0,0 +1,140 @@
+/************************************************************************
+ * File: common/repository/repository_sync_quarantine.rs
+ * Description:
+ *     sync_quarantine 테이블에 대한 DB 연산 로직을 담당한다.
+ *     검증 규칙을 통과하지 못한 업스트림 항목을 사유와 원본과 함께 격리 보관한다.
+ *
+ * Responsibilities:
+ *     1) insert_quarantine_bulk()
+ *         - 격리 항목 일괄 저장
+ * 
+ *     2) find_quarantine()
+ *         - 동기화 실행 / 사유 / 항목 종류별 격리 항목 조회
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::common::{
+    entity::entity_sync_quarantine::SyncQuarantineEntity, repository::BULK_CHUNK_SIZE,
+};
+
+/// 격리 항목을 일괄 저장한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `rows`    - 저장할 격리 항목 목록 (id, created_at은 DB에서 생성)
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 저장된 행 수
+/// * `Err(String)` - 저장 실패
+pub async fn insert_quarantine_bulk(
+    pool: &PgPool,
+    rows: &[SyncQuarantineEntity],
+) -> Result<u64, String> {
+    let mut affected = 0;
+
+    for chunk in rows.chunks(BULK_CHUNK_SIZE) {
+        let run_ids: Vec<Option<i32>> = chunk.iter().map(|r| r.run_id).collect();
+        let entities: Vec<String> = chunk.iter().map(|r| r.entity.clone()).collect();
+        let record_keys: Vec<String> = chunk.iter().map(|r| r.record_key.clone()).collect();
+        let reasons: Vec<String> = chunk.iter().map(|r| r.reason.clone()).collect();
+        let details: Vec<Option<String>> = chunk.iter().map(|r| r.detail.clone()).collect();
+        let payloads: Vec<String> = chunk.iter().map(|r| r.payload.clone()).collect();
+
+        let result = sqlx::query(
+            "
+            INSERT INTO sync_quarantine (
+                run_id, 
+                entity, 
+                record_key, 
+                reason, 
+                detail, 
+                payload
+            )
+            SELECT 
+                run_id, 
+                entity, 
+                record_key, 
+                reason, 
+                detail, 
+                payload::jsonb
+            FROM UNNEST(
+                $1::int4[], 
+                $2::varchar[], 
+                $3::varchar[], 
+                $4::varchar[], 
+                $5::text[], 
+                $6::text[]
+            ) AS t(
+                run_id, 
+                entity, 
+                record_key, 
+                reason, 
+                detail, 
+                payload
+            )
+            ",
+        )
+        .bind(run_ids)
+        .bind(entities)
+        .bind(record_keys)
+        .bind(reasons)
+        .bind(details)
+        .bind(payloads)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("격리 항목 저장 실패: {}", e))?;
+
+        affected += result.rows_affected();
+    }
+
+    Ok(affected)
+}
+
+/// 조건에 맞는 격리 항목을 최신순으로 조회한다.
+/// 조건 값이 None이면 해당 조건은 적용하지 않는다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `run_id`  - 동기화 실행 기록 ID
+/// * `reason`  - 격리 사유
+/// * `entity`  - 항목 종류 (price, good, store)
+/// * `limit`   - 최대 조회 건수
+/// 
+/// # Returns
+/// * `Ok(Vec<SyncQuarantineEntity>)`   - 격리 항목 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn find_quarantine(
+    pool: &PgPool,
+    run_id: Option<i32>,
+    reason: Option<&str>,
+    entity: Option<&str>,
+    limit: i64,
+) -> Result<Vec<SyncQuarantineEntity>, String> {
+    sqlx::query_as::<_, SyncQuarantineEntity>(
+        "
+        SELECT
+            id,
+            run_id,
+            entity,
+            record_key,
+            reason,
+            detail,
+            payload::text AS payload,
+            created_at
+        FROM sync_quarantine
+        WHERE ($1::int4 IS NULL OR run_id = $1)
+            AND ($2::varchar IS NULL OR reason = $2)
+            AND ($3::varchar IS NULL OR entity = $3)
+        ORDER BY created_at DESC, id DESC
+        LIMIT $4
+        ",
+    )
+    .bind(run_id)
+    .bind(reason)
+    .bind(entity)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("격리 항목 조회 실패: {}", e))
+}
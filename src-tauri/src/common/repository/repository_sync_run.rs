@@ -0,0 +1,226 @@
+/************************************************************************
+ * File: common/repository/repository_sync_run.rs
+ * Description:
+ *     sync_runs 테이블에 대한 DB 연산 로직을 담당한다.
+ *
+ * Responsibilities:
+ *     1) create_sync_run()
+ *         - 동기화 실행 기록 생성
+ * 
+ *     2) update_sync_run_progress()
+ *         - 진행 중인 동기화의 카운터 및 생존 신호(heartbeat_at) 갱신
+ * 
+ *     3) finish_sync_run()
+ *         - 동기화 종료 상태 및 결과 기록
+ * 
+ *     4) find_sync_run_by_id()
+ *         - 동기화 실행 기록 단건 조회
+ * 
+ *     5) fail_interrupted_sync_runs()
+ *         - 생존 신호가 끊긴 'running' 기록을 실패로 정리
+************************************************************************/
+
+use sqlx::{PgPool, Row};
+
+use crate::common::entity::entity_sync_run::SyncRunEntity;
+
+/// 새로운 동기화 실행 기록을 'running' 상태로 생성한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `kind`    - 동기화 종류
+/// * `params`  - 실행 파라미터 (JSON 문자열)
+/// 
+/// # Returns
+/// * `Ok(i32)`     - 생성된 실행 기록 ID
+/// * `Err(String)` - 생성 실패
+pub async fn create_sync_run(
+    pool: &PgPool,
+    kind: &str,
+    params: &str,
+) -> Result<i32, String> {
+    let row = sqlx::query(
+        "
+        INSERT INTO sync_runs (
+            kind, 
+            params
+        )
+        VALUES (
+            $1, 
+            $2::jsonb
+        )
+        RETURNING id
+        ",
+    )
+    .bind(kind)
+    .bind(params)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| format!("동기화 실행 기록 생성 실패: {}", e))?;
+
+    Ok(row.get::<i32, _>("id"))
+}
+
+/// 진행 중인 동기화의 성공/실패/건너뜀 카운터를 갱신한다.
+/// 실행이 살아 있음을 알리도록 생존 신호 시각(heartbeat_at)도 함께 갱신한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `id`      - 실행 기록 ID
+/// * `success` - 성공 항목 수
+/// * `failure` - 실패 항목 수
+/// * `skipped` - 건너뛴 항목 수
+/// 
+/// # Returns
+/// * `Ok(())`      - 갱신 성공
+/// * `Err(String)` - 갱신 실패
+pub async fn update_sync_run_progress(
+    pool: &PgPool,
+    id: i32,
+    success: i32,
+    failure: i32,
+    skipped: i32,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        UPDATE sync_runs
+        SET 
+            success_count = $2, 
+            failure_count = $3, 
+            skipped_count = $4,
+            heartbeat_at = NOW()
+        WHERE id = $1
+        ",
+    )
+    .bind(id)
+    .bind(success)
+    .bind(failure)
+    .bind(skipped)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("동기화 진행 상황 갱신 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// 동기화 종료 상태와 최종 카운터, 결과 요약, 에러를 기록한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `id`      - 실행 기록 ID
+/// * `status`  - 종료 상태 ("succeeded" | "failed")
+/// * `counts`  - (성공, 실패, 건너뜀) 항목 수
+/// * `summary` - 결과 요약 (JSON 문자열)
+/// * `error`   - 최종 에러 메시지
+/// 
+/// # Returns
+/// * `Ok(())`      - 기록 성공
+/// * `Err(String)` - 기록 실패
+pub async fn finish_sync_run(
+    pool: &PgPool,
+    id: i32,
+    status: &str,
+    counts: (i32, i32, i32),
+    summary: Option<&str>,
+    error: Option<&str>,
+) -> Result<(), String> {
+    sqlx::query(
+        "
+        UPDATE sync_runs
+        SET 
+            status = $2, 
+            finished_at = NOW(), 
+            success_count = $3, 
+            failure_count = $4, 
+            skipped_count = $5, 
+            summary = $6::jsonb, 
+            error = $7
+        WHERE id = $1
+        ",
+    )
+    .bind(id)
+    .bind(status)
+    .bind(counts.0)
+    .bind(counts.1)
+    .bind(counts.2)
+    .bind(summary)
+    .bind(error)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("동기화 종료 기록 실패: {}", e))?;
+
+    Ok(())
+}
+
+/// ID로 동기화 실행 기록을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `id`      - 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(Some(SyncRunEntity))` - 조회 성공
+/// * `Ok(None)`                - 해당 ID 없음
+/// * `Err(String)`             - 조회 실패
+pub async fn find_sync_run_by_id(
+    pool: &PgPool,
+    id: i32,
+) -> Result<Option<SyncRunEntity>, String> {
+    let row = sqlx::query_as::<_, SyncRunEntity>(
+        "
+        SELECT 
+            id, 
+            kind, 
+            params::text AS params, 
+            status, 
+            started_at, 
+            finished_at, 
+            success_count, 
+            failure_count, 
+            skipped_count, 
+            summary::text AS summary, 
+            error
+        FROM sync_runs
+        WHERE id = $1
+        ",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| format!("동기화 실행 기록 조회 실패: {}", e))?;
+
+    Ok(row)
+}
+
+/// 생존 신호(heartbeat_at)가 일정 시간 이상 갱신되지 않은 'running' 실행 기록을 'failed'로 정리한다.
+/// 다른 서버 인스턴스나 관리자 CLI에서 실행 중인 작업은 생존 신호를 계속 갱신하므로 정리되지 않는다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `stale_after_secs`- 중단된 것으로 볼 생존 신호 경과 시간 (초)
+/// 
+/// # Returns
+/// * `Ok(u64)`     - 정리된 실행 기록 수
+/// * `Err(String)` - 정리 실패
+pub async fn fail_interrupted_sync_runs(
+    pool: &PgPool,
+    stale_after_secs: f64,
+) -> Result<u64, String> {
+    let result = sqlx::query(
+        "
+        UPDATE sync_runs
+        SET 
+            status = 'failed', 
+            finished_at = NOW(), 
+            error = 'interrupted: 생존 신호가 끊겨 실행이 중단된 것으로 처리됨'
+        WHERE status = 'running'
+          AND heartbeat_at < NOW() - make_interval(secs => $1)
+        ",
+    )
+    .bind(stale_after_secs)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("중단된 동기화 실행 정리 실패: {}", e))?;
+
+    Ok(result.rows_affected())
+}
@@ -140,7 +140,8 @@ pub async fn create_user(
             email, 
             name, 
             created_at, 
-            last_login
+            last_login,
+            role
         ",
     )
     .bind(sub)
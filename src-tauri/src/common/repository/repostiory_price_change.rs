@@ -4,73 +4,110 @@
  *     price_change 테이블에 대한 DB 연산 로직을 담당한다.
  *
  * Responsibilities:
- *     1) insert_price_change() 
- *         - 가격 변화 이력 생성
+ *     1) replace_price_change() 
+ *         - 기준일별 가격 변화 이력 재생성 (삭제 후 upsert, 하나의 트랜잭션)
 ************************************************************************/
 
 use sqlx::PgPool;
 
-/// 두 날짜 (latest, prev)를 기준으로 price_change 테이블에
-/// 가격 변동 이력을 일괄 삽입한다.
+/// (기준일, 비교 조사일) 쌍마다 기준일의 price_change 행을 지우고 다시 생성한다.
+/// 모든 쌍을 하나의 트랜잭션으로 처리하므로, 중간에 실패하면 기존 데이터가 그대로 유지된다.
+/// (good_id, store_id, inspect_day) 기준으로 upsert하므로 여러 번 호출해도 행이 중복되지 않는다.
 /// 
 /// # Arguments
 /// * `pool`    - DB 커넥션 풀
-/// * `latest`  - 최근 조사일 (YYYYMMDD)
-/// * `prev`    - 비교 대상 이전 조사일 (YYYYMMDD)
+/// * `pairs`   - (기준일, 비교 대상 이전 조사일) 목록 (YYYYMMDD)
 /// 
 /// # Returns
-/// * `Ok(i32)`     
-/// * `Err(String)`
-pub async fn insert_price_change(
+/// * `Ok(Vec<i32>)`    - 쌍별 생성된 가격 변동 수
+/// * `Err(String)`     - 생성 실패 (전체 롤백)
+pub async fn replace_price_change(
     pool: &PgPool, 
-    latest: &str, 
-    prev: &str
-) -> Result<i32, String> {
-    let rows = sqlx::query(
-        "
-        INSERT INTO price_change (
-            good_id, 
-            store_id, 
-            previous_price, 
-            current_price, 
-            diff, 
-            inspect_day)
-        WITH latest AS (
-            SELECT 
-                good_id, 
-                store_id, 
-                price AS current_price
-            FROM prices
+    pairs: &[(String, String)],
+) -> Result<Vec<i32>, String> {
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| format!("트랜잭션 시작 실패: {}", e))?;
+    let mut counts = Vec::with_capacity(pairs.len());
+
+    for (latest, prev) in pairs {
+        sqlx::query(
+            "
+            DELETE FROM price_change
             WHERE inspect_day = $1
-        ),
-        prev AS (
-            SELECT 
+            ",
+        )
+        .bind(latest)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("price_change 삭제 실패: {}", e))?;
+
+        let rows = sqlx::query(
+            "
+            INSERT INTO price_change (
                 good_id, 
                 store_id, 
-                price AS previous_price
-            FROM prices
-            WHERE inspect_day = $2
+                previous_price, 
+                current_price, 
+                diff, 
+                inspect_day,
+                prev_inspect_day,
+                change_rate)
+            WITH latest AS (
+                SELECT 
+                    good_id, 
+                    store_id, 
+                    price AS current_price
+                FROM prices
+                WHERE inspect_day = $1
+            ),
+            prev AS (
+                SELECT 
+                    good_id, 
+                    store_id, 
+                    price AS previous_price
+                FROM prices
+                WHERE inspect_day = $2
+            )
+            SELECT
+                l.good_id,
+                l.store_id,
+                p.previous_price,
+                l.current_price,
+                (l.current_price - p.previous_price) AS diff,
+                $1,
+                $2,
+                CASE
+                    WHEN p.previous_price = 0 THEN NULL
+                    ELSE ROUND(((l.current_price - p.previous_price) * 100.0 / p.previous_price)::numeric, 2)::float8
+                END
+            FROM latest l
+            JOIN prev p
+                ON l.good_id = p.good_id
+               AND l.store_id = p.store_id
+            ON CONFLICT (good_id, store_id, inspect_day)
+            DO UPDATE SET
+                previous_price = EXCLUDED.previous_price,
+                current_price = EXCLUDED.current_price,
+                diff = EXCLUDED.diff,
+                prev_inspect_day = EXCLUDED.prev_inspect_day,
+                change_rate = EXCLUDED.change_rate,
+                updated_at = NOW()
+            "
         )
-        SELECT
-            l.good_id,
-            l.store_id,
-            p.previous_price,
-            l.current_price,
-            (l.current_price - p.previous_price) AS diff,
-            $1
-        FROM latest l
-        JOIN prev p
-            ON l.good_id = p.good_id
-           AND l.store_id = p.store_id
-        "
-    )
-    .bind(latest)
-    .bind(prev)
-    .execute(pool)
-    .await
-    .map_err(|e| format!("price_change insert 실패: {}", e))?;
+        .bind(latest)
+        .bind(prev)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| format!("price_change upsert 실패: {}", e))?;
+
+        counts.push(rows.rows_affected() as i32);
+    }
 
-    let count = rows.rows_affected() as i32;
+    tx.commit()
+        .await
+        .map_err(|e| format!("트랜잭션 커밋 실패: {}", e))?;
 
-    Ok(count)
+    Ok(counts)
 }
@@ -0,0 +1,97 @@
+/************************************************************************
+ * File: config/data_source.rs
+ * Description:
+ *     동기화에 사용할 업스트림 데이터 소스 설정을 환경 변수에서 불러온다.
+ *
+ * Reponsibilities:
+ *     1) load_data_source_config()
+ *         - 데이터 소스 모드(live / record / replay) 및 응답 저장 경로 로드
+ *         - replay 모드에서 녹화되지 않은 가격 응답 처리 방식 로드
+ *         - 원본 응답 DB 보관(raw_payloads) 여부 및 보관 기간 로드
+************************************************************************/
+
+use std::path::PathBuf;
+
+use crate::config::env::get_env_value;
+
+/// 응답 저장 경로 기본값
+const DEFAULT_DATA_SOURCE_DIR: &str = "fixtures";
+
+/// 원본 응답 보관 기간 기본값 (일)
+const DEFAULT_RAW_PAYLOAD_RETENTION_DAYS: i32 = 30;
+
+/// 데이터 소스 모드
+///
+/// * `Live`    - 실제 공공데이터포털 / vWorld API 호출
+/// * `Record`  - 실제 API를 호출하면서 원본 응답을 디스크에 저장
+/// * `Replay`  - 디스크에 저장된 원본 응답만 사용 (네트워크 / API 키 불필요)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataSourceMode {
+    Live,
+    Record,
+    Replay,
+}
+
+/// 데이터 소스 설정
+///
+/// # Fields
+/// * `mode`    - 데이터 소스 모드
+/// * `dir`     - 원본 응답 저장 경로 (record / replay 모드에서 사용)
+/// * `archive` - 실제 API 응답을 raw_payloads 테이블에 보관할지 여부 (live / record 모드)
+/// * `retention_days`  - 보관한 원본 응답을 유지할 일수 (0이면 정리하지 않음)
+/// * `replay_missing_prices_empty` - replay 모드에서 녹화되지 않은 가격 응답을 빈 응답으로 볼지 여부
+#[derive(Debug, Clone)]
+pub struct DataSourceConfig {
+    pub mode: DataSourceMode,
+    pub dir: PathBuf,
+    pub archive: bool,
+    pub retention_days: i32,
+    pub replay_missing_prices_empty: bool,
+}
+
+/// 환경 변수로부터 데이터 소스 설정을 불러온다.
+/// DATA_SOURCE_MODE 값이 없거나 올바르지 않으면 live 모드를 사용한다.
+/// 원본 응답은 기본으로 보관하며, RAW_PAYLOAD_ARCHIVE 값이 false / 0 일 때만 보관하지 않는다.
+/// RAW_PAYLOAD_RETENTION_DAYS 값이 없거나 올바르지 않으면 기본값(30일)을 사용한다.
+/// REPLAY_MISSING_PRICES_AS_EMPTY 값이 true / 1 일 때만 녹화되지 않은 가격 응답을 빈 응답으로 본다.
+///
+/// # Returns
+/// * `DataSourceConfig` - 데이터 소스 설정
+pub fn load_data_source_config() -> DataSourceConfig {
+    let mode = match get_env_value("DATA_SOURCE_MODE").trim().to_lowercase().as_str() {
+        "record" => DataSourceMode::Record,
+        "replay" => DataSourceMode::Replay,
+        _ => DataSourceMode::Live,
+    };
+
+    let dir = get_env_value("DATA_SOURCE_DIR");
+    let dir = if dir.trim().is_empty() {
+        PathBuf::from(DEFAULT_DATA_SOURCE_DIR)
+    } else {
+        PathBuf::from(dir.trim())
+    };
+
+    let archive = !matches!(
+        get_env_value("RAW_PAYLOAD_ARCHIVE").trim().to_lowercase().as_str(),
+        "false" | "0"
+    );
+
+    let retention_days = get_env_value("RAW_PAYLOAD_RETENTION_DAYS")
+        .parse::<i32>()
+        .ok()
+        .filter(|v| *v >= 0)
+        .unwrap_or(DEFAULT_RAW_PAYLOAD_RETENTION_DAYS);
+
+    let replay_missing_prices_empty = matches!(
+        get_env_value("REPLAY_MISSING_PRICES_AS_EMPTY").trim().to_lowercase().as_str(),
+        "true" | "1"
+    );
+
+    DataSourceConfig {
+        mode,
+        dir,
+        archive,
+        retention_days,
+        replay_missing_prices_empty,
+    }
+}
@@ -0,0 +1,99 @@
+/************************************************************************
+ * File: config/http.rs
+ * Description:
+ *     외부 API 공용 HTTP 클라이언트에 필요한 설정 값을 환경 변수에서 불러온다.
+ *
+ * Reponsibilities:
+ *     1) load_http_client_config()
+ *         - 타임아웃, 재시도, 서킷 브레이커, 호출 한도 초과 시 대기 설정 로드
+************************************************************************/
+
+use std::time::Duration;
+
+use crate::config::env::get_env_value;
+
+/// 연결 타임아웃 기본값 (초)
+const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 5;
+
+/// 응답 읽기 타임아웃 기본값 (초)
+const DEFAULT_READ_TIMEOUT_SECS: u64 = 30;
+
+/// 일시적 오류 시 최대 재시도 횟수 기본값
+const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// 재시도 대기 시간 기본값 (밀리초, 시도마다 2배씩 증가)
+const DEFAULT_BACKOFF_BASE_MS: u64 = 500;
+
+/// 재시도 대기 시간 상한 기본값 (밀리초)
+const DEFAULT_BACKOFF_MAX_MS: u64 = 30_000;
+
+/// 서킷 브레이커가 열리는 연속 실패 횟수 기본값
+const DEFAULT_BREAKER_THRESHOLD: u32 = 5;
+
+/// 서킷 브레이커가 열린 뒤 요청을 멈추는 시간 기본값 (초)
+const DEFAULT_BREAKER_COOLDOWN_SECS: u64 = 60;
+
+/// 서비스키 호출 한도 초과 시 요청을 멈추는 시간 기본값 (초)
+const DEFAULT_QUOTA_BACKOFF_SECS: u64 = 300;
+
+/// 외부 API 공용 HTTP 클라이언트 설정
+///
+/// # Fields
+/// * `connect_timeout`     - 연결 타임아웃
+/// * `read_timeout`        - 응답 읽기 타임아웃
+/// * `max_retries`         - 일시적 오류 시 최대 재시도 횟수
+/// * `backoff_base`        - 첫 재시도 대기 시간
+/// * `backoff_max`         - 재시도 대기 시간 상한
+/// * `breaker_threshold`   - 서킷 브레이커가 열리는 연속 실패 횟수
+/// * `breaker_cooldown`    - 서킷 브레이커가 열린 뒤 요청을 멈추는 시간
+/// * `quota_backoff`       - 호출 한도 초과 시 요청을 멈추는 시간
+#[derive(Debug, Clone)]
+pub struct HttpClientConfig {
+    pub connect_timeout: Duration,
+    pub read_timeout: Duration,
+    pub max_retries: u32,
+    pub backoff_base: Duration,
+    pub backoff_max: Duration,
+    pub breaker_threshold: u32,
+    pub breaker_cooldown: Duration,
+    pub quota_backoff: Duration,
+}
+
+/// 환경 변수로부터 HTTP 클라이언트 설정을 불러온다.
+/// 값이 없거나 올바르지 않으면 기본값을 사용한다.
+///
+/// # Returns
+/// * `HttpClientConfig` - HTTP 클라이언트 설정
+pub fn load_http_client_config() -> HttpClientConfig {
+    HttpClientConfig {
+        connect_timeout: Duration::from_secs(
+            parse_or("HTTP_CONNECT_TIMEOUT_SECS", DEFAULT_CONNECT_TIMEOUT_SECS),
+        ),
+        read_timeout: Duration::from_secs(
+            parse_or("HTTP_READ_TIMEOUT_SECS", DEFAULT_READ_TIMEOUT_SECS),
+        ),
+        max_retries: parse_or("HTTP_MAX_RETRIES", DEFAULT_MAX_RETRIES),
+        backoff_base: Duration::from_millis(
+            parse_or("HTTP_BACKOFF_BASE_MS", DEFAULT_BACKOFF_BASE_MS),
+        ),
+        backoff_max: Duration::from_millis(
+            parse_or("HTTP_BACKOFF_MAX_MS", DEFAULT_BACKOFF_MAX_MS),
+        ),
+        breaker_threshold: parse_or("HTTP_BREAKER_THRESHOLD", DEFAULT_BREAKER_THRESHOLD).max(1),
+        breaker_cooldown: Duration::from_secs(
+            parse_or("HTTP_BREAKER_COOLDOWN_SECS", DEFAULT_BREAKER_COOLDOWN_SECS),
+        ),
+        quota_backoff: Duration::from_secs(
+            parse_or("HTTP_QUOTA_BACKOFF_SECS", DEFAULT_QUOTA_BACKOFF_SECS),
+        ),
+    }
+}
+
+/// 환경 변수 값을 숫자로 변환하고, 실패하면 기본값을 반환한다.
+///
+/// # Arguments
+/// * `name`    - 환경 변수 이름
+/// * `default` - 기본값
+fn parse_or<T: std::str::FromStr>(name: &str, default: T) -> T {
+    get_env_value(name).parse::<T>().unwrap_or(default)
+}
@@ -1,2 +1,6 @@
 pub mod env;
+pub mod data_source;
 pub mod database;
+pub mod http;
+pub mod state;
+pub mod sync;
\ No newline at end of file
@@ -0,0 +1,51 @@
+/************************************************************************
+ * File: config/state.rs
+ * Description:
+ *     axum 라우터에 주입되는 애플리케이션 공용 상태를 정의한다.
+ *
+ * Reponsibilities:
+ *     1) AppState
+ *         - DB 커넥션 풀과 업스트림 데이터 소스 보관
+ *         - 기존 State<PgPool> 핸들러가 그대로 동작하도록 FromRef 제공
+ * 
+ *     2) build_app_state()
+ *         - DATA_SOURCE_MODE 설정에 맞춰 상태 생성
+************************************************************************/
+
+use axum::extract::FromRef;
+use sqlx::PgPool;
+
+use crate::{
+    common::external::data_source::{build_data_sources, DataSources},
+    config::data_source::load_data_source_config,
+};
+
+/// 애플리케이션 공용 상태
+///
+/// # Fields
+/// * `pool`    - DB 커넥션 풀
+/// * `sources` - 동기화에 사용하는 업스트림 데이터 소스
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: PgPool,
+    pub sources: DataSources,
+}
+
+impl FromRef<AppState> for PgPool {
+    fn from_ref(state: &AppState) -> PgPool {
+        state.pool.clone()
+    }
+}
+
+/// DB 커넥션 풀과 설정된 데이터 소스로 애플리케이션 상태를 생성한다.
+///
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+///
+/// # Returns
+/// * `AppState` - 애플리케이션 공용 상태
+pub fn build_app_state(pool: PgPool) -> AppState {
+    let sources = build_data_sources(&load_data_source_config(), &pool);
+
+    AppState { pool, sources }
+}
@@ -0,0 +1,164 @@
+/************************************************************************
+ * File: config/sync.rs
+ * Description:
+ *     공공데이터 동기화 작업에 필요한 설정 값을 환경 변수에서 불러온다.
+ *
+ * Reponsibilities:
+ *     1) load_price_sync_config()
+ *         - 가격 동기화 동시 실행 개수 및 초당 요청 수 제한 설정 로드
+ * 
+ *     2) load_scheduler_config()
+ *         - 정기 동기화 cron 일정 및 최신 조사일 탐색 범위 설정 로드
+ * 
+ *     3) load_geocode_negative_ttl()
+ *         - 좌표 변환 결과 없음 캐시의 재조회 주기 로드
+ * 
+ *     4) load_public_api_page_size()
+ *         - 공공데이터 목록 API(상품 / 매장 / 지역코드) 페이지당 요청 건수 로드
+ * 
+ *     5) load_probe_sample_size()
+ *         - 조사일 탐색 시 조회해 볼 표본 매장 수 로드
+ * 
+ *     6) kst()
+ *         - 동기화 일정 계산에 사용하는 한국 표준시(UTC+9) 오프셋 반환
+************************************************************************/
+
+use chrono::{Duration, FixedOffset};
+
+use crate::config::env::get_env_value;
+
+/// 가격 동기화 시 동시에 처리할 매장 수 기본값
+const DEFAULT_PRICE_SYNC_CONCURRENCY: usize = 8;
+
+/// 가격 동기화 시 공공데이터 API 초당 요청 수 기본값
+const DEFAULT_PRICE_SYNC_RPS: f64 = 10.0;
+
+/// 최신 조사일 탐색 시 오늘부터 거슬러 올라갈 일수 기본값
+const DEFAULT_SYNC_PROBE_DAYS: u32 = 21;
+
+/// 좌표 변환 결과 없음 캐시를 다시 조회하기까지의 일수 기본값
+const DEFAULT_GEOCODE_NEGATIVE_TTL_DAYS: i64 = 30;
+
+/// 공공데이터 목록 API 페이지당 요청 건수 기본값
+const DEFAULT_PUBLIC_API_PAGE_SIZE: u32 = 1000;
+
+/// 조사일 탐색 시 조회해 볼 표본 매장 수 기본값
+const DEFAULT_SYNC_PROBE_SAMPLE_STORES: i64 = 5;
+
+/// 한국 표준시 UTC 오프셋 (초)
+const KST_OFFSET_SECS: i32 = 9 * 3600;
+
+/// 가격 동기화 설정
+///
+/// # Fields
+/// * `concurrency`         - 동시에 처리할 매장 수
+/// * `requests_per_second` - 공공데이터 API 초당 최대 요청 수
+#[derive(Debug, Clone)]
+pub struct PriceSyncConfig {
+    pub concurrency: usize,
+    pub requests_per_second: f64,
+}
+
+/// 환경 변수로부터 가격 동기화 설정을 불러온다.
+/// 값이 없거나 올바르지 않으면 기본값을 사용한다.
+///
+/// # Returns
+/// * `PriceSyncConfig` - 가격 동기화 설정
+pub fn load_price_sync_config() -> PriceSyncConfig {
+    let concurrency = get_env_value("PRICE_SYNC_CONCURRENCY")
+        .parse::<usize>()
+        .ok()
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_PRICE_SYNC_CONCURRENCY);
+
+    let requests_per_second = get_env_value("PRICE_SYNC_RPS")
+        .parse::<f64>()
+        .ok()
+        .filter(|v| *v > 0.0)
+        .unwrap_or(DEFAULT_PRICE_SYNC_RPS);
+
+    PriceSyncConfig {
+        concurrency,
+        requests_per_second,
+    }
+}
+
+/// 정기 동기화 스케줄러 설정
+///
+/// # Fields
+/// * `schedule`    - cron 표현식 (초 분 시 일 월 요일 [연도], KST 기준). 없으면 스케줄러 비활성화
+/// * `probe_days`  - 최신 조사일 탐색 시 오늘부터 거슬러 올라갈 일수
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    pub schedule: Option<String>,
+    pub probe_days: u32,
+}
+
+/// 환경 변수로부터 정기 동기화 스케줄러 설정을 불러온다.
+/// SYNC_SCHEDULE 값이 비어 있으면 스케줄러를 사용하지 않는다.
+///
+/// # Returns
+/// * `SchedulerConfig` - 스케줄러 설정
+pub fn load_scheduler_config() -> SchedulerConfig {
+    let schedule = get_env_value("SYNC_SCHEDULE");
+    let schedule = Some(schedule.trim().to_string()).filter(|s| !s.is_empty());
+
+    let probe_days = get_env_value("SYNC_PROBE_DAYS")
+        .parse::<u32>()
+        .ok()
+        .unwrap_or(DEFAULT_SYNC_PROBE_DAYS);
+
+    SchedulerConfig {
+        schedule,
+        probe_days,
+    }
+}
+
+/// 좌표 변환 결과가 없던 주소를 다시 조회하기까지의 기간을 불러온다.
+/// 값이 없거나 올바르지 않으면 기본값(30일)을 사용한다.
+///
+/// # Returns
+/// * `Duration` - 결과 없음 캐시 유효 기간
+pub fn load_geocode_negative_ttl() -> Duration {
+    let days = get_env_value("GEOCODE_NEGATIVE_TTL_DAYS")
+        .parse::<i64>()
+        .ok()
+        .filter(|v| *v >= 0)
+        .unwrap_or(DEFAULT_GEOCODE_NEGATIVE_TTL_DAYS);
+
+    Duration::days(days)
+}
+
+/// 공공데이터 목록 API의 페이지당 요청 건수(numOfRows)를 불러온다.
+/// 값이 없거나 올바르지 않으면 기본값(1000)을 사용한다.
+///
+/// # Returns
+/// * `u32` - 페이지당 요청 건수
+pub fn load_public_api_page_size() -> u32 {
+    get_env_value("PUBLIC_API_PAGE_SIZE")
+        .parse::<u32>()
+        .ok()
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_PUBLIC_API_PAGE_SIZE)
+}
+
+/// 조사일 탐색 시 조회해 볼 표본 매장 수를 불러온다.
+/// 값이 없거나 올바르지 않으면 기본값(5)을 사용한다.
+///
+/// # Returns
+/// * `i64` - 표본 매장 수
+pub fn load_probe_sample_size() -> i64 {
+    get_env_value("SYNC_PROBE_SAMPLE_STORES")
+        .parse::<i64>()
+        .ok()
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_SYNC_PROBE_SAMPLE_STORES)
+}
+
+/// 한국 표준시(UTC+9) 오프셋을 반환한다.
+///
+/// # Returns
+/// * `FixedOffset` - UTC+9 오프셋
+pub fn kst() -> FixedOffset {
+    FixedOffset::east_opt(KST_OFFSET_SECS).expect("KST 오프셋 생성 실패")
+}
@@ -0,0 +1,74 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// 마이그레이션 적용 상태 응답 DTO
+///
+/// # Fields
+/// * `version`         - 마이그레이션 버전 (파일명 앞의 타임스탬프)
+/// * `description`     - 마이그레이션 설명
+/// * `status`          - 적용 상태 ("applied" | "pending" | "failed" | "missing_file")
+/// * `installed_on`    - 적용 시각 (미적용이면 None)
+#[derive(Debug, Serialize)]
+pub struct MigrationStatusRes {
+    pub version: i64,
+    pub description: String,
+    pub status: String,
+    pub installed_on: Option<NaiveDateTime>,
+}
+
+/// 데이터 품질 집계 응답 DTO
+///
+/// # Fields
+/// * `stores_total`            - 전체 매장 수
+/// * `stores_inactive`         - 업스트림 목록에서 사라진 비활성 매장 수
+/// * `stores_manual_review`    - 좌표를 구하지 못해 수동 확인이 필요한 활성 매장 수
+/// * `stores_without_coords`   - 좌표가 없는 활성 매장 수
+/// * `goods_total`             - 전체 상품 수
+/// * `goods_inactive`          - 업스트림 목록에서 사라진 비활성 상품 수
+/// * `latest_inspect_day`      - 가격 데이터가 있는 가장 최근 조사일
+/// * `latest_price_rows`       - 최근 조사일의 가격 데이터 수
+/// * `latest_price_stores`     - 최근 조사일에 가격이 있는 매장 수
+/// * `latest_unknown_goods`    - 최근 조사일 가격 중 goods 테이블에 없는 상품의 가격 수
+/// * `latest_unknown_stores`   - 최근 조사일 가격 중 stores 테이블에 없는 매장의 가격 수
+#[derive(Debug, Serialize, FromRow)]
+pub struct DataQualityCountsRes {
+    pub stores_total: i64,
+    pub stores_inactive: i64,
+    pub stores_manual_review: i64,
+    pub stores_without_coords: i64,
+    pub goods_total: i64,
+    pub goods_inactive: i64,
+    pub latest_inspect_day: Option<String>,
+    pub latest_price_rows: i64,
+    pub latest_price_stores: i64,
+    pub latest_unknown_goods: i64,
+    pub latest_unknown_stores: i64,
+}
+
+/// 사유별 격리 항목 수 응답 DTO
+///
+/// # Fields
+/// * `entity`  - 항목 종류 ("price" | "good" | "store")
+/// * `reason`  - 격리 사유 코드
+/// * `count`   - 격리 항목 수
+#[derive(Debug, Serialize, FromRow)]
+pub struct QuarantineCountRes {
+    pub entity: String,
+    pub reason: String,
+    pub count: i64,
+}
+
+/// 데이터 품질 리포트 응답 DTO
+///
+/// # Fields
+/// * `counts`          - 매장 / 상품 / 가격 데이터 품질 집계
+/// * `quarantine_days` - 격리 항목 집계 기간 (일)
+/// * `quarantine`      - 기간 내 사유별 격리 항목 수
+#[derive(Debug, Serialize)]
+pub struct DataQualityRes {
+    #[serde(flatten)]
+    pub counts: DataQualityCountsRes,
+    pub quarantine_days: i64,
+    pub quarantine: Vec<QuarantineCountRes>,
+}
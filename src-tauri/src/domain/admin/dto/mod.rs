@@ -0,0 +1 @@
+pub mod dto_res;
\ No newline at end of file
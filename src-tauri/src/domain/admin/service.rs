@@ -0,0 +1,107 @@
+/************************************************************************
+ * File: domain/admin/service.rs
+ * Description:
+ *     운영 / 점검(admin) 도메인의 서비스 로직을 처리한다.
+ *     관리자 CLI(storerader-admin)에서 사용한다.
+ *
+ * Responsibilities:
+ *     1) get_migration_status()
+ *         - 마이그레이션 파일과 적용 기록을 비교해 버전별 적용 상태 반환
+ * 
+ *     2) get_data_quality_report()
+ *         - 매장 / 상품 / 가격 / 격리 항목 데이터 품질 리포트 반환
+************************************************************************/
+
+use sqlx::PgPool;
+use std::{collections::BTreeMap, path::Path};
+
+use crate::{
+    common::repository::repository_admin::{
+        count_quarantine_by_reason, find_applied_migrations, find_data_quality_counts,
+    },
+    domain::admin::dto::dto_res::{DataQualityRes, MigrationStatusRes},
+};
+
+/// 마이그레이션 파일 목록과 DB 적용 기록을 비교해 버전별 적용 상태를 반환한다.
+/// 파일명은 sqlx 규칙({version}_{description}.sql)을 따른다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `dir`     - 마이그레이션 파일 디렉터리
+/// 
+/// # Returns
+/// * `Ok(Vec<MigrationStatusRes>)` - 버전 순 적용 상태 목록
+/// * `Err(String)`                 - 디렉터리 / 적용 기록 조회 실패
+pub async fn get_migration_status(
+    pool: &PgPool,
+    dir: &Path,
+) -> Result<Vec<MigrationStatusRes>, String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("마이그레이션 디렉터리 조회 실패 ({}): {}", dir.display(), e))?;
+
+    let mut migrations: BTreeMap<i64, MigrationStatusRes> = BTreeMap::new();
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        let Some(stem) = file_name.strip_suffix(".sql") else {
+            continue;
+        };
+        let Some((version, description)) = stem.split_once('_') else {
+            continue;
+        };
+        let Ok(version) = version.parse::<i64>() else {
+            continue;
+        };
+
+        migrations.insert(
+            version,
+            MigrationStatusRes {
+                version,
+                description: description.replace('_', " "),
+                status: "pending".into(),
+                installed_on: None,
+            },
+        );
+    }
+
+    for (version, description, success, installed_on) in find_applied_migrations(pool).await? {
+        let status = if success { "applied" } else { "failed" };
+
+        migrations
+            .entry(version)
+            .and_modify(|m| {
+                m.status = status.into();
+                m.installed_on = Some(installed_on);
+            })
+            .or_insert(MigrationStatusRes {
+                version,
+                description,
+                status: "missing_file".into(),
+                installed_on: Some(installed_on),
+            });
+    }
+
+    Ok(migrations.into_values().collect())
+}
+
+/// 데이터 품질 리포트를 생성한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `quarantine_days` - 격리 항목 집계 기간 (일)
+/// 
+/// # Returns
+/// * `Ok(DataQualityRes)`  - 데이터 품질 리포트
+/// * `Err(String)`         - 집계 실패
+pub async fn get_data_quality_report(
+    pool: &PgPool,
+    quarantine_days: i64,
+) -> Result<DataQualityRes, String> {
+    let counts = find_data_quality_counts(pool).await?;
+    let quarantine = count_quarantine_by_reason(pool, quarantine_days).await?;
+
+    Ok(DataQualityRes {
+        counts,
+        quarantine_days,
+        quarantine,
+    })
+}
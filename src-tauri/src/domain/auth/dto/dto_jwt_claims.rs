@@ -5,10 +5,26 @@ use serde::{Deserialize, Serialize};
 /// # Fields
 /// * `sub`     - 사용자 고유 ID (user 테이블의 id 값)
 /// * `email`   - 사용자 이메일
+/// * `role`    - 사용자 권한 ("user" | "admin", 권한이 없던 기존 토큰은 빈 값)
 /// * `exp`     - 만료 시각
 #[derive(Serialize, Deserialize)]
 pub struct JwtClaims {
     pub sub: String,
     pub email: String,
+    #[serde(default)]
+    pub role: String,
     pub exp: usize,
 }
+
+/// 일반 사용자 권한
+pub const ROLE_USER: &str = "user";
+
+/// 관리자 권한 (동기화 등 관리자 전용 라우트 접근 가능)
+pub const ROLE_ADMIN: &str = "admin";
+
+impl JwtClaims {
+    /// 관리자 권한 여부를 반환한다.
+    pub fn is_admin(&self) -> bool {
+        self.role == ROLE_ADMIN
+    }
+}
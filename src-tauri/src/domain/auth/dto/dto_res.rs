@@ -6,12 +6,14 @@ use serde::Serialize;
 /// * `id`    - 사용자 고유 ID (DB PK)
 /// * `name`  - 사용자 이름
 /// * `email` - 사용자 이메일
+/// * `role`  - 사용자 권한 ("user" | "admin")
 #[derive(Serialize)]
 pub struct UserRes {
     pub id: i32,
     pub name: String,
     pub email: String,
     pub picture: String,
+    pub role: String,
 }
 
 /// Google 로그인 처리 결과로 반환되는 응답 DTO
@@ -77,7 +77,7 @@ pub async fn auth_google(
 
     create_default_preference(pool, user.id).await?;
 
-    let jwt = create_jwt(user.id.to_string(), user.email.clone())
+    let jwt = create_jwt(user.id.to_string(), user.email.clone(), user.role.clone())
         .map_err(|e| format!("JWT 생성 실패: {e}"))?;
 
     let response = GoogleLoginRes {
@@ -87,6 +87,7 @@ pub async fn auth_google(
             name: user.name,
             email: user.email,
             picture: claims.picture,
+            role: user.role,
         },
     };
 
@@ -142,14 +143,15 @@ pub async fn verify_google_id_token(
 /// # Arguments
 /// * `sub`     - 사용자 고유 ID (users 테이블의 id)
 /// * `email`   - 사용자 이메일
+/// * `role`    - 사용자 권한 (users 테이블의 role)
 /// 
 /// # Returns
 /// * `Ok(String)`  - 생성된 JWT 문자열
 /// * `Err(String)` - JWT 생성 실패
-pub fn create_jwt(sub: String, email: String) -> Result<String, String> {
+pub fn create_jwt(sub: String, email: String, role: String) -> Result<String, String> {
     let exp = (Utc::now() + Duration::hours(24)).timestamp() as usize;
 
-    let claims = JwtClaims { sub, email, exp };
+    let claims = JwtClaims { sub, email, role, exp };
 
     let secret = get_env_value("JWT_SECRET");
     if secret.is_empty() {
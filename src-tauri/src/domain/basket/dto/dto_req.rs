@@ -0,0 +1,29 @@
+use serde::Deserialize;
+
+/// 장바구니 최저가 매장 조회 요청 DTO
+/// 
+/// # Fields
+/// * `items`               - 장바구니 상품 목록 (최대 50개)
+/// * `lat`                 - 기준 위도
+/// * `lon`                 - 기준 경도
+/// * `radius_km`           - 검색 반경 km (기본값: 3, 최대: 50)
+/// * `travel_cost_per_km`  - 이동 거리 1km당 비용 (원, 기본값: 0)
+#[derive(Deserialize)]
+pub struct BasketReq {
+    pub items: Vec<BasketItemReq>,
+    pub lat: f64,
+    pub lon: f64,
+    pub radius_km: Option<f64>,
+    pub travel_cost_per_km: Option<f64>,
+}
+
+/// 장바구니 상품 요청 DTO
+/// 
+/// # Fields
+/// * `good_id`     - 상품 ID
+/// * `quantity`    - 수량 (기본값: 1)
+#[derive(Deserialize)]
+pub struct BasketItemReq {
+    pub good_id: String,
+    pub quantity: Option<u32>,
+}
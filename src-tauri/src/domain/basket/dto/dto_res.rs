@@ -0,0 +1,92 @@
+use serde::Serialize;
+
+/// 장바구니 최저가 매장 조회 응답 DTO
+/// 
+/// # Fields
+/// * `single_store`    - 장바구니 전체를 살 수 있는 매장 중 총비용이 가장 적은 매장 (없으면 null)
+/// * `two_store`       - 두 매장에 나누어 살 때 총비용이 가장 적은 조합 (없으면 null)
+/// * `items`           - 상품별 반경 내 판매 현황
+/// * `gaps`            - 반경 내 어느 매장에서도 가격 정보가 없는 상품 ID 목록
+#[derive(Serialize)]
+pub struct BasketRes {
+    pub single_store: Option<BasketPlanRes>,
+    pub two_store: Option<BasketPlanRes>,
+    pub items: Vec<BasketItemRes>,
+    pub gaps: Vec<String>,
+}
+
+/// 장바구니 구매 계획 응답 DTO
+/// 
+/// # Fields
+/// * `stores`          - 방문 매장별 구매 목록 (방문 순서)
+/// * `items_total`     - 상품 금액 합계
+/// * `travel_km`       - 기준 좌표에서 출발하여 매장을 차례로 방문하고 돌아오는 왕복 이동 거리 (km)
+/// * `travel_cost`     - 이동 비용 (이동 거리 × km당 비용)
+/// * `total_cost`      - 총비용 (상품 금액 합계 + 이동 비용)
+#[derive(Serialize)]
+pub struct BasketPlanRes {
+    pub stores: Vec<BasketStoreRes>,
+    pub items_total: i64,
+    pub travel_km: f64,
+    pub travel_cost: f64,
+    pub total_cost: f64,
+}
+
+/// 구매 계획의 매장별 구매 목록 응답 DTO
+/// 
+/// # Fields
+/// * `store_id`        - 매장 ID
+/// * `store_name`      - 매장명
+/// * `x_coord`         - 위도 값
+/// * `y_coord`         - 경도 값
+/// * `distance_km`     - 기준 좌표로부터의 대원 거리 (km)
+/// * `lines`           - 이 매장에서 구매할 상품 목록
+/// * `subtotal`        - 이 매장에서의 상품 금액 합계
+#[derive(Serialize)]
+pub struct BasketStoreRes {
+    pub store_id: String,
+    pub store_name: String,
+    pub x_coord: f64,
+    pub y_coord: f64,
+    pub distance_km: f64,
+    pub lines: Vec<BasketLineRes>,
+    pub subtotal: i64,
+}
+
+/// 매장별 구매 상품 응답 DTO
+/// 
+/// # Fields
+/// * `good_id`         - 상품 ID
+/// * `good_name`       - 상품명
+/// * `quantity`        - 수량
+/// * `unit_price`      - 최신 가격
+/// * `inspect_day`     - 가격 조사일 (YYYYMMDD)
+/// * `amount`          - 금액 (최신 가격 × 수량)
+#[derive(Serialize)]
+pub struct BasketLineRes {
+    pub good_id: String,
+    pub good_name: String,
+    pub quantity: u32,
+    pub unit_price: i32,
+    pub inspect_day: String,
+    pub amount: i64,
+}
+
+/// 상품별 반경 내 판매 현황 응답 DTO
+/// 
+/// # Fields
+/// * `good_id`         - 상품 ID
+/// * `good_name`       - 상품명 (가격 정보가 없으면 null)
+/// * `quantity`        - 수량
+/// * `store_count`     - 반경 내 가격 정보가 있는 매장 수
+/// * `min_price`       - 반경 내 최저가 (없으면 null)
+/// * `max_price`       - 반경 내 최고가 (없으면 null)
+#[derive(Serialize)]
+pub struct BasketItemRes {
+    pub good_id: String,
+    pub good_name: Option<String>,
+    pub quantity: u32,
+    pub store_count: usize,
+    pub min_price: Option<i32>,
+    pub max_price: Option<i32>,
+}
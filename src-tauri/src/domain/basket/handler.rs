@@ -0,0 +1,55 @@
+/************************************************************************
+ * File: domain/basket/handler.rs
+ * Description:
+ *     장바구니(basket) 도메인의 라우팅 계층으로,
+ *     클라이언트 요청을 받아 서비스 계층으로 전달하고
+ *     그 결과를 HTTP 응답 형태로 반환한다.
+ *
+ * Responsibilities:
+ *     1) basket_optimize_handler()
+ *         - 장바구니 상품을 가장 적은 비용으로 살 수 있는 매장 / 매장 조합 조회 처리
+************************************************************************/
+
+use axum::{
+    extract::State,
+    http::status::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::domain::basket::{dto::dto_req::BasketReq, service};
+
+/// 장바구니 상품 목록과 기준 좌표 / 반경으로 최저 비용 매장과 두 매장 조합을 조회한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `payload` - BasketReq { items, lat, lon, radius_km, travel_cost_per_km }
+///
+/// # Returns
+/// * `200 Ok`                      - 한 매장 / 두 매장 계획 및 상품별 판매 현황 반환
+/// * `400 BAD_REQUEST`             - 잘못된 장바구니 / 좌표 / 반경 / 이동 비용
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn basket_optimize_handler(
+    State(pool): State<PgPool>,
+    Json(payload): Json<BasketReq>,
+) -> impl IntoResponse {
+    let query = match service::validate_basket(payload) {
+        Ok(q) => q,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "message": e })).into_response(),
+            )
+        }
+    };
+
+    match service::optimize_basket(&pool, &query).await {
+        Ok(res) => (StatusCode::OK, Json(res).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
@@ -0,0 +1,516 @@
+/************************************************************************
+ * File: domain/basket/service.rs
+ * Description:
+ *     장바구니(basket) 도메인의 서비스 로직을 처리한다.
+ *     여러 상품의 (상품, 매장)별 최신 가격으로 반경 내 매장 중
+ *     장바구니를 가장 적은 비용으로 살 수 있는 방법을 계산한다.
+ *
+ * Responsibilities:
+ *     1) validate_basket()
+ *         - 장바구니 상품 / 기준 좌표 / 반경 / 이동 비용 검증 및 수량 합산
+ *
+ *     2) optimize_basket()
+ *         - 한 매장에서 모두 사는 최저 비용 매장 계산
+ *         - 두 매장에 나누어 사는 최저 비용 조합 계산
+ *         - 상품별 판매 현황 및 반경 내 구매 불가 상품 계산
+************************************************************************/
+
+use std::collections::HashMap;
+
+use sqlx::PgPool;
+
+use crate::{
+    common::{
+        entity::entity_price::GoodStorePriceEntity,
+        repository::{
+            repository_join::find_latest_basket_prices_nearby,
+            repository_store::EARTH_RADIUS_KM,
+        },
+    },
+    domain::{
+        basket::dto::{
+            dto_req::BasketReq,
+            dto_res::{BasketItemRes, BasketLineRes, BasketPlanRes, BasketRes, BasketStoreRes},
+        },
+        store::service::validate_nearby_params,
+    },
+};
+
+/// 장바구니에 담을 수 있는 최대 상품 수
+pub const BASKET_MAX_ITEMS: usize = 50;
+/// 조합 계산에 사용하는 최대 후보 매장 수 (가까운 순)
+pub const BASKET_MAX_STORES: usize = 300;
+
+/// 검증을 마친 장바구니 요청
+/// 
+/// # Fields
+/// * `items`               - (상품 ID, 수량) 목록 (같은 상품은 수량 합산, 요청 순서 유지)
+/// * `lat`                 - 기준 위도
+/// * `lon`                 - 기준 경도
+/// * `radius_km`           - 검색 반경 (km)
+/// * `travel_cost_per_km`  - 이동 거리 1km당 비용
+pub struct BasketQuery {
+    pub items: Vec<(String, u32)>,
+    pub lat: f64,
+    pub lon: f64,
+    pub radius_km: f64,
+    pub travel_cost_per_km: f64,
+}
+
+/// 반경 내 한 매장의 상품별 최신 가격
+struct StoreOffer {
+    store_id: String,
+    store_name: String,
+    x_coord: f64,
+    y_coord: f64,
+    distance_km: f64,
+    prices: HashMap<String, (i32, String)>,
+}
+
+/// 장바구니 요청을 검증하고 같은 상품의 수량을 합산한다.
+/// 
+/// # Arguments
+/// * `req` - 장바구니 요청
+/// 
+/// # Returns
+/// * `Ok(BasketQuery)` - 검증을 마친 장바구니 요청
+/// * `Err(String)`     - 빈 장바구니 / 상품 수 초과 / 잘못된 수량, 좌표, 반경, 이동 비용
+pub fn validate_basket(
+    req: BasketReq
+) -> Result<BasketQuery, String> {
+    let (radius_km, _) = validate_nearby_params(req.lat, req.lon, req.radius_km, None)?;
+
+    let travel_cost_per_km = req.travel_cost_per_km.unwrap_or(0.0);
+    if !travel_cost_per_km.is_finite() || travel_cost_per_km < 0.0 {
+        return Err(format!("km당 이동 비용은 0 이상이어야 합니다: {}", travel_cost_per_km));
+    }
+
+    let mut items: Vec<(String, u32)> = Vec::new();
+    for item in req.items {
+        let good_id = item.good_id.trim().to_string();
+        if good_id.is_empty() {
+            return Err("상품 ID가 비어 있습니다".to_string());
+        }
+        let quantity = item.quantity.unwrap_or(1);
+        if quantity == 0 {
+            return Err(format!("수량은 1 이상이어야 합니다: {}", good_id));
+        }
+
+        match items.iter_mut().find(|(id, _)| *id == good_id) {
+            Some((_, q)) => *q = q.saturating_add(quantity),
+            None => items.push((good_id, quantity)),
+        }
+    }
+
+    if items.is_empty() {
+        return Err("장바구니가 비어 있습니다".to_string());
+    }
+    if items.len() > BASKET_MAX_ITEMS {
+        return Err(format!("장바구니에는 최대 {}개 상품까지 담을 수 있습니다", BASKET_MAX_ITEMS));
+    }
+
+    Ok(BasketQuery {
+        items,
+        lat: req.lat,
+        lon: req.lon,
+        radius_km,
+        travel_cost_per_km,
+    })
+}
+
+/// 반경 내 매장의 (상품, 매장)별 최신 가격으로 장바구니 구매 방법을 계산한다.
+/// 
+/// 반경 내 어느 매장에서도 가격 정보가 없는 상품은 gaps로 분리하고,
+/// 나머지 상품에 대해 아래 두 가지 계획을 계산한다.
+/// - 한 매장: 모든 상품을 파는 매장 중 (상품 금액 + 이동 비용)이 가장 적은 매장
+/// - 두 매장: 두 매장을 합쳐 모든 상품을 살 수 있는 조합 중, 상품마다 더 싼 매장에서 살 때
+///   (상품 금액 + 이동 비용)이 가장 적은 조합 (두 매장 모두에서 한 개 이상 사는 경우만)
+/// 
+/// 이동 거리는 기준 좌표에서 출발하여 매장을 차례로 방문하고 기준 좌표로 돌아오는
+/// 왕복 대원 거리 합이다. 총비용이 같으면 이동 거리가 짧은 계획을 고른다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `query`   - 검증을 마친 장바구니 요청
+/// 
+/// # Returns
+/// * `Ok(BasketRes)`   - 한 매장 / 두 매장 계획, 상품별 판매 현황, 구매 불가 상품
+/// * `Err(String)`     - 조회 실패
+pub async fn optimize_basket(
+    pool: &PgPool,
+    query: &BasketQuery,
+) -> Result<BasketRes, String> {
+    let good_ids: Vec<String> = query.items.iter().map(|(id, _)| id.clone()).collect();
+    let rows = find_latest_basket_prices_nearby(pool, &good_ids, query.lat, query.lon, query.radius_km).await?;
+
+    // 1) 매장별 가격표 구성 (조회 결과는 가까운 순)
+    let mut good_names: HashMap<String, String> = HashMap::new();
+    let mut offers: Vec<StoreOffer> = Vec::new();
+    let mut offer_index: HashMap<String, usize> = HashMap::new();
+    for row in rows {
+        add_offer_row(row, &mut good_names, &mut offers, &mut offer_index);
+    }
+
+    // 2) 상품별 판매 현황 / 구매 불가 상품
+    let mut items = Vec::with_capacity(query.items.len());
+    let mut gaps = Vec::new();
+    let mut targets: Vec<(String, u32)> = Vec::new();
+    for (good_id, quantity) in &query.items {
+        let prices: Vec<i32> = offers
+            .iter()
+            .filter_map(|o| o.prices.get(good_id).map(|(p, _)| *p))
+            .collect();
+
+        if prices.is_empty() {
+            gaps.push(good_id.clone());
+        } else {
+            targets.push((good_id.clone(), *quantity));
+        }
+
+        items.push(BasketItemRes {
+            good_id: good_id.clone(),
+            good_name: good_names.get(good_id).cloned(),
+            quantity: *quantity,
+            store_count: prices.len(),
+            min_price: prices.iter().min().copied(),
+            max_price: prices.iter().max().copied(),
+        });
+    }
+
+    if targets.is_empty() {
+        return Ok(BasketRes {
+            single_store: None,
+            two_store: None,
+            items,
+            gaps,
+        });
+    }
+
+    // 3) 후보 매장: 장바구니 상품을 하나 이상 파는 가까운 매장
+    let candidates: Vec<&StoreOffer> = offers
+        .iter()
+        .filter(|o| targets.iter().any(|(id, _)| o.prices.contains_key(id)))
+        .take(BASKET_MAX_STORES)
+        .collect();
+
+    let single_store = best_single_store(&candidates, &targets, query.travel_cost_per_km)
+        .map(|(store, cost)| {
+            let lines = targets.iter().map(|t| (t, store)).collect::<Vec<_>>();
+            build_plan(&[store], &lines, &good_names, cost)
+        });
+
+    let two_store = best_two_store(&candidates, &targets, query.travel_cost_per_km)
+        .map(|plan| {
+            let lines = targets.iter().zip(plan.assignment).collect::<Vec<_>>();
+            build_plan(&[plan.first, plan.second], &lines, &good_names, plan.cost)
+        });
+
+    Ok(BasketRes {
+        single_store,
+        two_store,
+        items,
+        gaps,
+    })
+}
+
+/// 조회 결과 한 행을 매장별 가격표에 추가한다.
+fn add_offer_row(
+    row: GoodStorePriceEntity,
+    good_names: &mut HashMap<String, String>,
+    offers: &mut Vec<StoreOffer>,
+    offer_index: &mut HashMap<String, usize>,
+) {
+    good_names.entry(row.good_id.clone()).or_insert(row.good_name);
+
+    let idx = *offer_index.entry(row.store_id.clone()).or_insert_with(|| {
+        offers.push(StoreOffer {
+            store_id: row.store_id.clone(),
+            store_name: row.store_name.clone(),
+            x_coord: row.x_coord,
+            y_coord: row.y_coord,
+            distance_km: row.distance_km,
+            prices: HashMap::new(),
+        });
+        offers.len() - 1
+    });
+
+    offers[idx].prices.insert(row.good_id, (row.price, row.inspect_day));
+}
+
+/// 구매 계획 비용 (상품 금액 합계, 이동 거리, 이동 비용, 총비용)
+#[derive(Clone, Copy)]
+struct PlanCost {
+    items_total: i64,
+    travel_km: f64,
+    travel_cost: f64,
+    total_cost: f64,
+}
+
+impl PlanCost {
+    fn new(items_total: i64, travel_km: f64, travel_cost_per_km: f64) -> Self {
+        let travel_cost = travel_km * travel_cost_per_km;
+        Self {
+            items_total,
+            travel_km,
+            travel_cost,
+            total_cost: items_total as f64 + travel_cost,
+        }
+    }
+
+    /// 총비용이 더 적거나, 같으면 이동 거리가 더 짧은지 여부
+    fn is_better_than(&self, other: &PlanCost) -> bool {
+        self.total_cost
+            .total_cmp(&other.total_cost)
+            .then_with(|| self.travel_km.total_cmp(&other.travel_km))
+            .is_lt()
+    }
+}
+
+/// 모든 상품을 파는 매장 중 총비용이 가장 적은 매장을 찾는다.
+fn best_single_store<'a>(
+    candidates: &[&'a StoreOffer],
+    targets: &[(String, u32)],
+    travel_cost_per_km: f64,
+) -> Option<(&'a StoreOffer, PlanCost)> {
+    let mut best: Option<(&StoreOffer, PlanCost)> = None;
+
+    for store in candidates {
+        let Some(items_total) = targets.iter().try_fold(0_i64, |acc, (id, qty)| {
+            store.prices.get(id).map(|(p, _)| acc + *p as i64 * *qty as i64)
+        }) else {
+            continue;
+        };
+
+        // 기준 좌표 → 매장 → 기준 좌표 왕복
+        let cost = PlanCost::new(items_total, 2.0 * store.distance_km, travel_cost_per_km);
+        if best.as_ref().is_none_or(|(_, b)| cost.is_better_than(b)) {
+            best = Some((store, cost));
+        }
+    }
+
+    best
+}
+
+/// 두 매장 조합 (방문 순서, 상품별 배정 매장, 비용)
+struct TwoStorePlan<'a> {
+    first: &'a StoreOffer,
+    second: &'a StoreOffer,
+    assignment: Vec<&'a StoreOffer>,
+    cost: PlanCost,
+}
+
+/// 두 매장을 합쳐 모든 상품을 살 수 있는 조합 중 총비용이 가장 적은 조합을 찾는다.
+/// 상품마다 더 싼 매장(같으면 먼저 방문하는 매장)에 배정하며, 한 매장에 모두 배정되는 조합은 제외한다.
+fn best_two_store<'a>(
+    candidates: &[&'a StoreOffer],
+    targets: &[(String, u32)],
+    travel_cost_per_km: f64,
+) -> Option<TwoStorePlan<'a>> {
+    let mut best: Option<TwoStorePlan> = None;
+
+    for (i, a) in candidates.iter().enumerate() {
+        for b in &candidates[i + 1..] {
+            // 기준 좌표 → 가까운 매장 → 먼 매장 → 기준 좌표 순서로 방문한다.
+            let (first, second) = if a.distance_km <= b.distance_km { (*a, *b) } else { (*b, *a) };
+
+            let mut items_total = 0_i64;
+            let mut assignment = Vec::with_capacity(targets.len());
+            let mut covered = true;
+            for (id, qty) in targets {
+                let chosen = match (first.prices.get(id), second.prices.get(id)) {
+                    (Some((p1, _)), Some((p2, _))) => if p2 < p1 { second } else { first },
+                    (Some(_), None) => first,
+                    (None, Some(_)) => second,
+                    (None, None) => {
+                        covered = false;
+                        break;
+                    }
+                };
+                items_total += chosen.prices[id].0 as i64 * *qty as i64;
+                assignment.push(chosen);
+            }
+
+            let uses_first = assignment.iter().any(|s| std::ptr::eq(*s, first));
+            let uses_second = assignment.iter().any(|s| std::ptr::eq(*s, second));
+            if !covered || !uses_first || !uses_second {
+                continue;
+            }
+
+            let travel_km = first.distance_km
+                + great_circle_km(first.x_coord, first.y_coord, second.x_coord, second.y_coord)
+                + second.distance_km;
+            let cost = PlanCost::new(items_total, travel_km, travel_cost_per_km);
+            if best.as_ref().is_none_or(|b| cost.is_better_than(&b.cost)) {
+                best = Some(TwoStorePlan { first, second, assignment, cost });
+            }
+        }
+    }
+
+    best
+}
+
+/// 방문 매장 / 상품 배정 / 비용으로 구매 계획 응답을 만든다.
+fn build_plan(
+    stores: &[&StoreOffer],
+    lines: &[(&(String, u32), &StoreOffer)],
+    good_names: &HashMap<String, String>,
+    cost: PlanCost,
+) -> BasketPlanRes {
+    let stores = stores
+        .iter()
+        .map(|store| {
+            let lines: Vec<BasketLineRes> = lines
+                .iter()
+                .filter(|(_, s)| std::ptr::eq(*s, *store))
+                .map(|((id, qty), _)| {
+                    let (price, inspect_day) = &store.prices[id];
+                    BasketLineRes {
+                        good_id: id.clone(),
+                        good_name: good_names.get(id).cloned().unwrap_or_default(),
+                        quantity: *qty,
+                        unit_price: *price,
+                        inspect_day: inspect_day.clone(),
+                        amount: *price as i64 * *qty as i64,
+                    }
+                })
+                .collect();
+
+            BasketStoreRes {
+                store_id: store.store_id.clone(),
+                store_name: store.store_name.clone(),
+                x_coord: store.x_coord,
+                y_coord: store.y_coord,
+                distance_km: store.distance_km,
+                subtotal: lines.iter().map(|l| l.amount).sum(),
+                lines,
+            }
+        })
+        .collect();
+
+    BasketPlanRes {
+        stores,
+        items_total: cost.items_total,
+        travel_km: cost.travel_km,
+        travel_cost: cost.travel_cost,
+        total_cost: cost.total_cost,
+    }
+}
+
+/// 두 좌표 사이의 대원 거리(km)를 하버사인 공식으로 계산한다.
+fn great_circle_km(
+    lat1: f64,
+    lon1: f64,
+    lat2: f64,
+    lon2: f64,
+) -> f64 {
+    let d_lat = (lat2 - lat1).to_radians() / 2.0;
+    let d_lon = (lon2 - lon1).to_radians() / 2.0;
+    let h = d_lat.sin().powi(2) + lat1.to_radians().cos() * lat2.to_radians().cos() * d_lon.sin().powi(2);
+
+    2.0 * EARTH_RADIUS_KM * h.sqrt().min(1.0).asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offer(store_id: &str, distance_km: f64, lon: f64, prices: &[(&str, i32)]) -> StoreOffer {
+        StoreOffer {
+            store_id: store_id.to_string(),
+            store_name: store_id.to_string(),
+            x_coord: 37.5,
+            y_coord: lon,
+            distance_km,
+            prices: prices
+                .iter()
+                .map(|(id, p)| (id.to_string(), (*p, "20250103".to_string())))
+                .collect(),
+        }
+    }
+
+    fn targets(ids: &[(&str, u32)]) -> Vec<(String, u32)> {
+        ids.iter().map(|(id, qty)| (id.to_string(), *qty)).collect()
+    }
+
+    fn ids(stores: &[&StoreOffer]) -> Vec<String> {
+        stores.iter().map(|s| s.store_id.clone()).collect()
+    }
+
+    #[test]
+    fn single_store_skips_stores_missing_an_item() {
+        let partial = offer("partial", 0.5, 127.0, &[("A", 100)]);
+        let full = offer("full", 2.0, 127.0, &[("A", 300), ("B", 200)]);
+        let candidates = [&partial, &full];
+
+        let (store, cost) = best_single_store(&candidates, &targets(&[("A", 2), ("B", 1)]), 100.0).unwrap();
+        assert_eq!(store.store_id, "full");
+        assert_eq!(cost.items_total, 800);
+        // 왕복 이동 거리
+        assert_eq!(cost.travel_km, 4.0);
+        assert_eq!(cost.total_cost, 1200.0);
+
+        assert!(best_single_store(&candidates, &targets(&[("C", 1)]), 100.0).is_none());
+    }
+
+    #[test]
+    fn single_store_tie_prefers_shorter_travel() {
+        let far = offer("far", 3.0, 127.0, &[("A", 1000)]);
+        let near = offer("near", 1.0, 127.0, &[("A", 1000)]);
+
+        let (store, _) = best_single_store(&[&far, &near], &targets(&[("A", 1)]), 0.0).unwrap();
+        assert_eq!(store.store_id, "near");
+    }
+
+    #[test]
+    fn two_store_splits_items_and_counts_round_trip() {
+        let first = offer("first", 1.0, 127.0, &[("A", 100), ("B", 500)]);
+        let second = offer("second", 2.0, 127.01, &[("A", 300), ("B", 200)]);
+        let candidates = [&second, &first];
+
+        let plan = best_two_store(&candidates, &targets(&[("A", 1), ("B", 1)]), 10.0).unwrap();
+        assert_eq!(ids(&[plan.first, plan.second]), vec!["first", "second"]);
+        assert_eq!(ids(&plan.assignment), vec!["first", "second"]);
+        assert_eq!(plan.cost.items_total, 300);
+
+        let between = great_circle_km(37.5, 127.0, 37.5, 127.01);
+        assert!((plan.cost.travel_km - (1.0 + between + 2.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn two_store_requires_coverage_and_both_stores_used() {
+        // "cheap"이 모든 상품에서 더 싸면 두 매장 모두 사용하는 조합이 아니다.
+        let cheap = offer("cheap", 1.0, 127.0, &[("A", 100), ("B", 100)]);
+        let pricey = offer("pricey", 2.0, 127.0, &[("A", 200), ("B", 200)]);
+        assert!(best_two_store(&[&cheap, &pricey], &targets(&[("A", 1), ("B", 1)]), 0.0).is_none());
+
+        // 두 매장을 합쳐도 살 수 없는 상품이 있으면 조합에서 제외한다.
+        let only_a = offer("only_a", 1.0, 127.0, &[("A", 100)]);
+        let only_b = offer("only_b", 2.0, 127.0, &[("B", 100)]);
+        let only_c = offer("only_c", 3.0, 127.0, &[("C", 100)]);
+        let needs_abc = targets(&[("A", 1), ("B", 1), ("C", 1)]);
+        assert!(best_two_store(&[&only_a, &only_b, &only_c], &needs_abc, 0.0).is_none());
+
+        let plan = best_two_store(&[&only_a, &only_b, &only_c], &targets(&[("A", 1), ("B", 1)]), 0.0).unwrap();
+        assert_eq!(ids(&[plan.first, plan.second]), vec!["only_a", "only_b"]);
+    }
+
+    #[test]
+    fn two_store_same_price_goes_to_first_visited_store() {
+        let near = offer("near", 1.0, 127.0, &[("A", 100), ("B", 500)]);
+        let far = offer("far", 2.0, 127.0, &[("A", 100), ("B", 200)]);
+
+        let plan = best_two_store(&[&far, &near], &targets(&[("A", 1), ("B", 1)]), 0.0).unwrap();
+        assert_eq!(ids(&plan.assignment), vec!["near", "far"]);
+    }
+
+    #[test]
+    fn two_store_tie_prefers_shorter_travel() {
+        let a = offer("a", 1.0, 127.0, &[("A", 100)]);
+        let b_far = offer("b_far", 3.0, 127.0, &[("B", 100)]);
+        let b_near = offer("b_near", 2.0, 127.0, &[("B", 100)]);
+
+        let plan = best_two_store(&[&a, &b_far, &b_near], &targets(&[("A", 1), ("B", 1)]), 0.0).unwrap();
+        assert_eq!(ids(&[plan.first, plan.second]), vec!["a", "b_near"]);
+        assert_eq!(plan.cost.travel_km, 3.0);
+    }
+}
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// 전체 상품 조회 요청 DTO
+/// 
+/// # Fields
+/// * `include_inactive`    - 업스트림 목록에서 사라진 비활성 상품 포함 여부 (기본값: false)
+/// * `category_code`       - 상품분류 코드 필터 (없으면 전체)
+#[derive(Deserialize)]
+pub struct GoodListReq {
+    pub include_inactive: Option<bool>,
+    pub category_code: Option<String>,
+}
+
+/// 상품 변경 이력 조회 요청 DTO
+/// 
+/// # Fields
+/// * `good_id`   - 조회할 상품 ID
+/// * `limit`     - 최대 조회 수 (기본값: 100, 최대: 1000)
+#[derive(Deserialize)]
+pub struct GoodHistoryReq {
+    pub good_id: String,
+    pub limit: Option<i64>,
+}
+
+/// 상품 검색 요청 DTO
+/// 
+/// # Fields
+/// * `q`       - 검색어 (상품명 일부, 오타 포함 상품명, 초성 — 예: "ㅅㄹㅁ")
+/// * `mode`    - 검색 방식 (search: 관련도 검색(기본값), autocomplete: 메모리 인덱스 자동완성)
+/// * `limit`   - 최대 조회 수 (search 기본값: 20 / 최대: 100, autocomplete 기본값: 10 / 최대: 50)
+#[derive(Deserialize)]
+pub struct GoodSearchReq {
+    pub q: String,
+    pub mode: Option<GoodSearchMode>,
+    pub limit: Option<i64>,
+}
+
+/// 상품 검색 방식
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum GoodSearchMode {
+    #[default]
+    Search,
+    Autocomplete,
+}
\ No newline at end of file
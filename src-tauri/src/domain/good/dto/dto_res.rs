@@ -11,6 +11,12 @@ use serde::Serialize;
 /// * `total_div_code`  - 구분 코드
 /// * `created_at`      - 생성 시각
 /// * `updated_at`      - 수정 시각
+/// * `is_active`       - 최신 업스트림 목록 포함 여부
+/// * `last_seen_at`    - 업스트림 목록에서 마지막으로 확인된 시각
+/// * `category_code`   - 상품분류 코드
+/// * `category_name`   - 상품분류명
+/// * `unit_code`       - 단위 코드
+/// * `unit_name`       - 단위명
 #[derive(Serialize)]
 pub struct GoodRes {
     pub id: i32,
@@ -20,4 +26,59 @@ pub struct GoodRes {
     pub total_div_code: Option<String>,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    pub is_active: bool,
+    pub last_seen_at: NaiveDateTime,
+    pub category_code: Option<String>,
+    pub category_name: Option<String>,
+    pub unit_code: Option<String>,
+    pub unit_name: Option<String>,
+}
+
+/// 상품 변경 이력 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `id`          - 변경 이력 ID
+/// * `run_id`      - 변경을 일으킨 동기화 실행 기록 ID
+/// * `field`       - 변경된 필드명
+/// * `old_value`   - 변경 전 값
+/// * `new_value`   - 변경 후 값
+/// * `changed_at`  - 변경 시각
+#[derive(Serialize)]
+pub struct GoodHistoryRes {
+    pub id: i32,
+    pub run_id: Option<i32>,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: NaiveDateTime,
+}
+
+/// 상품 검색 결과 응답 DTO
+/// 
+/// # Fields
+/// * `good_id`         - 상품 ID
+/// * `good_name`       - 상품명
+/// * `category_code`   - 상품분류 코드
+/// * `relevance`       - 관련도 (완전 일치 1.0 > 앞부분 0.9 > 부분 0.8 > 초성 0.7 > 유사도)
+/// * `popularity`      - 인기도 (사용자 선택 수)
+#[derive(Serialize)]
+pub struct GoodSearchRes {
+    pub good_id: String,
+    pub good_name: String,
+    pub category_code: Option<String>,
+    pub relevance: f64,
+    pub popularity: i64,
+}
+
+/// 상품명 자동완성 결과 응답 DTO
+/// 
+/// # Fields
+/// * `good_id`     - 상품 ID
+/// * `good_name`   - 상품명
+/// * `popularity`  - 인기도 (사용자 선택 수)
+#[derive(Serialize)]
+pub struct GoodSuggestionRes {
+    pub good_id: String,
+    pub good_name: String,
+    pub popularity: i64,
 }
\ No newline at end of file
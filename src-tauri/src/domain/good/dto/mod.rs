@@ -1 +1,2 @@
+pub mod dto_req;
 pub mod dto_res;
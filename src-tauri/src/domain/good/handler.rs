@@ -8,26 +8,72 @@
  * Responsibilities:
  *     1) auth_google_handler()
  *         - 전체 상품 목록 조회 처리
+ *
+ *     2) goods_history_handler()
+ *         - 상품 변경 이력 조회 처리
+ *
+ *     3) goods_search_handler()
+ *         - 상품 검색 / 자동완성 처리
 ************************************************************************/
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 use serde_json::json;
 use sqlx::PgPool;
 
-use crate::domain::good::service;
+use crate::domain::good::{
+    dto::dto_req::{GoodHistoryReq, GoodListReq, GoodSearchMode, GoodSearchReq},
+    service,
+};
 
 /// 전체 상품(goods) 목록을 조회하는 핸들러이다.
+/// 비활성 상품은 `include_inactive=true`일 때만 포함하며,
+/// `category_code`를 지정하면 해당 상품분류의 상품만 반환한다.
 ///
 /// # Arguments
 /// * `pool`    - DB 연결 풀
+/// * `req`     - GoodListReq { include_inactive, category_code }
 ///
 /// # Returns
 /// * `200 Ok`                      - 전체 상품 목록 반환
 /// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
 pub async fn goods_list_handler(
-    State(pool): State<PgPool>
+    State(pool): State<PgPool>,
+    Query(req): Query<GoodListReq>,
+) -> impl IntoResponse {
+    match service::get_all_goods(
+        &pool,
+        req.include_inactive.unwrap_or(false),
+        req.category_code.as_deref(),
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
+
+/// 특정 상품의 변경 이력을 조회하는 핸들러이다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - GoodHistoryReq { good_id, limit }
+///
+/// # Returns
+/// * `200 Ok`                      - 변경 이력 목록 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn goods_history_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<GoodHistoryReq>,
 ) -> impl IntoResponse {
-    match service::get_all_goods(&pool).await {
+    match service::get_good_history(&pool, &req.good_id, req.limit).await {
         Ok(res) => (StatusCode::OK, res.into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -35,3 +81,45 @@ pub async fn goods_list_handler(
         ),
     }
 }
+
+/// 상품명을 검색하거나 자동완성하는 핸들러이다.
+/// `mode=autocomplete`이면 메모리 인덱스에서 상품명 상위 N개를 빠르게 반환하고,
+/// 그 외에는 부분 일치 / 유사도 / 초성 일치 결과를 관련도와 인기도 순으로 반환한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - GoodSearchReq { q, mode, limit }
+///
+/// # Returns
+/// * `200 Ok`                      - 검색 / 자동완성 결과 반환
+/// * `400 BAD_REQUEST`             - 빈 검색어
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn goods_search_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<GoodSearchReq>,
+) -> impl IntoResponse {
+    let q = req.q.trim();
+    if q.is_empty() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "message": "검색어가 비어 있습니다" })).into_response(),
+        );
+    }
+
+    let result = match req.mode.unwrap_or_default() {
+        GoodSearchMode::Search => service::search_goods(&pool, q, req.limit)
+            .await
+            .map(|res| res.into_response()),
+        GoodSearchMode::Autocomplete => service::autocomplete_goods(&pool, q, req.limit)
+            .await
+            .map(|res| res.into_response()),
+    };
+
+    match result {
+        Ok(res) => (StatusCode::OK, res),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
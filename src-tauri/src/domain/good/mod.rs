@@ -1,3 +1,4 @@
 pub mod dto;
 pub mod handler;
+pub mod search_index;
 pub mod service;
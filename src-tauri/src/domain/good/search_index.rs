@@ -0,0 +1,446 @@
+/************************************************************************
+ * File: domain/good/search_index.rs
+ * Description:
+ *     상품명 자동완성을 위한 메모리 검색 인덱스를 관리한다.
+ *     상품 동기화가 끝날 때마다 다시 만들고, 다른 프로세스(관리자 CLI 등)에서
+ *     동기화한 변경도 반영되도록 일정 시간이 지나면 백그라운드에서 갱신한다.
+ *
+ * Responsibilities:
+ *     1) to_choseong() / has_choseong()
+ *         - 한글 음절을 초성으로 변환 / 검색어의 초성 포함 여부 확인
+ *         - 검색어의 음절은 그대로, 초성은 상품명 음절의 초성과 비교
+ *
+ *     2) rebuild_search_index()
+ *         - 활성 상품 목록으로 인덱스 재생성
+ *
+ *     3) ensure_search_index()
+ *         - 인덱스가 없으면 생성 (동시 요청은 한 번만 생성), 오래되었으면 백그라운드 갱신
+ *
+ *     4) autocomplete()
+ *         - 앞부분 / 초성 / 부분 일치 상품명 상위 N개 반환
+ *
+ *     5) choseong_matches()
+ *         - 초성이 일치하는 상품 ID 목록 반환 (DB 검색에 전달)
+************************************************************************/
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        RwLock,
+    },
+    time::{Duration, Instant},
+};
+
+use sqlx::PgPool;
+
+use crate::common::repository::repository_good::find_goods_for_search_index;
+
+/// 인덱스를 백그라운드에서 다시 만드는 주기
+const SEARCH_INDEX_TTL: Duration = Duration::from_secs(600);
+
+/// 한글 초성 (호환용 자모, 유니코드 음절 순서)
+const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ',
+    'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// 한글 음절 범위 시작 ('가')
+const HANGUL_BASE: u32 = 0xAC00;
+/// 한글 음절 범위 끝 ('힣')
+const HANGUL_LAST: u32 = 0xD7A3;
+/// 초성 하나에 속하는 음절 수 (중성 21 × 종성 28)
+const SYLLABLES_PER_CHOSEONG: u32 = 588;
+
+static INDEX: RwLock<SearchIndex> = RwLock::new(SearchIndex {
+    entries: Vec::new(),
+    built_at: None,
+});
+static REBUILDING: AtomicBool = AtomicBool::new(false);
+/// 최초 생성을 한 번만 수행하도록 막는 잠금
+static INITIALIZING: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
+/// 메모리 검색 인덱스
+///
+/// # Fields
+/// * `entries`     - 인덱스 항목 목록
+/// * `built_at`    - 마지막 생성 시각 (없으면 아직 생성 전)
+struct SearchIndex {
+    entries: Vec<IndexEntry>,
+    built_at: Option<Instant>,
+}
+
+/// 인덱스 항목
+///
+/// # Fields
+/// * `good_id`     - 상품 ID
+/// * `good_name`   - 상품명
+/// * `normalized`  - 공백을 없애고 소문자로 바꾼 상품명 (문자 단위)
+/// * `choseong`    - normalized의 각 문자를 초성으로 바꾼 값 (normalized와 같은 위치)
+/// * `popularity`  - 인기도 (사용자 선택 수)
+struct IndexEntry {
+    good_id: String,
+    good_name: String,
+    normalized: Vec<char>,
+    choseong: Vec<char>,
+    popularity: i64,
+}
+
+impl IndexEntry {
+    fn new(
+        good_id: String,
+        good_name: String,
+        popularity: i64,
+    ) -> Self {
+        let normalized = normalize(&good_name);
+        IndexEntry {
+            choseong: to_choseong(&normalized).chars().collect(),
+            normalized: normalized.chars().collect(),
+            good_id,
+            good_name,
+            popularity,
+        }
+    }
+
+    /// 상품명의 start 위치부터 검색어가 일치하는지 확인한다.
+    /// 검색어의 초성은 상품명 음절의 초성과, 그 외 문자는 상품명 문자와 그대로 비교한다.
+    /// (예: "신ㄹ"은 "신라면"과 일치하지만 "삼립"과는 일치하지 않음)
+    fn matches_at(
+        &self,
+        query: &[char],
+        start: usize,
+    ) -> bool {
+        start + query.len() <= self.normalized.len()
+            && query.iter().enumerate().all(|(i, q)| {
+                let target = if CHOSEONG.contains(q) { &self.choseong } else { &self.normalized };
+                target[start + i] == *q
+            })
+    }
+
+    /// 검색어가 상품명 어딘가에서 일치하는지 확인한다.
+    fn matches_anywhere(
+        &self,
+        query: &[char],
+    ) -> bool {
+        (0..self.normalized.len()).any(|start| self.matches_at(query, start))
+    }
+}
+
+/// 백그라운드 갱신이 끝나거나 실패해도(패닉 포함) 갱신 중 표시를 해제한다.
+struct RebuildingGuard;
+
+impl Drop for RebuildingGuard {
+    fn drop(&mut self) {
+        REBUILDING.store(false, Ordering::Release);
+    }
+}
+
+/// 자동완성 결과
+///
+/// # Fields
+/// * `good_id`     - 상품 ID
+/// * `good_name`   - 상품명
+/// * `popularity`  - 인기도 (사용자 선택 수)
+pub struct Suggestion {
+    pub good_id: String,
+    pub good_name: String,
+    pub popularity: i64,
+}
+
+/// 문자열의 한글 음절을 초성으로 바꾼다. 한글 음절이 아닌 문자는 그대로 둔다.
+///
+/// # Arguments
+/// * `s` - 변환할 문자열
+///
+/// # Returns
+/// * `String` - 초성으로 바뀐 문자열 (예: "신라면" → "ㅅㄹㅁ")
+pub fn to_choseong(
+    s: &str
+) -> String {
+    s.chars()
+        .map(|c| match c as u32 {
+            code @ HANGUL_BASE..=HANGUL_LAST => {
+                CHOSEONG[((code - HANGUL_BASE) / SYLLABLES_PER_CHOSEONG) as usize]
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+/// 검색어에 초성(호환용 자모 자음)이 하나라도 있는지 확인한다.
+///
+/// # Arguments
+/// * `q` - 검색어
+///
+/// # Returns
+/// * `bool` - 초성 포함 여부
+pub fn has_choseong(
+    q: &str
+) -> bool {
+    q.chars().any(|c| CHOSEONG.contains(&c))
+}
+
+/// 공백을 없애고 소문자로 바꾼다.
+fn normalize(
+    s: &str
+) -> String {
+    s.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 활성 상품 목록으로 검색 인덱스를 다시 만든다.
+///
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+///
+/// # Returns
+/// * `Ok(usize)`   - 인덱스에 담긴 상품 수
+/// * `Err(String)` - 상품 조회 실패
+pub async fn rebuild_search_index(
+    pool: &PgPool
+) -> Result<usize, String> {
+    let goods = find_goods_for_search_index(pool).await?;
+
+    let entries: Vec<IndexEntry> = goods
+        .into_iter()
+        .map(|g| IndexEntry::new(g.good_id, g.good_name, g.popularity))
+        .collect();
+    let count = entries.len();
+
+    let mut index = INDEX
+        .write()
+        .map_err(|_| "검색 인덱스 잠금 실패".to_string())?;
+    index.entries = entries;
+    index.built_at = Some(Instant::now());
+    drop(index);
+
+    tracing::info!("상품 검색 인덱스 재생성 완료 ({}개)", count);
+    Ok(count)
+}
+
+/// 검색 인덱스가 없으면 만들고, 오래되었으면 백그라운드에서 다시 만든다.
+/// 최초 생성은 동시에 들어온 요청 중 하나만 수행하고 나머지는 완료를 기다린다.
+/// 백그라운드 갱신 중에는 기존 인덱스로 계속 응답한다.
+///
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+///
+/// # Returns
+/// * `Ok(())`      - 인덱스 사용 가능
+/// * `Err(String)` - 최초 생성 실패
+pub async fn ensure_search_index(
+    pool: &PgPool
+) -> Result<(), String> {
+    let built_at = INDEX
+        .read()
+        .map_err(|_| "검색 인덱스 잠금 실패".to_string())?
+        .built_at;
+
+    match built_at {
+        None => {
+            let _init = INITIALIZING.lock().await;
+            // 잠금을 기다리는 동안 다른 요청이 만들었으면 다시 만들지 않는다.
+            let built = INDEX
+                .read()
+                .map_err(|_| "검색 인덱스 잠금 실패".to_string())?
+                .built_at
+                .is_some();
+            if !built {
+                rebuild_search_index(pool).await?;
+            }
+        }
+        Some(at) if at.elapsed() >= SEARCH_INDEX_TTL => {
+            if !REBUILDING.swap(true, Ordering::AcqRel) {
+                let pool = pool.clone();
+                tokio::spawn(async move {
+                    let _guard = RebuildingGuard;
+                    if let Err(e) = rebuild_search_index(&pool).await {
+                        tracing::warn!("상품 검색 인덱스 갱신 실패: {}", e);
+                    }
+                });
+            }
+        }
+        Some(_) => {}
+    }
+
+    Ok(())
+}
+
+/// 검색어와 앞부분 / 초성 / 부분 일치하는 상품명을 상위 N개 반환한다.
+///
+/// 앞부분 일치 > 초성 앞부분 일치 > 부분 일치 > 초성 부분 일치 순이며,
+/// 같은 순위에서는 인기도가 높고 상품명이 짧은 순으로 정렬한다.
+/// 초성이 섞인 검색어는 음절은 그대로, 초성은 상품명 음절의 초성과 비교한다.
+///
+/// # Arguments
+/// * `q`       - 검색어
+/// * `limit`   - 최대 반환 수
+///
+/// # Returns
+/// * `Vec<Suggestion>` - 자동완성 결과
+pub fn autocomplete(
+    q: &str,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let Ok(index) = INDEX.read() else {
+        return Vec::new();
+    };
+
+    rank_entries(&index.entries, q, limit)
+}
+
+/// 인덱스 항목을 검색어로 걸러 autocomplete() 순위대로 상위 N개를 반환한다.
+///
+/// # Arguments
+/// * `entries` - 인덱스 항목 목록
+/// * `q`       - 검색어
+/// * `limit`   - 최대 반환 수
+///
+/// # Returns
+/// * `Vec<Suggestion>` - 자동완성 결과
+fn rank_entries(
+    entries: &[IndexEntry],
+    q: &str,
+    limit: usize,
+) -> Vec<Suggestion> {
+    let query: Vec<char> = normalize(q).chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    // 초성이 섞인 검색어는 초성 일치 순위(1, 3)로 분류한다.
+    let offset = u8::from(has_choseong(q));
+
+    let mut ranked: Vec<(u8, &IndexEntry)> = entries
+        .iter()
+        .filter_map(|e| {
+            let rank = if e.matches_at(&query, 0) {
+                offset
+            } else if e.matches_anywhere(&query) {
+                2 + offset
+            } else {
+                return None;
+            };
+            Some((rank, e))
+        })
+        .collect();
+
+    ranked.sort_by(|(ra, a), (rb, b)| {
+        ra.cmp(rb)
+            .then_with(|| b.popularity.cmp(&a.popularity))
+            .then_with(|| a.good_name.chars().count().cmp(&b.good_name.chars().count()))
+            .then_with(|| a.good_name.cmp(&b.good_name))
+    });
+
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(_, e)| Suggestion {
+            good_id: e.good_id.clone(),
+            good_name: e.good_name.clone(),
+            popularity: e.popularity,
+        })
+        .collect()
+}
+
+/// 검색어가 상품명과 초성 기준으로 부분 일치하는 상품 ID 목록을 반환한다.
+/// (검색어의 음절은 그대로, 초성은 상품명 음절의 초성과 비교)
+/// 검색어에 초성이 없으면 빈 목록을 반환한다.
+///
+/// # Arguments
+/// * `q` - 검색어
+///
+/// # Returns
+/// * `Vec<String>` - 초성이 일치하는 상품 ID 목록
+pub fn choseong_matches(
+    q: &str
+) -> Vec<String> {
+    if !has_choseong(q) {
+        return Vec::new();
+    }
+
+    let Ok(index) = INDEX.read() else {
+        return Vec::new();
+    };
+
+    matching_ids(&index.entries, q)
+}
+
+/// 검색어가 상품명 어딘가에서 일치하는 인덱스 항목의 상품 ID 목록을 반환한다.
+fn matching_ids(
+    entries: &[IndexEntry],
+    q: &str,
+) -> Vec<String> {
+    let query: Vec<char> = normalize(q).chars().collect();
+
+    entries
+        .iter()
+        .filter(|e| e.matches_anywhere(&query))
+        .map(|e| e.good_id.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(goods: &[(&str, &str, i64)]) -> Vec<IndexEntry> {
+        goods
+            .iter()
+            .map(|(id, name, popularity)| IndexEntry::new(id.to_string(), name.to_string(), *popularity))
+            .collect()
+    }
+
+    fn ranked_ids(entries: &[IndexEntry], q: &str) -> Vec<String> {
+        rank_entries(entries, q, 10).into_iter().map(|s| s.good_id).collect()
+    }
+
+    #[test]
+    fn to_choseong_converts_only_hangul_syllables() {
+        assert_eq!(to_choseong("신라면"), "ㅅㄹㅁ");
+        assert_eq!(to_choseong("까르보 불닭"), "ㄲㄹㅂ ㅂㄷ");
+        assert_eq!(to_choseong("CJ 햇반 210g"), "CJ ㅎㅂ 210g");
+        assert_eq!(to_choseong("ㅅㄹ"), "ㅅㄹ");
+        assert_eq!(to_choseong("가힣"), "ㄱㅎ");
+    }
+
+    #[test]
+    fn has_choseong_detects_jamo() {
+        assert!(has_choseong("ㅅㄹㅁ"));
+        assert!(has_choseong("신ㄹ"));
+        assert!(!has_choseong("신라면"));
+        assert!(!has_choseong("cola"));
+    }
+
+    #[test]
+    fn mixed_query_matches_syllables_exactly() {
+        let entries = entries(&[("1", "신라면", 1), ("2", "삼립 호빵", 100), ("3", "농심 신라면 컵", 5)]);
+
+        assert_eq!(ranked_ids(&entries, "신ㄹ"), vec!["1", "3"]);
+        // 초성만 있는 검색어는 초성이 일치하는 상품을 모두 찾는다.
+        assert_eq!(ranked_ids(&entries, "ㅅㄹ"), vec!["2", "1", "3"]);
+        assert_eq!(matching_ids(&entries, "신ㄹ"), vec!["1", "3"]);
+    }
+
+    #[test]
+    fn ranks_prefix_before_choseong_before_contains() {
+        let entries = entries(&[("1", "농심 라면", 100), ("2", "라면사리", 1), ("3", "진짬뽕 라볶이", 100)]);
+
+        // 앞부분 일치는 인기도와 관계없이 부분 일치보다 앞선다.
+        assert_eq!(ranked_ids(&entries, "라면"), vec!["2", "1"]);
+        assert_eq!(ranked_ids(&entries, "ㄹㅁ"), vec!["2", "1"]);
+        // 같은 순위(초성 부분 일치)에서는 인기도가 같으면 짧은 상품명이 앞선다.
+        assert_eq!(ranked_ids(&entries, "ㄹ"), vec!["2", "1", "3"]);
+        // 음절 검색어도 앞부분 일치 다음에 부분 일치를 같은 기준으로 정렬한다.
+        assert_eq!(ranked_ids(&entries, "라"), vec!["2", "1", "3"]);
+    }
+
+    #[test]
+    fn ties_break_by_popularity_then_shorter_name() {
+        let entries = entries(&[("a", "우유 1L", 3), ("b", "우유 200ml", 3), ("c", "우유 900ml", 10)]);
+
+        assert_eq!(ranked_ids(&entries, "우유"), vec!["c", "a", "b"]);
+        assert_eq!(rank_entries(&entries, "우유", 1).len(), 1);
+        assert!(rank_entries(&entries, "  ", 10).is_empty());
+    }
+}
@@ -5,28 +5,57 @@
  *
  * Responsibilities:
  *     1) get_all_goods()
- *         - goods 테이블 전체를 조회하여 반환
+ *         - goods 테이블 전체를 조회하여 반환 (기본적으로 비활성 상품 제외)
+ *         - 상품분류 코드 필터 및 상품분류명 / 단위명 표시
+ *
+ *     2) get_good_history()
+ *         - 상품 기준 정보의 변경 이력을 최신순으로 반환
+ *
+ *     3) search_goods()
+ *         - 부분 일치 / trigram 유사도 / 초성 일치 상품 검색 (관련도 → 인기도 순)
+ *
+ *     4) autocomplete_goods()
+ *         - 메모리 검색 인덱스로 상품명 상위 N개 자동완성
 ************************************************************************/
 
 use axum::Json;
 use sqlx::PgPool;
 
-use crate::{common::repository::repository_good, domain::good::dto::dto_res::GoodRes};
+use crate::{
+    common::repository::{
+        repository_good,
+        repository_master_history::{find_master_history, HISTORY_DEFAULT_LIMIT, HISTORY_MAX_LIMIT},
+    },
+    domain::{
+        good::{
+            dto::dto_res::{GoodHistoryRes, GoodRes, GoodSearchRes, GoodSuggestionRes},
+            search_index::{autocomplete, choseong_matches, ensure_search_index},
+        },
+        standard_code::service::load_code_names,
+        sync::dto::dto_req::StandardCodeClass,
+    },
+};
 
 /// 전체 상품 목록을 조회하여 클라이언트로 반환한다
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`                - DB 커넥션 풀
+/// * `include_inactive`    - 비활성 상품 포함 여부
+/// * `category_code`       - 상품분류 코드 필터
 /// 
 /// # Returns
 /// * `Ok(Json<Vec<GoodRes>)`   - 전체 상품 목록 
 /// * `Err(String)`             - 조회 실패
 pub async fn get_all_goods(
-    pool: &PgPool
+    pool: &PgPool,
+    include_inactive: bool,
+    category_code: Option<&str>,
 ) -> Result<Json<Vec<GoodRes>>, String> {
-    let goods = repository_good::find_all_goods(pool)
+    let goods = repository_good::find_all_goods(pool, include_inactive, category_code)
         .await
         .map_err(|e| format!("DB 조회 실패: {}", e))?;
+    let category_names = load_code_names(pool, StandardCodeClass::GoodCategory).await?;
+    let unit_names = load_code_names(pool, StandardCodeClass::Unit).await?;
 
     let result = goods
         .into_iter()
@@ -38,8 +67,134 @@ pub async fn get_all_goods(
             total_div_code: g.total_div_code,
             created_at: g.created_at,
             updated_at: g.created_at,
+            is_active: g.is_active,
+            last_seen_at: g.last_seen_at,
+            category_name: g.category_code.as_ref().and_then(|c| category_names.get(c).cloned()),
+            category_code: g.category_code,
+            unit_name: g.unit_code.as_ref().and_then(|c| unit_names.get(c).cloned()),
+            unit_code: g.unit_code,
         })
         .collect::<Vec<GoodRes>>();
 
     Ok(Json(result))
 }
+
+/// 특정 상품의 변경 이력(상품명, 총 개수 등 필드별 기존 값 / 새 값)을 최신순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `good_id`     - 조회할 상품 ID
+/// * `limit`       - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<GoodHistoryRes>>)`   - 변경 이력 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn get_good_history(
+    pool: &PgPool,
+    good_id: &str,
+    limit: Option<i64>,
+) -> Result<Json<Vec<GoodHistoryRes>>, String> {
+    let limit = limit
+        .unwrap_or(HISTORY_DEFAULT_LIMIT)
+        .clamp(1, HISTORY_MAX_LIMIT);
+    let rows = find_master_history(pool, "good", good_id, limit)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    let result = rows
+        .into_iter()
+        .map(|h| GoodHistoryRes {
+            id: h.id,
+            run_id: h.run_id,
+            field: h.field,
+            old_value: h.old_value,
+            new_value: h.new_value,
+            changed_at: h.changed_at,
+        })
+        .collect::<Vec<GoodHistoryRes>>();
+
+    Ok(Json(result))
+}
+
+/// 상품 검색 기본 조회 수
+pub const SEARCH_DEFAULT_LIMIT: i64 = 20;
+/// 상품 검색 최대 조회 수
+pub const SEARCH_MAX_LIMIT: i64 = 100;
+/// 자동완성 기본 조회 수
+pub const AUTOCOMPLETE_DEFAULT_LIMIT: i64 = 10;
+/// 자동완성 최대 조회 수
+pub const AUTOCOMPLETE_MAX_LIMIT: i64 = 50;
+
+/// 상품명을 부분 일치 / trigram 유사도 / 초성 일치로 검색한다.
+/// 초성 일치는 메모리 검색 인덱스에서 찾아 DB 검색에 함께 전달한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `q`       - 검색어 (앞뒤 공백 제거 후 비어 있지 않아야 함)
+/// * `limit`   - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<GoodSearchRes>>)`    - 관련도 → 인기도 순 검색 결과
+/// * `Err(String)`                     - 조회 실패
+pub async fn search_goods(
+    pool: &PgPool,
+    q: &str,
+    limit: Option<i64>,
+) -> Result<Json<Vec<GoodSearchRes>>, String> {
+    let limit = limit
+        .unwrap_or(SEARCH_DEFAULT_LIMIT)
+        .clamp(1, SEARCH_MAX_LIMIT);
+
+    ensure_search_index(pool).await?;
+    let choseong_ids = choseong_matches(q);
+
+    let rows = repository_good::search_goods(pool, q, &choseong_ids, limit)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    let result = rows
+        .into_iter()
+        .map(|g| GoodSearchRes {
+            good_id: g.good_id,
+            good_name: g.good_name,
+            category_code: g.category_code,
+            relevance: g.relevance,
+            popularity: g.popularity,
+        })
+        .collect::<Vec<GoodSearchRes>>();
+
+    Ok(Json(result))
+}
+
+/// 메모리 검색 인덱스로 상품명을 자동완성한다. (DB 조회 없음)
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀 (인덱스가 없을 때 생성용)
+/// * `q`       - 검색어 (앞뒤 공백 제거 후 비어 있지 않아야 함)
+/// * `limit`   - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<GoodSuggestionRes>>)`    - 자동완성 결과
+/// * `Err(String)`                         - 인덱스 생성 실패
+pub async fn autocomplete_goods(
+    pool: &PgPool,
+    q: &str,
+    limit: Option<i64>,
+) -> Result<Json<Vec<GoodSuggestionRes>>, String> {
+    let limit = limit
+        .unwrap_or(AUTOCOMPLETE_DEFAULT_LIMIT)
+        .clamp(1, AUTOCOMPLETE_MAX_LIMIT);
+
+    ensure_search_index(pool).await?;
+
+    let result = autocomplete(q, limit as usize)
+        .into_iter()
+        .map(|s| GoodSuggestionRes {
+            good_id: s.good_id,
+            good_name: s.good_name,
+            popularity: s.popularity,
+        })
+        .collect::<Vec<GoodSuggestionRes>>();
+
+    Ok(Json(result))
+}
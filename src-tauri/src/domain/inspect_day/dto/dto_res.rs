@@ -0,0 +1,19 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 조사일 정보 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `inspect_day`     - 조사일 (YYYYMMDD)
+/// * `status`          - 동기화 상태 ("discovered" | "syncing" | "synced" | "partial" | "failed")
+/// * `saved_prices`    - 저장된 가격 데이터 수
+/// * `failed_stores`   - 동기화에 실패한 매장 수
+/// * `synced_at`       - 마지막 동기화 완료 시각
+#[derive(Serialize)]
+pub struct InspectDayRes {
+    pub inspect_day: String,
+    pub status: String,
+    pub saved_prices: i32,
+    pub failed_stores: i32,
+    pub synced_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,3 @@
+pub mod dto;
+pub mod handler;
+pub mod service;
@@ -0,0 +1,44 @@
+/************************************************************************
+ * File: domain/inspect_day/service.rs
+ * Description:
+ *     조사일(inspect_day) 도메인의 서비스 로직을 처리한다.
+ *
+ * Responsibilities:
+ *     1) get_all_inspect_days()
+ *         - 데이터가 게시된 조사일 목록을 최신순으로 조회하여 반환
+************************************************************************/
+
+use axum::Json;
+use sqlx::PgPool;
+
+use crate::{
+    common::repository::repository_inspect_day,
+    domain::inspect_day::dto::dto_res::InspectDayRes,
+};
+
+/// 데이터가 게시된 조사일 목록을 최신순으로 조회하여 클라이언트로 반환한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<InspectDayRes>>)`    - 조사일 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn get_all_inspect_days(
+    pool: &PgPool
+) -> Result<Json<Vec<InspectDayRes>>, String> {
+    let days = repository_inspect_day::find_all_inspect_days(pool).await?;
+
+    let result = days
+        .into_iter()
+        .map(|d| InspectDayRes {
+            inspect_day: d.inspect_day,
+            status: d.status,
+            saved_prices: d.saved_prices,
+            failed_stores: d.failed_stores,
+            synced_at: d.synced_at,
+        })
+        .collect::<Vec<InspectDayRes>>();
+
+    Ok(Json(result))
+}
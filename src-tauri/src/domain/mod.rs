@@ -1,9 +1,14 @@
+pub mod admin;
 pub mod auth;
+pub mod basket;
 pub mod good;
+pub mod inspect_day;
 pub mod user_log;
 pub mod user_preference;
 pub mod price;
 pub mod price_change;
+pub mod recommendation;
 pub mod region_code;
+pub mod standard_code;
 pub mod store;
 pub mod sync;
@@ -1,10 +1,19 @@
 use serde::Deserialize;
 
-/// 특정 상품의 가격 정보를 조회하기 위한 요청 DTO
+/// 상품의 매장별 최신 가격 정보를 조회하기 위한 요청 DTO
+/// good_id 또는 good_name 중 하나는 있어야 하며, 둘 다 있으면 good_id를 사용한다.
 /// 
 /// # Fields
-/// * `good_name` - 조회할 상품명
+/// * `good_id`     - 조회할 상품 ID (쉼표로 구분하여 여러 개, 최대 100개)
+/// * `good_name`   - 조회할 상품명 (같은 이름의 상품이 여러 개면 모두 조회)
+/// * `store_ids`   - 매장 ID 필터 (쉼표로 구분, 없으면 전체)
+/// * `region_code` - 지역 코드 필터 (없으면 전체, 모든 하위 지역 포함)
+/// * `include_inactive`    - 업스트림 목록에서 사라진 비활성 매장 포함 여부 (기본값: false)
 #[derive(Deserialize)]
 pub struct PriceReq {
-    pub good_name: String,
+    pub good_id: Option<String>,
+    pub good_name: Option<String>,
+    pub store_ids: Option<String>,
+    pub region_code: Option<String>,
+    pub include_inactive: Option<bool>,
 }
\ No newline at end of file
@@ -4,12 +4,22 @@ use sqlx::prelude::FromRow;
 /// 특정 상품의 매장별 가격 정보 조회 시 반환되는 응답 DTO
 /// 
 /// # Fields
-/// * `store_id`    - 매장 ID
-/// * `price`       - 해당 조사일의 가격
-/// * `inspect_day` - 가격 조사일 (YYYYMMDD)
+/// * `good_id`         - 상품 ID
+/// * `store_id`        - 매장 ID
+/// * `price`           - 해당 조사일의 가격
+/// * `inspect_day`     - 가격 조사일 (YYYYMMDD)
+/// * `is_one_plus_one` - 1+1 행사 여부
+/// * `is_discount`     - 할인 여부
+/// * `discount_start`  - 할인 시작일 (YYYYMMDD)
+/// * `discount_end`    - 할인 종료일 (YYYYMMDD)
 #[derive(Serialize, FromRow)]
 pub struct PriceRes {
+    pub good_id: String,
     pub store_id: String,
     pub price: i32,
     pub inspect_day: String,
+    pub is_one_plus_one: bool,
+    pub is_discount: bool,
+    pub discount_start: Option<String>,
+    pub discount_end: Option<String>,
 }
\ No newline at end of file
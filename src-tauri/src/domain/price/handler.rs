@@ -7,7 +7,7 @@
  *
  * Responsibilities:
  *     1) prices_get_handler()
- *         - 특정 상품의 최신 가격 목록 조회 처리
+ *         - 하나 또는 여러 상품의 매장별 최신 가격 목록 조회 처리
 ************************************************************************/
 
 use axum::{
@@ -19,22 +19,39 @@ use axum::{
 use serde_json::json;
 use sqlx::PgPool;
 
-use crate::domain::price::{dto::dto_req::PriceReq, service::get_latest_prices};
+use crate::domain::price::{
+    dto::dto_req::PriceReq,
+    service::{get_latest_prices, validate_price_req},
+};
 
-/// 특정 상품명에 대한 최신 가격 목록을 조회하는 핸들러이다.
+/// 하나 또는 여러 상품의 매장별 최신 가격 목록을 조회하는 핸들러이다.
+/// `good_id`(쉼표로 여러 개) 또는 `good_name`으로 상품을 지정하고,
+/// `store_ids` / `region_code`로 조회할 매장을 제한할 수 있다.
+/// 비활성 매장은 `include_inactive=true`일 때만 포함한다.
 ///
 /// # Arguments
 /// * `pool`    - DB 연결 풀
-/// * `params`  - PriceReq { good_name }
+/// * `params`  - PriceReq { good_id, good_name, store_ids, region_code, include_inactive }
 ///
 /// # Returns
-/// * `200 Ok`                      - 최신 가격 목록 반환
+/// * `200 Ok`                      - 최신 가격 목록 반환 (할인 / 1+1 여부, 할인 기간 포함)
+/// * `400 BAD_REQUEST`             - 상품 미지정 / 조회 개수 초과
 /// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
 pub async fn prices_get_handler(
     State(pool): State<PgPool>,
     Query(param): Query<PriceReq>,
 ) -> impl IntoResponse {
-    match get_latest_prices(&pool, &param.good_name).await {
+    let query = match validate_price_req(param) {
+        Ok(q) => q,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(json!({ "message": e })).into_response(),
+            )
+        }
+    };
+
+    match get_latest_prices(&pool, &query).await {
         Ok(list) => (StatusCode::OK, Json(list).into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
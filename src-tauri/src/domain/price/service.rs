@@ -4,29 +4,173 @@
  *     가격(price) 도메인의 서비스 로직을 처리한다.
  *
  * Responsibilities:
- *     1) get_latest_prices()
- *         - 특정 상품명 기준으로 가장 최근 조사일의 가격 목록 조회
+ *     1) validate_price_req()
+ *         - 상품 ID / 상품명, 매장 목록, 지역 코드 요청 검증
+ *
+ *     2) get_latest_prices()
+ *         - 하나 또는 여러 상품의 매장별 최신 가격 목록 조회
+ *           (할인 / 1+1 여부, 할인 기간 포함, 매장 / 지역 필터 선택)
 ************************************************************************/
 
 use sqlx::PgPool;
 
 use crate::{
-    common::repository::repository_join::find_latest_prices_by_good_name,
-    domain::price::dto::dto_res::PriceRes,
+    common::repository::{
+        repository_good::find_good_ids_by_name, repository_join::find_latest_prices,
+    },
+    domain::price::dto::{dto_req::PriceReq, dto_res::PriceRes},
 };
 
-/// 특정 상품명에 대해 가장 최근 조사일의 가격 정보를 조회한다.
+/// 한 번에 조회할 수 있는 최대 상품 수
+pub const PRICE_MAX_GOODS: usize = 100;
+/// 한 번에 지정할 수 있는 최대 매장 수
+pub const PRICE_MAX_STORES: usize = 1000;
+
+/// 조회할 상품 지정 방식
+pub enum PriceGoods {
+    Ids(Vec<String>),
+    Name(String),
+}
+
+/// 검증을 마친 가격 조회 요청
+/// 
+/// # Fields
+/// * `goods`       - 조회할 상품 (ID 목록 또는 상품명)
+/// * `store_ids`   - 매장 ID 목록 (없으면 전체)
+/// * `region_code` - 지역 코드 (없으면 전체)
+/// * `include_inactive`    - 비활성 매장 포함 여부
+pub struct PriceQuery {
+    pub goods: PriceGoods,
+    pub store_ids: Option<Vec<String>>,
+    pub region_code: Option<String>,
+    pub include_inactive: bool,
+}
+
+/// 쉼표로 구분된 ID 목록을 나눈다. 빈 항목과 중복은 제외한다.
+fn split_ids(
+    value: &str
+) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in value.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+        if !ids.iter().any(|existing| existing == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+/// 가격 조회 요청을 검증한다.
 /// 
 /// # Arguments
-/// * `pool`        - DB 커넥션 풀
-/// * `good_name`   - 조회할 상품명
+/// * `req` - 가격 조회 요청
 /// 
 /// # Returns
-/// * `Ok(Vec<PriceRes)`    - 매장별 최신 가격 목록
+/// * `Ok(PriceQuery)`  - 검증을 마친 요청
+/// * `Err(String)`     - 상품 미지정 / 상품 수 또는 매장 수 초과
+pub fn validate_price_req(
+    req: PriceReq
+) -> Result<PriceQuery, String> {
+    let good_ids = req.good_id.as_deref().map(split_ids).unwrap_or_default();
+    let good_name = req.good_name.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+
+    let goods = match (good_ids.is_empty(), good_name) {
+        (false, _) => {
+            if good_ids.len() > PRICE_MAX_GOODS {
+                return Err(format!("상품은 최대 {}개까지 조회할 수 있습니다", PRICE_MAX_GOODS));
+            }
+            PriceGoods::Ids(good_ids)
+        }
+        (true, Some(name)) => PriceGoods::Name(name),
+        (true, None) => return Err("good_id 또는 good_name이 필요합니다".to_string()),
+    };
+
+    let store_ids = req.store_ids.as_deref().map(split_ids).filter(|ids| !ids.is_empty());
+    if store_ids.as_ref().is_some_and(|ids| ids.len() > PRICE_MAX_STORES) {
+        return Err(format!("매장은 최대 {}개까지 지정할 수 있습니다", PRICE_MAX_STORES));
+    }
+
+    let region_code = req.region_code.map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+
+    Ok(PriceQuery {
+        goods,
+        store_ids,
+        region_code,
+        include_inactive: req.include_inactive.unwrap_or(false),
+    })
+}
+
+/// 하나 또는 여러 상품의 매장별 최신 가격 정보를 조회한다.
+/// 상품명으로 조회하면 같은 이름의 상품을 모두 조회하며, 응답의 good_id로 구분한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `query`   - 검증을 마친 가격 조회 요청
+/// 
+/// # Returns
+/// * `Ok(Vec<PriceRes)`    - (상품, 매장)별 최신 가격 목록
 /// * `Err(String)`         - DB 조회 실패
 pub async fn get_latest_prices(
     pool: &PgPool, 
-    good_name: &str
+    query: &PriceQuery
 ) -> Result<Vec<PriceRes>, String> {
-    find_latest_prices_by_good_name(pool, good_name).await
+    let good_ids = match &query.goods {
+        PriceGoods::Ids(ids) => ids.clone(),
+        PriceGoods::Name(name) => find_good_ids_by_name(pool, name).await?,
+    };
+
+    find_latest_prices(
+        pool,
+        &good_ids,
+        query.store_ids.as_deref(),
+        query.region_code.as_deref(),
+        query.include_inactive,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(good_id: Option<&str>, good_name: Option<&str>, store_ids: Option<&str>) -> PriceReq {
+        PriceReq {
+            good_id: good_id.map(str::to_string),
+            good_name: good_name.map(str::to_string),
+            store_ids: store_ids.map(str::to_string),
+            region_code: Some("  ".to_string()),
+            include_inactive: None,
+        }
+    }
+
+    #[test]
+    fn split_ids_trims_and_drops_empty_and_duplicate_ids() {
+        assert_eq!(split_ids("101, 102 ,,101, 103 "), vec!["101", "102", "103"]);
+        assert_eq!(split_ids("101"), vec!["101"]);
+        assert!(split_ids(" , ,").is_empty());
+        assert!(split_ids("").is_empty());
+    }
+
+    #[test]
+    fn good_ids_take_precedence_over_name() {
+        let query = validate_price_req(req(Some("101,102"), Some("우유"), None)).unwrap();
+        assert!(matches!(query.goods, PriceGoods::Ids(ids) if ids == ["101", "102"]));
+        assert_eq!(query.region_code, None);
+        assert!(!query.include_inactive);
+
+        let query = validate_price_req(req(Some(" , "), Some(" 우유 "), Some(" , "))).unwrap();
+        assert!(matches!(query.goods, PriceGoods::Name(name) if name == "우유"));
+        assert_eq!(query.store_ids, None);
+
+        assert!(validate_price_req(req(None, Some("  "), None)).is_err());
+    }
+
+    #[test]
+    fn good_and_store_counts_are_limited() {
+        let ids = |n: usize| (0..n).map(|i| i.to_string()).collect::<Vec<_>>().join(",");
+
+        assert!(validate_price_req(req(Some(&ids(PRICE_MAX_GOODS)), None, None)).is_ok());
+        assert!(validate_price_req(req(Some(&ids(PRICE_MAX_GOODS + 1)), None, None)).is_err());
+        assert!(validate_price_req(req(Some("101"), None, Some(&ids(PRICE_MAX_STORES)))).is_ok());
+        assert!(validate_price_req(req(Some("101"), None, Some(&ids(PRICE_MAX_STORES + 1)))).is_err());
+    }
 }
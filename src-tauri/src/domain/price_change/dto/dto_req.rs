@@ -9,6 +9,17 @@ pub struct PriceChangeReq {
     pub inspect_day: String,
 }
 
+/// 가격 변동 재계산 요청 DTO
+/// 
+/// # Fields
+/// * `from`    - 시작 조사일(YYYYMMDD)
+/// * `to`      - 종료 조사일(YYYYMMDD, 생략 시 시작 조사일 하루만 재계산)
+#[derive(Deserialize)]
+pub struct PriceChangeRecomputeReq {
+    pub from: String,
+    pub to: Option<String>,
+}
+
 /// 가격 변동 추이(상승/하락)을 조회하기 위한 요청 DTO
 /// 
 /// # Fields
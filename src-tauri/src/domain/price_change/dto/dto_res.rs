@@ -1,6 +1,8 @@
 use serde::Serialize;
 use sqlx::prelude::FromRow;
 
+use crate::domain::sync::dto::dto_res::PriceChangePairRes;
+
 /// 가격 변동 분석 결과를 반환하는 응답 DTO
 /// 
 /// # Fields
@@ -21,3 +23,16 @@ pub struct PriceTrendRes {
     pub change_count: i64,
     pub inspect_day: String,
 }
+
+/// 가격 변동 재계산 결과 응답 DTO
+/// 
+/// # Fields
+/// * `from`    - 시작 조사일
+/// * `to`      - 종료 조사일
+/// * `pairs`   - 재계산한 조사일 쌍별 결과
+#[derive(Serialize)]
+pub struct PriceChangeRecomputeRes {
+    pub from: String,
+    pub to: String,
+    pub pairs: Vec<PriceChangePairRes>,
+}
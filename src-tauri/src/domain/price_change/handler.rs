@@ -4,12 +4,17 @@
  *     가격 변동(price_change) 도메인의 라우팅 계층으로,
  *     클라이언트 요청을 받아 서비스 계층으로 전달하고
  *     그 결과를 HTTP 응답 형태로 반환한다.
+ *     가격변동 생성 / 재생성은 동기화 파이프라인과 같은 advisory lock을 잡고 실행되며,
+ *     이미 실행 중이면 409로 거절한다.
  *
  * Responsibilities:
  *     1) sync_price_change_handler()
- *         - 가격변동 데이터 생성 및 동기화
+ *         - 가격변동 데이터 생성 및 동기화 (백그라운드 실행)
  *
- *     1) price_change_get_handler()
+ *     2) recompute_price_change_handler()
+ *         - 하루 또는 기간 내 가격변동 데이터 재생성 (백그라운드 실행)
+ *
+ *     3) price_change_get_handler()
  *         - 가격 상승/하락 데이터 조회 처리
 ************************************************************************/
 
@@ -23,12 +28,16 @@ use sqlx::PgPool;
 
 use serde_json::json;
 
-use crate::domain::price_change::{
-    dto::dto_req::{PriceChangeReq, PriceTrendReq},
-    service::{get_price_change, upsert_price_change},
+use crate::domain::{
+    price_change::{
+        dto::dto_req::{PriceChangeRecomputeReq, PriceChangeReq, PriceTrendReq},
+        service::{get_price_change, recompute_price_change, upsert_price_change},
+    },
+    sync::{handler::spawn_pipeline_run, progress::SyncKind},
 };
 
-/// 가격변동 데이터를 생성하여 DB에 저장한다.
+/// 가격변동 데이터 생성을 백그라운드로 시작한다.
+/// 진행 상황은 /sync/runs/{id} 로 조회한다.
 ///
 /// # Arguments
 /// * `pool` - DB 커넥션 풀
@@ -36,19 +45,49 @@ use crate::domain::price_change::{
 ///
 ///
 /// # Returns
-/// * `200 OK`                      - 갱신 성공
-/// * `500 INTERNAL_SERVER_ERROR`   - 갱신 실패
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
 pub async fn sync_price_change_handler(
     State(pool): State<PgPool>,
     Query(param): Query<PriceChangeReq>,
 ) -> impl IntoResponse {
-    match upsert_price_change(&pool, &param.inspect_day).await {
-        Ok(msg) => (StatusCode::OK, msg.into_response()),
-        Err(e) => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(json!({ "message": e })).into_response(),
-        ),
-    }
+    let params = json!({ "inspect_day": param.inspect_day });
+    let job_pool = pool.clone();
+
+    spawn_pipeline_run(&pool, SyncKind::PriceChange, params, move |progress| async move {
+        upsert_price_change(&job_pool, &param.inspect_day, &progress)
+            .await
+            .map(|msg| json!({ "message": msg }))
+    })
+    .await
+}
+
+/// 하루 또는 기간 내 가격변동 데이터 재생성을 백그라운드로 시작한다.
+/// 진행 상황은 /sync/runs/{id} 로 조회한다.
+///
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `param`   - PriceChangeRecomputeReq { from, to }
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn recompute_price_change_handler(
+    State(pool): State<PgPool>,
+    Query(param): Query<PriceChangeRecomputeReq>,
+) -> impl IntoResponse {
+    let to = param.to.unwrap_or_else(|| param.from.clone());
+    let params = json!({ "from": param.from, "to": to });
+    let job_pool = pool.clone();
+
+    spawn_pipeline_run(&pool, SyncKind::PriceChangeRecompute, params, move |progress| async move {
+        recompute_price_change(&job_pool, &param.from, &to, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
 }
 
 /// 가격 변동 (상승/하락) 정보를 조회하는 핸들러이다.
@@ -8,32 +8,59 @@
  *         - 최근 조사일과 이전 조사일의 가격을 비교하여
  *           변동 결과를 price_change 테이블에 저장
  * 
- *     2) get_price_change()
+ *     2) generate_price_change_after_sync()
+ *         - 가격 동기화된 조사일과 앞뒤 조사일 사이의 가격 변동 재생성
+ * 
+ *     3) recompute_price_change()
+ *         - 하루 또는 기간 내 조사일의 가격 변동을 하나의 트랜잭션으로 재생성
+ * 
+ *     4) get_price_change()
  *         - 가격 변동 데이터를 조회하여 반환한다.
 ************************************************************************/
 
+use chrono::NaiveDate;
 use sqlx::PgPool;
 
 use crate::{
-    common::repository::{repository_join::{fetch_price_rise_top, find_price_drop_top}, repository_price::find_prev_day, repostiory_price_change::insert_price_change},
-    domain::price_change::dto::dto_res::PriceTrendRes,
+    common::repository::{
+        repository_join::{fetch_price_rise_top, find_price_drop_top},
+        repository_price::{find_next_day, find_prev_day, find_price_days_between},
+        repostiory_price_change::replace_price_change,
+    },
+    domain::{
+        price_change::dto::dto_res::{PriceChangeRecomputeRes, PriceTrendRes},
+        sync::{dto::dto_res::PriceChangePairRes, progress::SyncProgress},
+    },
 };
 
-/// 상품 정보를 API로부터 가져와 goods 테이블에 저장/갱신한다.
+/// 한 번에 재계산할 수 있는 최대 일수
+const RECOMPUTE_MAX_DAYS: i64 = 366;
+
+/// 기준 조사일과 직전 조사일의 가격을 비교하여 price_change 테이블에 저장/갱신한다.
+/// 기준일의 기존 변동 데이터는 다시 생성되므로 여러 번 실행해도 결과가 같다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`        - DB 커넥션 풀
+/// * `latest_day`  - 기준 조사일(YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 완료
+/// * `Ok(String)`  - 저장 완료 메시지
 /// * `Err(String)` - 저장 실패
-pub async fn upsert_price_change(pool: &PgPool, latest_day: &str) -> Result<String, String> {
-    // inpece_day 기준 prev_day 조회
-    let prev_day = find_prev_day(pool, latest_day)
-        .await
-        .map_err(|e| format!("prev_day 조회 실패: {}", e))?;
+pub async fn upsert_price_change(
+    pool: &PgPool,
+    latest_day: &str,
+    progress: &SyncProgress,
+) -> Result<String, String> {
+    // inspect_day 기준 prev_day 조회
+    let Some(prev_day) = find_prev_day(pool, latest_day).await? else {
+        progress.add_skipped(1);
+        return Ok(format!("price_change 생성 건너뜀: latest={} 이전 조사일 없음", latest_day));
+    };
 
-    let inserted_count = insert_price_change(pool, latest_day, &prev_day).await?;
+    let counts = replace_price_change(pool, &[(latest_day.to_string(), prev_day.clone())]).await?;
+    let inserted_count = counts.first().copied().unwrap_or(0);
+    progress.add_success(inserted_count as usize);
 
     Ok(format!(
         "price_change 생성 완료: latest={}, prev={}, inserted={}",
@@ -41,6 +68,151 @@ pub async fn upsert_price_change(pool: &PgPool, latest_day: &str) -> Result<Stri
     ))
 }
 
+/// 가격 동기화가 끝난 조사일을 기준으로 가격 변동을 다시 생성한다.
+/// (조사일, 직전 조사일) 쌍과 함께, 이후 조사일이 이미 있으면
+/// (다음 조사일, 조사일) 쌍도 함께 재생성한다. (과거 날짜 백필 대응)
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `inspect_day` - 가격 동기화된 조사일(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Vec<PriceChangePairRes>` - 조사일 쌍별 결과 (실패 시 message 포함)
+pub async fn generate_price_change_after_sync(
+    pool: &PgPool,
+    inspect_day: &str,
+) -> Vec<PriceChangePairRes> {
+    let neighbors = match (
+        find_prev_day(pool, inspect_day).await,
+        find_next_day(pool, inspect_day).await,
+    ) {
+        (Ok(prev), Ok(next)) => (prev, next),
+        (Err(e), _) | (_, Err(e)) => {
+            tracing::warn!("가격 변동 생성 대상 조회 실패 — {}: {}", inspect_day, e);
+            return vec![PriceChangePairRes {
+                inspect_day: inspect_day.to_string(),
+                prev_day: String::new(),
+                inserted: 0,
+                message: Some(e),
+            }];
+        }
+    };
+
+    let mut pairs: Vec<(String, String)> = Vec::new();
+    if let Some(prev_day) = neighbors.0 {
+        pairs.push((inspect_day.to_string(), prev_day));
+    }
+    if let Some(next_day) = neighbors.1 {
+        pairs.push((next_day, inspect_day.to_string()));
+    }
+
+    replace_pairs(pool, pairs).await
+}
+
+/// 하루 또는 기간 내 조사일의 가격 변동을 다시 생성한다.
+/// 기간 내 조사일마다 직전 조사일(기간 시작 이전 포함)과 비교하며,
+/// 모든 조사일 쌍을 하나의 트랜잭션으로 처리하여 실패 시 기존 데이터를 유지한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `from`        - 시작 조사일(YYYYMMDD)
+/// * `to`          - 종료 조사일(YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기 (조사일 쌍 단위로 누적)
+/// 
+/// # Returns
+/// * `Ok(PriceChangeRecomputeRes)` - 조사일 쌍별 재계산 결과
+/// * `Err(String)`                 - 잘못된 기간 또는 재생성 실패 (전체 롤백)
+pub async fn recompute_price_change(
+    pool: &PgPool,
+    from: &str,
+    to: &str,
+    progress: &SyncProgress,
+) -> Result<PriceChangeRecomputeRes, String> {
+    let from_date = NaiveDate::parse_from_str(from, "%Y%m%d")
+        .map_err(|e| format!("조사일 형식 오류 ({}): {}", from, e))?;
+    let to_date = NaiveDate::parse_from_str(to, "%Y%m%d")
+        .map_err(|e| format!("조사일 형식 오류 ({}): {}", to, e))?;
+
+    if from_date > to_date {
+        return Err(format!("시작일({})이 종료일({})보다 늦음", from, to));
+    }
+    if (to_date - from_date).num_days() >= RECOMPUTE_MAX_DAYS {
+        return Err(format!("재계산 기간은 최대 {}일까지 가능", RECOMPUTE_MAX_DAYS));
+    }
+
+    let mut days = find_price_days_between(pool, from, to).await?;
+    if let Some(prev_day) = find_prev_day(pool, from).await? {
+        days.insert(0, prev_day);
+    }
+
+    let pairs: Vec<(String, String)> = days
+        .windows(2)
+        .map(|w| (w[1].clone(), w[0].clone()))
+        .collect();
+
+    let counts = replace_price_change(pool, &pairs).await?;
+
+    let pairs: Vec<PriceChangePairRes> = pairs
+        .into_iter()
+        .zip(counts)
+        .map(|((inspect_day, prev_day), inserted)| {
+            progress.add_success(1);
+            PriceChangePairRes {
+                inspect_day,
+                prev_day,
+                inserted,
+                message: None,
+            }
+        })
+        .collect();
+
+    tracing::info!("가격 변동 재계산 완료: {} ~ {} (조사일 쌍 {}개)", from, to, pairs.len());
+
+    Ok(PriceChangeRecomputeRes {
+        from: from.to_string(),
+        to: to.to_string(),
+        pairs,
+    })
+}
+
+/// 조사일 쌍 목록의 가격 변동을 재생성하고 쌍별 결과로 변환한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `pairs`   - (기준일, 비교 대상 이전 조사일) 목록
+/// 
+/// # Returns
+/// * `Vec<PriceChangePairRes>` - 조사일 쌍별 결과 (실패 시 모든 쌍에 message 포함)
+async fn replace_pairs(
+    pool: &PgPool,
+    pairs: Vec<(String, String)>,
+) -> Vec<PriceChangePairRes> {
+    match replace_price_change(pool, &pairs).await {
+        Ok(counts) => pairs
+            .into_iter()
+            .zip(counts)
+            .map(|((inspect_day, prev_day), inserted)| PriceChangePairRes {
+                inspect_day,
+                prev_day,
+                inserted,
+                message: None,
+            })
+            .collect(),
+        Err(e) => {
+            tracing::warn!("가격 변동 생성 실패: {}", e);
+            pairs
+                .into_iter()
+                .map(|(inspect_day, prev_day)| PriceChangePairRes {
+                    inspect_day,
+                    prev_day,
+                    inserted: 0,
+                    message: Some(e.clone()),
+                })
+                .collect()
+        }
+    }
+}
+
 /// 가격 변동 정보를 조회한다.
 /// 
 /// # Arguments
@@ -0,0 +1,18 @@
+use serde::Deserialize;
+
+/// 매장 추천 조회 요청 DTO
+/// 
+/// # Fields
+/// * `good_id`     - 추천 기준 상품 ID
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 km (없으면 전체 매장, 최대: 50)
+/// * `limit`       - 최대 조회 수 (기본값: 100, 최대: 1000)
+#[derive(Deserialize)]
+pub struct RecommendationReq {
+    pub good_id: String,
+    pub lat: f64,
+    pub lon: f64,
+    pub radius_km: Option<f64>,
+    pub limit: Option<i64>,
+}
@@ -0,0 +1,47 @@
+use serde::Serialize;
+
+/// 매장 추천 결과 응답 DTO
+/// 
+/// # Fields
+/// * `good_id`         - 추천 기준 상품 ID
+/// * `w_price`         - 적용된 가격 가중치
+/// * `w_distance`      - 적용된 거리 가중치
+/// * `personalized`    - 사용자 가중치 적용 여부 (false면 기본 가중치)
+/// * `stores`          - 점수가 높은 순으로 정렬된 추천 매장 목록
+#[derive(Serialize)]
+pub struct RecommendationRes {
+    pub good_id: String,
+    pub w_price: f64,
+    pub w_distance: f64,
+    pub personalized: bool,
+    pub stores: Vec<RecommendedStoreRes>,
+}
+
+/// 추천 매장 응답 DTO
+/// 
+/// # Fields
+/// * `rank`            - 순위 (1부터 시작)
+/// * `store_id`        - 매장 ID
+/// * `store_name`      - 매장명
+/// * `x_coord`         - 위도 값
+/// * `y_coord`         - 경도 값
+/// * `price`           - 최신 가격
+/// * `inspect_day`     - 가격 조사일 (YYYYMMDD)
+/// * `distance_km`     - 기준 좌표로부터의 대원 거리 (km)
+/// * `price_score`     - 가격 점수 (가격 가중치 × 가격 효율 × 100)
+/// * `distance_score`  - 거리 점수 (거리 가중치 × 거리 효율 × 100)
+/// * `score`           - 종합 점수 (가격 점수 + 거리 점수)
+#[derive(Serialize)]
+pub struct RecommendedStoreRes {
+    pub rank: usize,
+    pub store_id: String,
+    pub store_name: String,
+    pub x_coord: f64,
+    pub y_coord: f64,
+    pub price: i32,
+    pub inspect_day: String,
+    pub distance_km: f64,
+    pub price_score: f64,
+    pub distance_score: f64,
+    pub score: f64,
+}
@@ -0,0 +1,84 @@
+/************************************************************************
+ * File: domain/recommendation/handler.rs
+ * Description:
+ *     매장 추천(recommendation) 도메인의 라우팅 계층으로,
+ *     클라이언트 요청을 받아 서비스 계층으로 전달하고
+ *     그 결과를 HTTP 응답 형태로 반환한다.
+ *
+ * Responsibilities:
+ *     1) recommendations_get_handler()
+ *         - 상품 기준 사용자 맞춤 매장 추천 순위 조회 처리
+************************************************************************/
+
+use axum::{
+    extract::{Query, State},
+    http::{status::StatusCode, HeaderMap},
+    response::IntoResponse,
+    Json,
+};
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::{
+    common::token::extract_token::extract_token,
+    domain::recommendation::{dto::dto_req::RecommendationReq, service},
+};
+
+/// 상품의 매장별 최신 가격과 거리를 사용자 가중치로 점수화하여 추천 순위를 조회한다.
+/// Authorization 헤더가 있으면 해당 사용자의 가중치를, 없으면 기본 가중치를 사용한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `headers` - Authorization 헤더 (선택)
+/// * `req`     - RecommendationReq { good_id, lat, lon, radius_km, limit }
+///
+/// # Returns
+/// * `200 Ok`                      - 추천 매장 목록 및 점수 구성 반환
+/// * `400 BAD_REQUEST`             - 잘못된 좌표 또는 반경
+/// * `401 UNAUTHORIZED`            - Authorization 헤더 형식 오류 / 토큰 검증 실패
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn recommendations_get_handler(
+    State(pool): State<PgPool>,
+    headers: HeaderMap,
+    Query(req): Query<RecommendationReq>,
+) -> impl IntoResponse {
+    // 1) 파라미터 검증
+    let (radius_km, limit) =
+        match service::validate_recommendation_params(req.lat, req.lon, req.radius_km, req.limit) {
+            Ok(params) => params,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "message": e })).into_response(),
+                )
+            }
+        };
+
+    // 2) 토큰이 있으면 사용자 확인 (없으면 비로그인 사용자)
+    let token = if headers.contains_key("Authorization") {
+        match extract_token(&headers) {
+            Ok(t) => Some(t),
+            Err((status, resp)) => return (status, resp),
+        }
+    } else {
+        None
+    };
+    let user_id = match service::resolve_user_id(token) {
+        Ok(id) => id,
+        Err(e) => {
+            return (
+                StatusCode::UNAUTHORIZED,
+                Json(json!({ "message": e })).into_response(),
+            )
+        }
+    };
+
+    // 3) 서비스 호출
+    match service::get_recommendations(&pool, user_id, &req.good_id, req.lat, req.lon, radius_km, limit).await {
+        Ok(res) => (StatusCode::OK, Json(res).into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
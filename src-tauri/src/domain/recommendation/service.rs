@@ -0,0 +1,252 @@
+/************************************************************************
+ * File: domain/recommendation/service.rs
+ * Description:
+ *     매장 추천(recommendation) 도메인의 서비스 로직을 처리한다.
+ *     가격 / 거리 점수 계산을 서버에서 수행하여 웹 / 데스크톱 / 안드로이드
+ *     클라이언트가 같은 순위를 받도록 한다.
+ *
+ * Responsibilities:
+ *     1) resolve_user_id()
+ *         - JWT가 있으면 user_id 추출 (없으면 비로그인 사용자)
+ *
+ *     2) validate_recommendation_params()
+ *         - 기준 좌표 / 반경 검증 및 조회 수 보정
+ *
+ *     3) get_recommendations()
+ *         - 상품의 매장별 최신 가격과 거리를 정규화하고
+ *           사용자 가중치(없으면 기본 가중치)로 점수를 계산하여 순위 반환
+************************************************************************/
+
+use sqlx::PgPool;
+
+use crate::{
+    common::repository::{
+        repository_join::find_latest_prices_with_distance,
+        repository_user_preference::find_preference_by_user_id,
+    },
+    domain::{
+        auth::service::decode_jwt,
+        recommendation::dto::dto_res::{RecommendationRes, RecommendedStoreRes},
+        store::service::NEARBY_MAX_RADIUS_KM,
+    },
+};
+
+/// 비로그인 사용자 / 가중치가 없는 사용자에게 적용하는 가격 가중치
+pub const DEFAULT_W_PRICE: f64 = 0.5;
+/// 비로그인 사용자 / 가중치가 없는 사용자에게 적용하는 거리 가중치
+pub const DEFAULT_W_DISTANCE: f64 = 0.5;
+/// 추천 매장 기본 조회 수
+pub const RECOMMENDATION_DEFAULT_LIMIT: i64 = 100;
+/// 추천 매장 최대 조회 수
+pub const RECOMMENDATION_MAX_LIMIT: i64 = 1000;
+
+/// JWT가 전달되었으면 user_id를 추출한다.
+/// 
+/// # Arguments
+/// * `token` - JWT 문자열 (없으면 비로그인 사용자)
+/// 
+/// # Returns
+/// * `Ok(Option<i32>)` - user_id (비로그인 사용자면 None)
+/// * `Err(String)`     - 토큰 검증 / user_id 파싱 실패
+pub fn resolve_user_id(
+    token: Option<&str>
+) -> Result<Option<i32>, String> {
+    let Some(token) = token else {
+        return Ok(None);
+    };
+
+    let claims = decode_jwt(token)?;
+    let user_id: i32 = claims
+        .sub
+        .parse::<i32>()
+        .map_err(|_| "user_id 파싱 실패 (JWT sub이 숫자가 아님)".to_string())?;
+
+    Ok(Some(user_id))
+}
+
+/// 추천 기준 좌표 / 반경을 검증하고, 최대값을 적용한 반경과 조회 수를 반환한다.
+/// 
+/// # Arguments
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (없으면 전체)
+/// * `limit`       - 최대 조회 수 (없으면 기본값)
+/// 
+/// # Returns
+/// * `Ok((Option<f64>, i64))`  - (적용할 반경, 적용할 조회 수)
+/// * `Err(String)`             - 잘못된 좌표 또는 반경
+pub fn validate_recommendation_params(
+    lat: f64,
+    lon: f64,
+    radius_km: Option<f64>,
+    limit: Option<i64>,
+) -> Result<(Option<f64>, i64), String> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("위도는 -90 ~ 90 사이여야 합니다: {}", lat));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(format!("경도는 -180 ~ 180 사이여야 합니다: {}", lon));
+    }
+    if let Some(r) = radius_km {
+        if !r.is_finite() || r <= 0.0 {
+            return Err(format!("반경은 0보다 커야 합니다: {}", r));
+        }
+    }
+
+    let limit = limit
+        .unwrap_or(RECOMMENDATION_DEFAULT_LIMIT)
+        .clamp(1, RECOMMENDATION_MAX_LIMIT);
+
+    Ok((radius_km.map(|r| r.min(NEARBY_MAX_RADIUS_KM)), limit))
+}
+
+/// 상품의 매장별 최신 가격과 기준 좌표로부터의 거리를 이용해 매장 추천 순위를 계산한다.
+/// 
+/// 가격 / 거리는 후보 매장 중 최대값으로 나누어 0~1로 정규화한 뒤 반전(1 - 비율)하여
+/// 낮을수록 높은 효율로 보고, 가중치를 곱해 0~100 범위의 점수로 환산한다.
+/// 로그인 사용자는 학습된 가중치(user_preferences)를, 비로그인 사용자나
+/// 가중치가 없는 사용자는 기본 가중치(0.5 / 0.5)를 사용한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `user_id`     - 사용자 ID (비로그인 사용자면 None)
+/// * `good_id`     - 추천 기준 상품 ID
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (km, 검증 완료 값)
+/// * `limit`       - 최대 조회 수 (검증 완료 값)
+/// 
+/// # Returns
+/// * `Ok(RecommendationRes)`   - 적용 가중치 및 점수순 추천 매장 목록
+/// * `Err(String)`             - 조회 실패
+pub async fn get_recommendations(
+    pool: &PgPool,
+    user_id: Option<i32>,
+    good_id: &str,
+    lat: f64,
+    lon: f64,
+    radius_km: Option<f64>,
+    limit: i64,
+) -> Result<RecommendationRes, String> {
+    // 1) 가중치 결정
+    let preference = match user_id {
+        Some(user_id) => match find_preference_by_user_id(pool, user_id).await {
+            Ok(pref) => Some(pref),
+            Err(e) => {
+                tracing::warn!("사용자 {} 가중치 조회 실패, 기본 가중치 사용: {}", user_id, e);
+                None
+            }
+        },
+        None => None,
+    };
+    let personalized = preference.is_some();
+    let (w_price, w_distance) = preference
+        .map(|p| (p.w_price, p.w_distance))
+        .unwrap_or((DEFAULT_W_PRICE, DEFAULT_W_DISTANCE));
+
+    // 2) 후보 매장 조회
+    let candidates = find_latest_prices_with_distance(pool, good_id, lat, lon, radius_km).await?;
+
+    // 3) 정규화 및 점수 계산
+    let max_price = candidates.iter().map(|c| c.price).max().unwrap_or(0) as f64;
+    let max_distance = candidates
+        .iter()
+        .map(|c| c.distance_km)
+        .fold(0.0_f64, f64::max);
+
+    let mut stores: Vec<RecommendedStoreRes> = candidates
+        .into_iter()
+        .map(|c| {
+            let price_eff = 1.0 - ratio(c.price as f64, max_price);
+            let distance_eff = 1.0 - ratio(c.distance_km, max_distance);
+            let price_score = w_price * price_eff * 100.0;
+            let distance_score = w_distance * distance_eff * 100.0;
+
+            RecommendedStoreRes {
+                rank: 0,
+                store_id: c.store_id,
+                store_name: c.store_name,
+                x_coord: c.x_coord,
+                y_coord: c.y_coord,
+                price: c.price,
+                inspect_day: c.inspect_day,
+                distance_km: c.distance_km,
+                price_score,
+                distance_score,
+                score: price_score + distance_score,
+            }
+        })
+        .collect();
+
+    // 점수가 같으면 가까운 매장, 거리도 같으면 매장 ID 순
+    stores.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| a.distance_km.total_cmp(&b.distance_km))
+            .then_with(|| a.store_id.cmp(&b.store_id))
+    });
+    stores.truncate(limit as usize);
+    for (idx, store) in stores.iter_mut().enumerate() {
+        store.rank = idx + 1;
+    }
+
+    Ok(RecommendationRes {
+        good_id: good_id.to_string(),
+        w_price,
+        w_distance,
+        personalized,
+        stores,
+    })
+}
+
+/// 값을 최대값으로 나눈 비율(0~1)을 반환한다. 최대값이 0이면 0으로 본다.
+fn ratio(
+    value: f64,
+    max: f64
+) -> f64 {
+    if max > 0.0 {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ratio_normalizes_against_max() {
+        assert_eq!(ratio(50.0, 200.0), 0.25);
+        assert_eq!(ratio(200.0, 200.0), 1.0);
+        assert_eq!(ratio(0.0, 200.0), 0.0);
+    }
+
+    #[test]
+    fn ratio_handles_zero_max_and_out_of_range_values() {
+        // 후보가 없거나 모든 값이 0이면 0으로 본다.
+        assert_eq!(ratio(0.0, 0.0), 0.0);
+        assert_eq!(ratio(5.0, 0.0), 0.0);
+        assert_eq!(ratio(5.0, -1.0), 0.0);
+        assert_eq!(ratio(300.0, 200.0), 1.0);
+        assert_eq!(ratio(-10.0, 200.0), 0.0);
+    }
+
+    #[test]
+    fn recommendation_params_are_validated_and_clamped() {
+        assert_eq!(
+            validate_recommendation_params(37.5, 127.0, None, None),
+            Ok((None, RECOMMENDATION_DEFAULT_LIMIT))
+        );
+        assert_eq!(
+            validate_recommendation_params(37.5, 127.0, Some(1e6), Some(1e6 as i64)),
+            Ok((Some(NEARBY_MAX_RADIUS_KM), RECOMMENDATION_MAX_LIMIT))
+        );
+        assert_eq!(validate_recommendation_params(37.5, 127.0, Some(3.0), Some(0)), Ok((Some(3.0), 1)));
+
+        assert!(validate_recommendation_params(91.0, 127.0, None, None).is_err());
+        assert!(validate_recommendation_params(37.5, 181.0, None, None).is_err());
+        assert!(validate_recommendation_params(37.5, 127.0, Some(0.0), None).is_err());
+        assert!(validate_recommendation_params(37.5, 127.0, Some(f64::NAN), None).is_err());
+    }
+}
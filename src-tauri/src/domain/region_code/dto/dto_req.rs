@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+/// 하위 지역 목록 조회 요청 DTO
+/// 
+/// # Fields
+/// * `code` - 상위 지역 코드 (없으면 최상위 지역 목록)
+#[derive(Deserialize)]
+pub struct RegionChildrenReq {
+    pub code: Option<String>,
+}
+
+/// 상위 지역 목록 조회 요청 DTO
+/// 
+/// # Fields
+/// * `code` - 기준 지역 코드
+#[derive(Deserialize)]
+pub struct RegionAncestorsReq {
+    pub code: String,
+}
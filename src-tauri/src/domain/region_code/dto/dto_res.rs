@@ -13,4 +13,19 @@ pub struct RegionCodeRes {
     pub name: String,
     pub parent_code: Option<String>,
     pub level: i16,
+}
+
+/// 지역 트리 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `code`        - 행정구역 코드
+/// * `name`        - 행정구역 명칭
+/// * `level`       - 행정구역 단계
+/// * `children`    - 하위 행정구역 목록
+#[derive(Serialize)]
+pub struct RegionTreeRes {
+    pub code: String,
+    pub name: String,
+    pub level: i16,
+    pub children: Vec<RegionTreeRes>,
 }
\ No newline at end of file
@@ -1 +1,2 @@
-pub mod dto_res;
+pub mod dto_req;
+pub mod dto_res;
\ No newline at end of file
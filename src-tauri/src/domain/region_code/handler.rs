@@ -8,14 +8,30 @@
  * Responsibilities:
  *     1) region_codes_list_handler()
  *         - 전체 지역 코드 목록 조회 처리
+ *
+ *     2) region_codes_tree_handler()
+ *         - 지역 트리 조회 처리
+ *
+ *     3) region_codes_children_handler()
+ *         - 하위 지역 목록 조회 처리
+ *
+ *     4) region_codes_ancestors_handler()
+ *         - 상위 지역 목록 조회 처리
 ************************************************************************/
 
-use axum::{extract::State, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
 use reqwest::StatusCode;
 use serde_json::json;
 use sqlx::PgPool;
 
-use crate::domain::region_code::service;
+use crate::domain::region_code::{
+    dto::dto_req::{RegionAncestorsReq, RegionChildrenReq},
+    service,
+};
 
 /// 전체 지역 코드(region_code) 목록을 조회하는 핸들러이다.
 ///
@@ -36,3 +52,68 @@ pub async fn region_codes_list_handler(
         ),
     }
 }
+
+/// 전체 지역 코드를 트리 형태로 조회하는 핸들러이다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+///
+/// # Returns
+/// * `200 Ok`                      - 지역 트리 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn region_codes_tree_handler(
+    State(pool): State<PgPool>
+) -> impl IntoResponse {
+    match service::get_region_tree(&pool).await {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
+
+/// 특정 지역의 바로 아래 하위 지역 목록을 조회하는 핸들러이다.
+/// code가 없으면 최상위 지역 목록을 반환한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - RegionChildrenReq { code }
+///
+/// # Returns
+/// * `200 Ok`                      - 하위 지역 목록 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn region_codes_children_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<RegionChildrenReq>,
+) -> impl IntoResponse {
+    match service::get_region_children(&pool, req.code.as_deref()).await {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
+
+/// 특정 지역의 상위 지역 목록을 최상위 지역부터 조회하는 핸들러이다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - RegionAncestorsReq { code }
+///
+/// # Returns
+/// * `200 Ok`                      - 상위 지역 목록 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn region_codes_ancestors_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<RegionAncestorsReq>,
+) -> impl IntoResponse {
+    match service::get_region_ancestors(&pool, &req.code).await {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
@@ -1,17 +1,29 @@
 /************************************************************************
  * File: domain/region_code/service.rs
  * Description:
- *     상품(good) 도메인의 서비스 로직을 처리한다.
+ *     지역 코드(region_code) 도메인의 서비스 로직을 처리한다.
  *
  * Responsibilities:
- *     1) get_all_goods()
- *         - goods 테이블 전체를 조회하여 반환
+ *     1) get_all_region_codes()
+ *         - regions 테이블 전체를 조회하여 반환
+ *
+ *     2) get_region_tree()
+ *         - 상위 지역 코드 연결로 지역 트리를 구성하여 반환 (단계 제한 없음)
+ *
+ *     3) get_region_children()
+ *         - 특정 지역의 바로 아래 하위 지역 목록 반환
+ *
+ *     4) get_region_ancestors()
+ *         - 특정 지역의 상위 지역 목록을 최상위 지역부터 반환
 ************************************************************************/
+use std::collections::{HashMap, HashSet};
+
 use axum::Json;
 use sqlx::PgPool;
 
 use crate::{
-    common::repository::repository_region, domain::region_code::dto::dto_res::RegionCodeRes,
+    common::{entity::entity_region::RegionEntity, repository::repository_region},
+    domain::region_code::dto::dto_res::{RegionCodeRes, RegionTreeRes},
 };
 
 /// 전체 지역 코드 목록을 조회하여 클라이언트로 반환한다
@@ -25,11 +37,88 @@ use crate::{
 pub async fn get_all_region_codes(
     pool: &PgPool
 ) -> Result<Json<Vec<RegionCodeRes>>, String> {
-    let stores = repository_region::get_all_region_codes(pool)
+    let regions = repository_region::get_all_region_codes(pool)
         .await
         .map_err(|e| format!("DB 조회 실패: {}", e))?;
 
-    let result = stores
+    Ok(Json(to_region_code_res(regions)))
+}
+
+/// 전체 지역 코드를 트리 형태로 조회하여 반환한다.
+/// 상위 지역이 없거나 저장되지 않은 지역을 최상위 지역으로 본다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<RegionTreeRes>>)` - 최상위 지역부터 구성된 지역 트리
+/// * `Err(String)`                  - 조회 실패
+pub async fn get_region_tree(
+    pool: &PgPool
+) -> Result<Json<Vec<RegionTreeRes>>, String> {
+    let regions = repository_region::get_all_region_codes(pool)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    let known: HashSet<String> = regions.iter().map(|r| r.code.clone()).collect();
+    let mut children: HashMap<Option<String>, Vec<RegionEntity>> = HashMap::new();
+    for region in regions {
+        let parent = region
+            .parent_code
+            .clone()
+            .filter(|p| *p != region.code && known.contains(p));
+        children.entry(parent).or_default().push(region);
+    }
+
+    let mut visited = HashSet::new();
+    Ok(Json(build_tree(None, &mut children, &mut visited)))
+}
+
+/// 특정 지역의 바로 아래 하위 지역 목록을 조회한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// * `code` - 상위 지역 코드 (None이면 최상위 지역 목록)
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<RegionCodeRes>>)` - 하위 지역 목록
+/// * `Err(String)`                  - 조회 실패
+pub async fn get_region_children(
+    pool: &PgPool,
+    code: Option<&str>,
+) -> Result<Json<Vec<RegionCodeRes>>, String> {
+    let regions = repository_region::find_region_children(pool, code)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    Ok(Json(to_region_code_res(regions)))
+}
+
+/// 특정 지역의 상위 지역 목록을 최상위 지역부터 조회한다. (자기 자신 제외)
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// * `code` - 기준 지역 코드
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<RegionCodeRes>>)` - 상위 지역 목록
+/// * `Err(String)`                  - 조회 실패
+pub async fn get_region_ancestors(
+    pool: &PgPool,
+    code: &str,
+) -> Result<Json<Vec<RegionCodeRes>>, String> {
+    let regions = repository_region::find_region_ancestors(pool, code)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    Ok(Json(to_region_code_res(regions)))
+}
+
+/// 지역 엔티티 목록을 응답 DTO로 변환한다.
+fn to_region_code_res(
+    regions: Vec<RegionEntity>
+) -> Vec<RegionCodeRes> {
+    regions
         .into_iter()
         .map(|r| RegionCodeRes {
             code: r.code,
@@ -37,7 +126,30 @@ pub async fn get_all_region_codes(
             parent_code: r.parent_code,
             level: r.level,
         })
-        .collect::<Vec<RegionCodeRes>>();
+        .collect()
+}
+
+/// 상위 코드별로 묶인 지역 목록에서 주어진 상위 지역 아래의 트리를 재귀적으로 구성한다.
+/// 순환 참조로 이미 방문한 지역은 다시 펼치지 않는다.
+fn build_tree(
+    parent: Option<String>,
+    children: &mut HashMap<Option<String>, Vec<RegionEntity>>,
+    visited: &mut HashSet<String>,
+) -> Vec<RegionTreeRes> {
+    let mut nodes = children.remove(&parent).unwrap_or_default();
+    nodes.sort_by(|a, b| a.code.cmp(&b.code));
 
-    Ok(Json(result))
+    let mut tree = Vec::with_capacity(nodes.len());
+    for r in nodes {
+        if !visited.insert(r.code.clone()) {
+            continue;
+        }
+        tree.push(RegionTreeRes {
+            children: build_tree(Some(r.code.clone()), children, visited),
+            code: r.code,
+            name: r.name,
+            level: r.level,
+        });
+    }
+    tree
 }
@@ -0,0 +1,12 @@
+use serde::Deserialize;
+
+use crate::domain::sync::dto::dto_req::StandardCodeClass;
+
+/// 표준코드 목록 조회 요청 DTO
+/// 
+/// # Fields
+/// * `class` - 표준코드 분류 (region, good_category, unit, store_type)
+#[derive(Deserialize)]
+pub struct StandardCodeListReq {
+    pub class: StandardCodeClass,
+}
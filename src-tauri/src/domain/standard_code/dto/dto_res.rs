@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+/// 표준코드 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `code`        - 코드
+/// * `name`        - 코드명
+/// * `parent_code` - 상위 코드
+#[derive(Serialize)]
+pub struct StandardCodeRes {
+    pub code: String,
+    pub name: String,
+    pub parent_code: Option<String>,
+}
@@ -0,0 +1,45 @@
+/************************************************************************
+ * File: domain/standard_code/handler.rs
+ * Description:
+ *     표준코드(standard_code) 도메인의 라우팅 계층으로,
+ *     클라이언트 요청을 받아 서비스 계층으로 전달하고
+ *     그 결과를 HTTP 응답 형태로 반환한다.
+ *
+ * Responsibilities:
+ *     1) standard_codes_list_handler()
+ *         - 분류별 표준코드 목록 조회 처리
+************************************************************************/
+
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use reqwest::StatusCode;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::domain::standard_code::{dto::dto_req::StandardCodeListReq, service};
+
+/// 분류별 표준코드 목록을 조회하는 핸들러이다.
+/// 상품분류 / 단위 / 업태 코드로 상품 / 매장을 필터링하거나 이름을 표시할 때 사용한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - StandardCodeListReq { class }
+///
+/// # Returns
+/// * `200 Ok`                      - 표준코드 목록 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn standard_codes_list_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<StandardCodeListReq>,
+) -> impl IntoResponse {
+    match service::get_standard_codes(&pool, req.class).await {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
@@ -0,0 +1,66 @@
+/************************************************************************
+ * File: domain/standard_code/service.rs
+ * Description:
+ *     표준코드(standard_code) 도메인의 서비스 로직을 처리한다.
+ *
+ * Responsibilities:
+ *     1) get_standard_codes()
+ *         - 분류별 표준코드 목록을 조회하여 반환
+ *
+ *     2) load_code_names()
+ *         - 상품 / 매장 응답에 코드명을 붙이기 위한 코드 → 코드명 맵 생성
+************************************************************************/
+
+use axum::Json;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use crate::{
+    common::repository::repository_standard_code::find_standard_codes,
+    domain::{standard_code::dto::dto_res::StandardCodeRes, sync::dto::dto_req::StandardCodeClass},
+};
+
+/// 분류별 표준코드 목록을 조회하여 클라이언트로 반환한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `class`   - 표준코드 분류
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<StandardCodeRes>>)`  - 표준코드 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn get_standard_codes(
+    pool: &PgPool,
+    class: StandardCodeClass,
+) -> Result<Json<Vec<StandardCodeRes>>, String> {
+    let codes = find_standard_codes(pool, class).await?;
+
+    let result = codes
+        .into_iter()
+        .map(|c| StandardCodeRes {
+            code: c.code,
+            name: c.name,
+            parent_code: c.parent_code,
+        })
+        .collect::<Vec<StandardCodeRes>>();
+
+    Ok(Json(result))
+}
+
+/// 분류별 표준코드를 코드 → 코드명 맵으로 불러온다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `class`   - 표준코드 분류
+/// 
+/// # Returns
+/// * `Ok(HashMap<String, String>)` - 코드 → 코드명
+/// * `Err(String)`                 - 조회 실패
+pub async fn load_code_names(
+    pool: &PgPool,
+    class: StandardCodeClass,
+) -> Result<HashMap<String, String>, String> {
+    let codes = find_standard_codes(pool, class).await?;
+
+    Ok(codes.into_iter().map(|c| (c.code, c.name)).collect())
+}
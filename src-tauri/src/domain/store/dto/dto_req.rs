@@ -7,4 +7,43 @@ use serde::Deserialize;
 #[derive(Deserialize)]
 pub struct GoodIdReq {
     pub good_id: i32,
+}
+
+/// 전체 매장 조회 요청 DTO
+/// 
+/// # Fields
+/// * `include_inactive`    - 업스트림 목록에서 사라진 비활성 매장 포함 여부 (기본값: false)
+/// * `store_type_code`     - 업태 코드 필터 (없으면 전체)
+/// * `region_code`         - 지역 코드 필터 (없으면 전체, 모든 하위 지역 포함)
+#[derive(Deserialize)]
+pub struct StoreListReq {
+    pub include_inactive: Option<bool>,
+    pub store_type_code: Option<String>,
+    pub region_code: Option<String>,
+}
+
+/// 근처 매장 조회 요청 DTO
+/// 
+/// # Fields
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 km (기본값: 3, 최대: 50)
+/// * `limit`       - 최대 조회 수 (기본값: 100, 최대: 1000)
+#[derive(Deserialize)]
+pub struct StoreNearbyReq {
+    pub lat: f64,
+    pub lon: f64,
+    pub radius_km: Option<f64>,
+    pub limit: Option<i64>,
+}
+
+/// 매장 변경 이력 조회 요청 DTO
+/// 
+/// # Fields
+/// * `store_id`  - 조회할 매장 ID
+/// * `limit`     - 최대 조회 수 (기본값: 100, 최대: 1000)
+#[derive(Deserialize)]
+pub struct StoreHistoryReq {
+    pub store_id: String,
+    pub limit: Option<i64>,
 }
\ No newline at end of file
@@ -16,6 +16,10 @@ use serde::Serialize;
 /// * `updated_at`          - 수정 시각
 /// * `area_code`           - 행정구역 코드
 /// * `area_detail_code`    - 세부 행정구역 코드
+/// * `is_active`           - 최신 업스트림 목록 포함 여부
+/// * `last_seen_at`        - 업스트림 목록에서 마지막으로 확인된 시각
+/// * `store_type_code`     - 업태 코드
+/// * `store_type_name`     - 업태명
 #[derive(Serialize)]
 pub struct StoreRes {
     pub id: i32,
@@ -31,4 +35,39 @@ pub struct StoreRes {
     pub updated_at: String,
     pub area_code: String,
     pub area_detail_code: String,
+    pub is_active: bool,
+    pub last_seen_at: String,
+    pub store_type_code: Option<String>,
+    pub store_type_name: Option<String>,
+}
+
+/// 근처 매장 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `store`       - 매장 정보 (StoreRes 필드를 그대로 펼쳐서 반환)
+/// * `distance_km` - 기준 좌표로부터의 대원 거리 (km)
+#[derive(Serialize)]
+pub struct StoreNearbyRes {
+    #[serde(flatten)]
+    pub store: StoreRes,
+    pub distance_km: f64,
+}
+
+/// 매장 변경 이력 조회 시 반환되는 응답 DTO
+/// 
+/// # Fields
+/// * `id`          - 변경 이력 ID
+/// * `run_id`      - 변경을 일으킨 동기화 실행 기록 ID
+/// * `field`       - 변경된 필드명
+/// * `old_value`   - 변경 전 값
+/// * `new_value`   - 변경 후 값
+/// * `changed_at`  - 변경 시각
+#[derive(Serialize)]
+pub struct StoreHistoryRes {
+    pub id: i32,
+    pub run_id: Option<i32>,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: String,
 }
\ No newline at end of file
@@ -8,26 +8,109 @@
  * Responsibilities:
  *     1) auth_google_handler()
  *         - 전체 상품 목록 조회 처리
+ *
+ *     2) stores_history_handler()
+ *         - 매장 변경 이력 조회 처리
+ *
+ *     3) stores_nearby_handler()
+ *         - 기준 좌표 반경 내 매장을 거리순으로 조회 처리
 ************************************************************************/
 
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 use serde_json::json;
 use sqlx::PgPool;
 
-use crate::domain::store::service;
+use crate::domain::store::{
+    dto::dto_req::{StoreHistoryReq, StoreListReq, StoreNearbyReq},
+    service,
+};
 
 /// 전체 매장(stores) 목록을 조회하는 핸들러이다.
+/// 비활성 매장은 `include_inactive=true`일 때만 포함하며,
+/// `store_type_code`를 지정하면 해당 업태의 매장만 반환한다.
+/// `region_code`를 지정하면 해당 지역과 모든 하위 지역(area_code / area_detail_code 기준)의 매장만 반환한다.
 ///
 /// # Arguments
 /// * `pool`    - DB 연결 풀
+/// * `req`     - StoreListReq { include_inactive, store_type_code, region_code }
 ///
 /// # Returns
 /// * `200 Ok`                      - 전체 매장 목록 반환
 /// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
 pub async fn stores_list_handler(
-    State(pool): State<PgPool>
+    State(pool): State<PgPool>,
+    Query(req): Query<StoreListReq>,
+) -> impl IntoResponse {
+    match service::get_all_stores(
+        &pool,
+        req.include_inactive.unwrap_or(false),
+        req.store_type_code.as_deref(),
+        req.region_code.as_deref(),
+    )
+    .await
+    {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
+
+/// 특정 매장의 변경 이력을 조회하는 핸들러이다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - StoreHistoryReq { store_id, limit }
+///
+/// # Returns
+/// * `200 Ok`                      - 변경 이력 목록 반환
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn stores_history_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<StoreHistoryReq>,
 ) -> impl IntoResponse {
-    match service::get_all_stores(&pool).await {
+    match service::get_store_history(&pool, &req.store_id, req.limit).await {
+        Ok(res) => (StatusCode::OK, res.into_response()),
+        Err(e) => (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(json!({ "message": e })).into_response(),
+        ),
+    }
+}
+
+/// 기준 좌표 반경 내 활성 매장을 가까운 순으로 조회하는 핸들러이다.
+/// 거리 계산 / 필터링 / 정렬은 DB에서 수행하며, 응답에 거리(km)를 포함한다.
+///
+/// # Arguments
+/// * `pool`    - DB 연결 풀
+/// * `req`     - StoreNearbyReq { lat, lon, radius_km, limit }
+///
+/// # Returns
+/// * `200 Ok`                      - 거리순 매장 목록 반환
+/// * `400 BAD_REQUEST`             - 잘못된 좌표 또는 반경
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn stores_nearby_handler(
+    State(pool): State<PgPool>,
+    Query(req): Query<StoreNearbyReq>,
+) -> impl IntoResponse {
+    let (radius_km, limit) =
+        match service::validate_nearby_params(req.lat, req.lon, req.radius_km, req.limit) {
+            Ok(params) => params,
+            Err(e) => {
+                return (
+                    StatusCode::BAD_REQUEST,
+                    Json(json!({ "message": e })).into_response(),
+                )
+            }
+        };
+
+    match service::get_nearby_stores(&pool, req.lat, req.lon, radius_km, limit).await {
         Ok(res) => (StatusCode::OK, res.into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -5,46 +5,205 @@
  *
  * Responsibilities:
  *     1) get_all_goods()
- *         - stores 테이블 전체를 조회하여 반환
+ *         - stores 테이블 전체를 조회하여 반환 (기본적으로 비활성 매장 제외)
+ *         - 업태 코드 필터 및 업태명 표시
+ *
+ *     2) get_store_history()
+ *         - 매장 기준 정보의 변경 이력을 최신순으로 반환
+ *
+ *     3) get_nearby_stores()
+ *         - 기준 좌표 반경 내 활성 매장을 거리순으로 반환 (거리 계산은 DB에서 수행)
 ************************************************************************/
+use std::collections::HashMap;
+
 use axum::Json;
 use sqlx::PgPool;
 
-use crate::{common::repository::repository_store, domain::store::dto::dto_res::StoreRes};
+use crate::{
+    common::{
+        entity::entity_store::StoreEntity,
+        repository::{
+            repository_store,
+            repository_master_history::{find_master_history, HISTORY_DEFAULT_LIMIT, HISTORY_MAX_LIMIT},
+        },
+    },
+    domain::{
+        standard_code::service::load_code_names,
+        store::dto::dto_res::{StoreHistoryRes, StoreNearbyRes, StoreRes},
+        sync::dto::dto_req::StandardCodeClass,
+    },
+};
 
 /// 전체 매장 (stores) 목록을 조회하여 클라이언트로 반환한다
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`                - DB 커넥션 풀
+/// * `include_inactive`    - 비활성 매장 포함 여부
+/// * `store_type_code`     - 업태 코드 필터
+/// * `region_code`         - 지역 코드 필터 (하위 지역 포함)
 /// 
 /// # Returns
 /// * `Ok(Json<Vec<GoodRes>)`   - 전체 매장 목록 
 /// * `Err(String)`             - 조회 실패
 pub async fn get_all_stores(
-    pool: &PgPool
+    pool: &PgPool,
+    include_inactive: bool,
+    store_type_code: Option<&str>,
+    region_code: Option<&str>,
 ) -> Result<Json<Vec<StoreRes>>, String> {
-    let stores = repository_store::get_all_stores(pool)
+    let stores = repository_store::get_all_stores(pool, include_inactive, store_type_code, region_code)
         .await
         .map_err(|e| format!("DB 조회 실패: {}", e))?;
+    let store_type_names = load_code_names(pool, StandardCodeClass::StoreType).await?;
 
     let result = stores
         .into_iter()
-        .map(|s| StoreRes {
-            id: s.id,
-            store_id: s.store_id,
-            store_name: s.store_name,
-            tel_no: s.tel_no,
-            post_no: s.post_no,
-            jibun_addr: s.jibun_addr,
-            road_addr: s.road_addr,
-            x_coord: s.x_coord,
-            y_coord: s.y_coord,
-            created_at: s.created_at.to_string(),
-            updated_at: s.updated_at.to_string(),
-            area_code: s.area_code,
-            area_detail_code: s.area_detail_code,
-        })
+        .map(|s| to_store_res(s, &store_type_names))
         .collect::<Vec<StoreRes>>();
 
     Ok(Json(result))
 }
+
+/// 근처 매장 조회 기본 반경 (km)
+pub const NEARBY_DEFAULT_RADIUS_KM: f64 = 3.0;
+/// 근처 매장 조회 최대 반경 (km)
+pub const NEARBY_MAX_RADIUS_KM: f64 = 50.0;
+/// 근처 매장 조회 기본 개수
+pub const NEARBY_DEFAULT_LIMIT: i64 = 100;
+/// 근처 매장 조회 최대 개수
+pub const NEARBY_MAX_LIMIT: i64 = 1000;
+
+/// 근처 매장 조회 기준 좌표 / 반경을 검증하고, 기본값 / 최대값을 적용한 반경과 조회 수를 반환한다.
+/// 
+/// # Arguments
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (없으면 기본값)
+/// * `limit`       - 최대 조회 수 (없으면 기본값)
+/// 
+/// # Returns
+/// * `Ok((f64, i64))`  - (적용할 반경, 적용할 조회 수)
+/// * `Err(String)`     - 잘못된 좌표 또는 반경
+pub fn validate_nearby_params(
+    lat: f64,
+    lon: f64,
+    radius_km: Option<f64>,
+    limit: Option<i64>,
+) -> Result<(f64, i64), String> {
+    if !(-90.0..=90.0).contains(&lat) {
+        return Err(format!("위도는 -90 ~ 90 사이여야 합니다: {}", lat));
+    }
+    if !(-180.0..=180.0).contains(&lon) {
+        return Err(format!("경도는 -180 ~ 180 사이여야 합니다: {}", lon));
+    }
+
+    let radius_km = radius_km.unwrap_or(NEARBY_DEFAULT_RADIUS_KM);
+    if !radius_km.is_finite() || radius_km <= 0.0 {
+        return Err(format!("반경은 0보다 커야 합니다: {}", radius_km));
+    }
+
+    let limit = limit
+        .unwrap_or(NEARBY_DEFAULT_LIMIT)
+        .clamp(1, NEARBY_MAX_LIMIT);
+
+    Ok((radius_km.min(NEARBY_MAX_RADIUS_KM), limit))
+}
+
+/// 기준 좌표에서 반경 내에 있는 활성 매장을 가까운 순으로 조회하여 반환한다.
+/// 거리 필터링과 정렬은 DB에서 수행하므로 클라이언트는 전체 매장을 내려받을 필요가 없다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `lat`         - 기준 위도
+/// * `lon`         - 기준 경도
+/// * `radius_km`   - 검색 반경 (km, 검증 완료 값)
+/// * `limit`       - 최대 조회 수 (검증 완료 값)
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<StoreNearbyRes>>)`   - 거리순 매장 목록 (거리 포함)
+/// * `Err(String)`                     - 조회 실패
+pub async fn get_nearby_stores(
+    pool: &PgPool,
+    lat: f64,
+    lon: f64,
+    radius_km: f64,
+    limit: i64,
+) -> Result<Json<Vec<StoreNearbyRes>>, String> {
+    let stores = repository_store::find_stores_nearby(pool, lat, lon, radius_km, limit)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+    let store_type_names = load_code_names(pool, StandardCodeClass::StoreType).await?;
+
+    let result = stores
+        .into_iter()
+        .map(|s| StoreNearbyRes {
+            store: to_store_res(s.store, &store_type_names),
+            distance_km: s.distance_km,
+        })
+        .collect::<Vec<StoreNearbyRes>>();
+
+    Ok(Json(result))
+}
+
+/// 매장 엔티티를 응답 DTO로 변환한다.
+fn to_store_res(
+    s: StoreEntity,
+    store_type_names: &HashMap<String, String>,
+) -> StoreRes {
+    StoreRes {
+        id: s.id,
+        store_id: s.store_id,
+        store_name: s.store_name,
+        tel_no: s.tel_no,
+        post_no: s.post_no,
+        jibun_addr: s.jibun_addr,
+        road_addr: s.road_addr,
+        x_coord: s.x_coord,
+        y_coord: s.y_coord,
+        created_at: s.created_at.to_string(),
+        updated_at: s.updated_at.to_string(),
+        area_code: s.area_code,
+        area_detail_code: s.area_detail_code,
+        is_active: s.is_active,
+        last_seen_at: s.last_seen_at.to_string(),
+        store_type_name: s.store_type_code.as_ref().and_then(|c| store_type_names.get(c).cloned()),
+        store_type_code: s.store_type_code,
+    }
+}
+
+/// 특정 매장의 변경 이력(매장명, 주소, 좌표 등 필드별 기존 값 / 새 값)을 최신순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `store_id`    - 조회할 매장 ID
+/// * `limit`       - 최대 조회 수
+/// 
+/// # Returns
+/// * `Ok(Json<Vec<StoreHistoryRes>>)`  - 변경 이력 목록
+/// * `Err(String)`                     - 조회 실패
+pub async fn get_store_history(
+    pool: &PgPool,
+    store_id: &str,
+    limit: Option<i64>,
+) -> Result<Json<Vec<StoreHistoryRes>>, String> {
+    let limit = limit
+        .unwrap_or(HISTORY_DEFAULT_LIMIT)
+        .clamp(1, HISTORY_MAX_LIMIT);
+    let rows = find_master_history(pool, "store", store_id, limit)
+        .await
+        .map_err(|e| format!("DB 조회 실패: {}", e))?;
+
+    let result = rows
+        .into_iter()
+        .map(|h| StoreHistoryRes {
+            id: h.id,
+            run_id: h.run_id,
+            field: h.field,
+            old_value: h.old_value,
+            new_value: h.new_value,
+            changed_at: h.changed_at.to_string(),
+        })
+        .collect::<Vec<StoreHistoryRes>>();
+
+    Ok(Json(result))
+}
@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::sync::pagination::PagedResponse;
 
 /// 한국소비자원 '상품정보 API' 전체 응답 DTO
 ///
@@ -13,13 +15,28 @@ pub struct ApiResponse {
 /// 상품정보 API의 `<result>` 내부 구조
 ///
 /// # Fields
-/// * `items` - 상품 정보 리스트
+/// * `total_count` - 전체 건수 (페이지와 무관한 전체 목록 크기)
+/// * `items`       - 상품 정보 리스트
 #[derive(Debug, Deserialize)]
 pub struct ApiResult {
-    #[serde(rename = "item")]
+    #[serde(rename = "totalCount", default)]
+    pub total_count: Option<usize>,
+    #[serde(rename = "item", default)]
     pub items: Vec<ApiItem>,
 }
 
+impl PagedResponse for ApiResponse {
+    type Item = ApiItem;
+
+    fn total_count(&self) -> Option<usize> {
+        self.result.total_count
+    }
+
+    fn into_items(self) -> Vec<ApiItem> {
+        self.result.items
+    }
+}
+
 /// 상품정보 API의 개별 item DTO
 ///
 /// # Fields
@@ -27,7 +44,9 @@ pub struct ApiResult {
 /// * `good_name`           - 상품명
 /// * `good_total_cnt`      - 총 개수
 /// * `good_total_div_code` - 구분 코드
-#[derive(Debug, Deserialize)]
+/// * `good_smlcls_code`    - 상품 소분류 코드 (표준코드 AL)
+/// * `good_unit_div_code`  - 단위 코드 (표준코드 UT)
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApiItem {
     #[serde(rename = "goodId")]
     pub good_id: String,
@@ -37,4 +56,8 @@ pub struct ApiItem {
     pub good_total_cnt: Option<String>,
     #[serde(rename = "goodTotalDivCode")]
     pub good_total_div_code: Option<String>,
+    #[serde(rename = "goodSmlclsCode")]
+    pub good_smlcls_code: Option<String>,
+    #[serde(rename = "goodUnitDivCode")]
+    pub good_unit_div_code: Option<String>,
 }
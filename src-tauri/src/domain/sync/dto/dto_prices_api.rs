@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// 한국소비자원 '가격정보 API' 전체 응답 DTO
 ///
@@ -31,7 +31,7 @@ pub struct ApiResult {
 /// * `good_dc_yn`          - 할인 여부 (Y/N)
 /// * `good_dc_start_day`   - 할인 시작일
 /// * `good_dc_end_day`     - 할인 종료일
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApiItem {
     #[serde(rename = "goodInspectDay")]
     pub good_inspect_day: String,
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// 가격 데이터 조회 요청 DTO
 /// 
@@ -8,3 +8,122 @@ use serde::Deserialize;
 pub struct InspectDayReq {
     pub inspect_day: String,
 }
+
+/// 가격 데이터 기간 백필 요청 DTO
+/// 
+/// # Fields
+/// * `from`    - 시작 조사일 (YYYYMMDD)
+/// * `to`      - 종료 조사일 (YYYYMMDD)
+#[derive(Deserialize)]
+pub struct BackfillReq {
+    pub from: String,
+    pub to: String,
+}
+
+/// 보관된 원본 응답 재처리 대상
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReprocessTarget {
+    Goods,
+    Stores,
+    RegionCodes,
+    StandardCodes,
+    Prices,
+}
+
+/// 표준코드 분류
+/// 
+/// 공공데이터 표준코드 API(getStandardInfoSvc)의 classCode와 저장 테이블을 연결한다.
+/// 지역코드는 계층 구조(level)를 갖는 기존 regions 테이블에 저장한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StandardCodeClass {
+    Region,
+    GoodCategory,
+    Unit,
+    StoreType,
+}
+
+impl StandardCodeClass {
+    /// 동기화 대상 전체 분류 (지역코드 우선)
+    pub const ALL: [StandardCodeClass; 4] = [
+        StandardCodeClass::Region,
+        StandardCodeClass::GoodCategory,
+        StandardCodeClass::Unit,
+        StandardCodeClass::StoreType,
+    ];
+
+    /// 표준코드 API 요청에 사용하는 classCode를 반환한다.
+    pub fn class_code(&self) -> &'static str {
+        match self {
+            StandardCodeClass::Region => "AR",
+            StandardCodeClass::GoodCategory => "AL",
+            StandardCodeClass::Unit => "UT",
+            StandardCodeClass::StoreType => "CT",
+        }
+    }
+
+    /// 코드를 저장하는 테이블명을 반환한다.
+    pub fn table(&self) -> &'static str {
+        match self {
+            StandardCodeClass::Region => "regions",
+            StandardCodeClass::GoodCategory => "good_categories",
+            StandardCodeClass::Unit => "units",
+            StandardCodeClass::StoreType => "store_types",
+        }
+    }
+
+    /// 로그 / 오류 메시지에 사용하는 분류명을 반환한다.
+    pub fn label(&self) -> &'static str {
+        match self {
+            StandardCodeClass::Region => "지역코드",
+            StandardCodeClass::GoodCategory => "상품분류코드",
+            StandardCodeClass::Unit => "단위코드",
+            StandardCodeClass::StoreType => "업태코드",
+        }
+    }
+}
+
+/// 표준코드 동기화 / 조회 요청 DTO
+/// 
+/// # Fields
+/// * `class` - 표준코드 분류 (region, good_category, unit, store_type / 동기화 시 없으면 전체)
+#[derive(Deserialize)]
+pub struct StandardCodeReq {
+    pub class: Option<StandardCodeClass>,
+}
+
+/// 보관된 원본 응답 재처리 요청 DTO
+/// 
+/// # Fields
+/// * `target`      - 재처리 대상 (goods, stores, region_codes, standard_codes, prices)
+/// * `inspect_day` - 가격 재처리 시 조사일 (YYYYMMDD)
+#[derive(Deserialize)]
+pub struct ReprocessReq {
+    pub target: ReprocessTarget,
+    pub inspect_day: Option<String>,
+}
+
+/// 조사일 탐색 요청 DTO
+/// 
+/// # Fields
+/// * `days` - 오늘부터 거슬러 올라갈 일수 (없으면 SYNC_PROBE_DAYS 설정값)
+#[derive(Deserialize)]
+pub struct InspectDayDiscoverReq {
+    pub days: Option<u32>,
+}
+
+/// 격리 항목 조회 요청 DTO
+/// 
+/// # Fields
+/// * `run_id`  - 동기화 실행 기록 ID
+/// * `reason`  - 격리 사유 코드
+/// * `entity`  - 항목 종류 (price, good, store)
+/// * `limit`   - 최대 조회 건수 (기본값 100, 최대 1000)
+#[derive(Deserialize)]
+pub struct QuarantineReq {
+    pub run_id: Option<i32>,
+    pub reason: Option<String>,
+    pub entity: Option<String>,
+    pub limit: Option<i64>,
+}
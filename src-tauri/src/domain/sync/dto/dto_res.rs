@@ -0,0 +1,262 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::domain::sync::dto::dto_req::StandardCodeClass;
+
+/// 가격 데이터 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `inspect_day`     - 조사일 (YYYYMMDD)
+/// * `total_stores`    - 동기화 대상 매장 수
+/// * `success_stores`  - 가격 저장에 성공한 매장 수
+/// * `skipped_stores`  - 조사 데이터가 없는 매장 수
+/// * `saved_prices`    - 저장된 가격 데이터 수
+/// * `quarantined`     - 검증 실패로 격리된 가격 데이터 수
+/// * `failures`        - 매장별 실패 내역
+/// * `price_changes`   - 동기화 후 자동 생성한 조사일 쌍별 가격 변동 결과
+#[derive(Debug, Serialize)]
+pub struct PriceSyncRes {
+    pub inspect_day: String,
+    pub total_stores: usize,
+    pub success_stores: usize,
+    pub skipped_stores: usize,
+    pub saved_prices: usize,
+    pub quarantined: usize,
+    pub failures: Vec<SyncFailureRes>,
+    pub price_changes: Vec<PriceChangePairRes>,
+}
+
+/// 동기화 중 개별 항목의 실패 내역
+///
+/// # Fields
+/// * `store_id`    - 실패한 매장 ID
+/// * `message`     - 실패 사유
+#[derive(Debug, Serialize)]
+pub struct SyncFailureRes {
+    pub store_id: String,
+    pub message: String,
+}
+
+/// 매장 데이터 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `total_stores`        - API에서 받은 매장 수
+/// * `geocoded`            - 좌표를 구한 매장 수
+/// * `geocoded_road`       - 도로명 주소로 좌표를 구한 매장 수
+/// * `geocoded_parcel`     - 지번 주소로 좌표를 구한 매장 수
+/// * `geocoded_upstream`   - 업스트림 지도 좌표를 변환해 사용한 매장 수
+/// * `manual_review`       - 좌표를 구하지 못해 수동 확인이 필요한 매장 수
+/// * `no_address`          - 도로명 / 지번 주소가 모두 없는 매장 수
+/// * `quarantined`         - 검증 실패로 격리된 매장 수
+/// * `geocode_cache_hits`  - 좌표 캐시를 재사용한 주소 검색 수
+/// * `geocode_cache_misses`- 좌표를 새로 조회한 주소 검색 수
+/// * `geocode_failed`      - 주소 검색 요청 실패로 기존 좌표를 유지한 매장 수
+/// * `deactivated`         - 최신 목록에서 사라져 비활성화된 매장 수
+#[derive(Debug, Serialize)]
+pub struct StoreSyncRes {
+    pub total_stores: usize,
+    pub geocoded: usize,
+    pub geocoded_road: usize,
+    pub geocoded_parcel: usize,
+    pub geocoded_upstream: usize,
+    pub manual_review: usize,
+    pub no_address: usize,
+    pub quarantined: usize,
+    pub geocode_cache_hits: usize,
+    pub geocode_cache_misses: usize,
+    pub geocode_failed: usize,
+    pub deactivated: u64,
+}
+
+/// 상품 데이터 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `total_goods` - API에서 받은 상품 수
+/// * `saved_goods` - 저장된 상품 수
+/// * `quarantined` - 검증 실패로 격리된 상품 수
+/// * `deactivated` - 최신 목록에서 사라져 비활성화된 상품 수
+#[derive(Debug, Serialize)]
+pub struct GoodSyncRes {
+    pub total_goods: usize,
+    pub saved_goods: usize,
+    pub quarantined: usize,
+    pub deactivated: u64,
+}
+
+/// 상품 + 매장 데이터 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `goods`   - 상품 동기화 결과
+/// * `stores`  - 매장 동기화 결과
+#[derive(Debug, Serialize)]
+pub struct GoodsAndStoresSyncRes {
+    pub goods: GoodSyncRes,
+    pub stores: StoreSyncRes,
+}
+
+/// 백그라운드 동기화 시작 응답 DTO
+///
+/// # Fields
+/// * `run_id` - 생성된 동기화 실행 기록 ID
+#[derive(Debug, Serialize)]
+pub struct SyncRunStartRes {
+    pub run_id: i32,
+}
+
+/// 동기화 실행 기록 조회 응답 DTO
+///
+/// # Fields
+/// * `id`              - 실행 기록 ID
+/// * `kind`            - 동기화 종류
+/// * `params`          - 실행 파라미터
+/// * `status`          - 상태 ("running" | "succeeded" | "failed")
+/// * `started_at`      - 시작 시각
+/// * `finished_at`     - 종료 시각
+/// * `success_count`   - 성공 항목 수
+/// * `failure_count`   - 실패 항목 수
+/// * `skipped_count`   - 건너뛴 항목 수
+/// * `summary`         - 결과 요약
+/// * `error`           - 최종 에러 메시지
+#[derive(Debug, Serialize)]
+pub struct SyncRunRes {
+    pub id: i32,
+    pub kind: String,
+    pub params: Value,
+    pub status: String,
+    pub started_at: NaiveDateTime,
+    pub finished_at: Option<NaiveDateTime>,
+    pub success_count: i32,
+    pub failure_count: i32,
+    pub skipped_count: i32,
+    pub summary: Option<Value>,
+    pub error: Option<String>,
+}
+
+/// 가격 데이터 기간 백필 결과 응답 DTO
+///
+/// # Fields
+/// * `from`            - 시작 조사일
+/// * `to`              - 종료 조사일
+/// * `days`            - 조사 데이터가 있거나 처리에 실패한 날짜별 결과
+/// * `no_data_days`    - 조사 데이터가 없어 건너뛴 날짜 수
+/// * `price_changes`   - 연속한 조사일 쌍별 가격 변동 생성 결과
+#[derive(Debug, Serialize)]
+pub struct BackfillRes {
+    pub from: String,
+    pub to: String,
+    pub days: Vec<BackfillDayRes>,
+    pub no_data_days: usize,
+    pub price_changes: Vec<PriceChangePairRes>,
+}
+
+/// 백필 날짜별 결과 응답 DTO
+///
+/// # Fields
+/// * `inspect_day`     - 조사일
+/// * `status`          - 처리 결과 ("synced" | "partial" | "already_loaded" | "failed")
+/// * `run_id`          - 해당 날짜의 가격 동기화 실행 기록 ID
+/// * `saved_prices`    - 저장된 가격 데이터 수
+/// * `message`         - 실패 사유
+#[derive(Debug, Serialize)]
+pub struct BackfillDayRes {
+    pub inspect_day: String,
+    pub status: String,
+    pub run_id: Option<i32>,
+    pub saved_prices: usize,
+    pub message: Option<String>,
+}
+
+/// 조사일 쌍별 가격 변동 생성 결과 응답 DTO
+///
+/// # Fields
+/// * `inspect_day` - 기준 조사일
+/// * `prev_day`    - 비교 대상 이전 조사일
+/// * `inserted`    - 생성된 가격 변동 수
+/// * `message`     - 실패 사유
+#[derive(Debug, Serialize)]
+pub struct PriceChangePairRes {
+    pub inspect_day: String,
+    pub prev_day: String,
+    pub inserted: i32,
+    pub message: Option<String>,
+}
+
+/// 표준코드 분류별 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `class`       - 표준코드 분류
+/// * `class_code`  - 표준코드 API classCode
+/// * `saved`       - 저장된 코드 수
+#[derive(Debug, Serialize)]
+pub struct StandardCodeSyncRes {
+    pub class: StandardCodeClass,
+    pub class_code: String,
+    pub saved: usize,
+}
+
+/// 조사일 탐색 결과 응답 DTO
+///
+/// # Fields
+/// * `from`        - 탐색 시작일
+/// * `to`          - 탐색 종료일
+/// * `probed_days` - 탐색한 날짜 수
+/// * `discovered`  - 새로 발견하여 등록한 조사일 목록
+#[derive(Debug, Serialize)]
+pub struct InspectDayDiscoverRes {
+    pub from: String,
+    pub to: String,
+    pub probed_days: usize,
+    pub discovered: Vec<String>,
+}
+
+/// 최신 조사일 가격 동기화 결과 응답 DTO
+///
+/// # Fields
+/// * `inspect_day`     - 가장 최근 조사일 (게시된 조사일이 없으면 None)
+/// * `already_synced`  - 이미 동기화되어 건너뛰었는지 여부
+/// * `prices`          - 가격 동기화 결과
+#[derive(Debug, Serialize)]
+pub struct LatestPriceSyncRes {
+    pub inspect_day: Option<String>,
+    pub already_synced: bool,
+    pub prices: Option<PriceSyncRes>,
+}
+
+/// 격리 항목 조회 응답 DTO
+///
+/// # Fields
+/// * `id`          - 격리 항목 ID
+/// * `run_id`      - 격리된 동기화 실행 기록 ID
+/// * `entity`      - 항목 종류 ("price" | "good" | "store")
+/// * `record_key`  - 항목 식별 값
+/// * `reason`      - 격리 사유 코드
+/// * `detail`      - 격리 사유 상세
+/// * `payload`     - 원본 API 항목
+/// * `created_at`  - 격리 시각
+#[derive(Debug, Serialize)]
+pub struct QuarantineRes {
+    pub id: i32,
+    pub run_id: Option<i32>,
+    pub entity: String,
+    pub record_key: String,
+    pub reason: String,
+    pub detail: Option<String>,
+    pub payload: Value,
+    pub created_at: NaiveDateTime,
+}
+
+/// 수동 확인 매장 좌표 재시도 결과 응답 DTO
+///
+/// # Fields
+/// * `total_stores`    - 재시도 대상 매장 수
+/// * `geocoded_road`   - 도로명 주소로 좌표를 구한 매장 수
+/// * `geocoded_parcel` - 지번 주소로 좌표를 구한 매장 수
+/// * `manual_review`   - 여전히 수동 확인이 필요한 매장 수
+#[derive(Debug, Serialize)]
+pub struct GeocodeRetryRes {
+    pub total_stores: usize,
+    pub geocoded_road: usize,
+    pub geocoded_parcel: usize,
+    pub manual_review: usize,
+}
@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+use crate::domain::sync::pagination::PagedResponse;
+
+/// 한국소비자원 '표준코드 API' 전체 응답 DTO (지역 / 상품분류 / 단위 / 업태 공통)
+///
+/// # Fields
+/// * `result` - API 결과 본문
+#[derive(Debug, Deserialize)]
+pub struct ApiResponse {
+    #[serde(rename = "result")]
+    pub result: ApiResult,
+}
+
+/// 표준코드 API의 `<result>` 내부 구조
+///
+/// # Fields
+/// * `total_count` - 전체 건수 (페이지와 무관한 전체 목록 크기)
+/// * `items`       - 표준 코드 리스트
+#[derive(Debug, Deserialize)]
+pub struct ApiResult {
+    #[serde(rename = "totalCount", default)]
+    pub total_count: Option<usize>,
+    #[serde(rename = "iros.openapi.service.vo.stdInfoVO", default)]
+    pub items: Vec<ApiItem>,
+}
+
+impl PagedResponse for ApiResponse {
+    type Item = ApiItem;
+
+    fn total_count(&self) -> Option<usize> {
+        self.result.total_count
+    }
+
+    fn into_items(self) -> Vec<ApiItem> {
+        self.result.items
+    }
+}
+
+/// 표준코드 API의 개별 item DTO
+/// 
+/// # Fields
+/// * `code`        - 코드
+/// * `code_name`   - 코드명
+/// * `high_code`   - 상위 코드
+#[derive(Debug, Deserialize)]
+pub struct ApiItem {
+    #[serde(rename = "code")]
+    pub code: String,
+    #[serde(rename = "codeName")]
+    pub code_name: String,
+    #[serde(rename = "highCode")]
+    pub high_code: String,
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::sync::pagination::PagedResponse;
 
 /// 한국소비자원 '매장정보 API' 전체 응답 DTO
 ///
@@ -13,13 +15,28 @@ pub struct ApiResponse {
 /// 매장정보 API의 `<result>` 내부 구조
 ///
 /// # Fields
-/// * `items` - 매장 정보 리스트
+/// * `total_count` - 전체 건수 (페이지와 무관한 전체 목록 크기)
+/// * `items`       - 매장 정보 리스트
 #[derive(Debug, Deserialize)]
 pub struct ApiResult {
-    #[serde(rename = "iros.openapi.service.vo.entpInfoVO")]
+    #[serde(rename = "totalCount", default)]
+    pub total_count: Option<usize>,
+    #[serde(rename = "iros.openapi.service.vo.entpInfoVO", default)]
     pub items: Vec<ApiItem>,
 }
 
+impl PagedResponse for ApiResponse {
+    type Item = ApiItem;
+
+    fn total_count(&self) -> Option<usize> {
+        self.result.total_count
+    }
+
+    fn into_items(self) -> Vec<ApiItem> {
+        self.result.items
+    }
+}
+
 /// 매장정보 API의 개별 item DTO
 /// 
 /// # Fields
@@ -29,11 +46,12 @@ pub struct ApiResult {
 /// * `post_no`             - 우편번호
 /// * `jibun_addr`          - 지번 주소
 /// * `road_addr`           - 도로명 주소
-/// * `x_coord`             - 위도 좌표
-/// * `y_coord`             - 경도 좌표
+/// * `x_coord`             - 지도 X 좌표 (좌표 변환 실패 시 대체 좌표로 사용)
+/// * `y_coord`             - 지도 Y 좌표 (좌표 변환 실패 시 대체 좌표로 사용)
 /// * `area_code`           - 지역 코드
 /// * `area_detail_code`    - 지역 상세 코드
-#[derive(Debug, Deserialize)]
+/// * `entp_type_code`      - 업태 코드 (표준코드 CT)
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ApiItem {
     #[serde(rename = "entpId")]
     pub entp_id: String,
@@ -55,4 +73,6 @@ pub struct ApiItem {
     pub area_code: String,
     #[serde(rename = "areaDetailCode")]
     pub area_detail_code: String,
+    #[serde(rename = "entpTypeCode")]
+    pub entp_type_code: Option<String>,
 }
\ No newline at end of file
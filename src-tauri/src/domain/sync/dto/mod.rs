@@ -1,5 +1,6 @@
 pub mod dto_goods_api;
 pub mod dto_prices_api;
-pub mod dto_region_codes_api;
 pub mod dto_req;
+pub mod dto_res;
+pub mod dto_standard_codes_api;
 pub mod dto_stores_api;
@@ -4,45 +4,79 @@
  *     공공데이터 API 동기화 도메인의 라우팅 계층으로,
  *     클라이언트 요청을 받아 서비스 계층으로 전달하고
  *     그 결과를 HTTP 응답 형태로 반환한다.
+ *     동기화 작업은 백그라운드로 실행되며, 응답으로 실행 ID를 반환한다.
+ *     파이프라인 단계(표준코드 / 지역코드 / 상품·매장 / 가격 / 백필 / 조사일 탐색 / 재반영)는
+ *     정기 동기화와 같은 advisory lock을 잡고 실행되며, 이미 실행 중이면 409로 거절한다.
  *
  * Responsibilities:
  *     1) sync_goods_and_stores_handler()
  *         - 상품 + 매장 데이터 동기화
- * 
+ *
  *     2) sync_prices_handler()
  *         - 특정 조사일의 가격 데이터 동기화
- * 
+ *
  *     3) sync_regions_codes_handler()
  *         - 지역코드 데이터 동기화
+ *
+ *     4) sync_backfill_handler()
+ *         - 기간 내 가격 데이터 및 가격 변동 백필
+ *
+ *     5) sync_inspect_days_discover_handler()
+ *         - 공공데이터에 게시된 조사일 탐색
+ *
+ *     6) sync_latest_prices_handler()
+ *         - 가장 최근 조사일의 가격 데이터 동기화
+ *
+ *     7) sync_reprocess_handler()
+ *         - 보관된 원본 응답으로 업스트림 호출 없이 DB 재반영
+ *
+ *     8) sync_run_get_handler()
+ *         - 동기화 실행 기록 및 진행 상황 조회
+ *
+ *     9) sync_quarantine_list_handler()
+ *         - 검증 실패로 격리된 항목 조회 (동기화 실행 / 사유별)
+ *
+ *     10) sync_standard_codes_handler()
+ *         - 분류별(지역 / 상품분류 / 단위 / 업태) 표준코드 동기화
 ************************************************************************/
 
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     response::IntoResponse,
     Json,
 };
 use reqwest::StatusCode;
-use serde_json::json;
+use serde_json::{json, Value};
 use sqlx::PgPool;
+use std::future::Future;
 
-use crate::domain::sync::{
-    dto::dto_req::InspectDayReq,
-    service,
+use crate::{
+    common::repository::repository_lock::{try_lock, unlock, SYNC_PIPELINE_LOCK_KEY},
+    config::{state::AppState, sync::load_scheduler_config},
+    domain::sync::{
+        dto::{
+            dto_req::{
+                BackfillReq, InspectDayDiscoverReq, InspectDayReq, QuarantineReq, ReprocessReq,
+                StandardCodeReq,
+            },
+            dto_res::SyncRunStartRes,
+        },
+        progress::{spawn_sync_run, SyncKind, SyncProgress},
+        service,
+    },
 };
 
-/// 상품 + 매장 데이터를 공공데이터 API에서 받아 DB에 갱신한다.
-/// 
+/// 백그라운드 실행 시작 결과를 HTTP 응답으로 변환한다.
+///
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
-/// 
+/// * `started` - 실행 기록 ID 또는 에러
+///
 /// # Returns
-/// * `200 OK`                      - 갱신 성공
-/// * `500 INTERNAL_SERVER_ERROR`   - 갱신 실패
-pub async fn sync_goods_and_stores_handler(
-    State(pool): State<PgPool>
-) -> impl IntoResponse {
-    match service::upsert_good_and_store(&pool).await {
-        Ok(res) => (StatusCode::OK, res.into_response()),
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `500 INTERNAL_SERVER_ERROR`   - 실행 기록 생성 실패
+fn started_response(started: Result<i32, String>) -> (StatusCode, axum::response::Response) {
+    match started {
+        Ok(run_id) => (StatusCode::ACCEPTED, Json(SyncRunStartRes { run_id }).into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({ "message": e })).into_response(),
@@ -50,21 +84,278 @@ pub async fn sync_goods_and_stores_handler(
     }
 }
 
-/// 특정 조사일의 가격 데이터를 공공데이터 API에서 받아 DB에 저장한다.
-/// 
+/// 동기화 파이프라인 잠금을 획득한 뒤 작업을 백그라운드로 시작한다.
+/// 잠금은 작업이 끝날 때 해제되며, 정기 동기화 / 관리자 CLI와 동시에 실행되지 않는다.
+/// 같은 테이블을 갱신하는 가격변동 핸들러도 이 함수를 사용한다.
+///
 /// # Arguments
 /// * `pool`    - DB 커넥션 풀
+/// * `kind`    - 동기화 종류
+/// * `params`  - 실행 파라미터
+/// * `job`     - 진행 상황 추적기를 받아 동기화를 수행하는 작업
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn spawn_pipeline_run<F, Fut>(
+    pool: &PgPool,
+    kind: SyncKind,
+    params: Value,
+    job: F,
+) -> (StatusCode, axum::response::Response)
+where
+    F: FnOnce(SyncProgress) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Value, String>> + Send + 'static,
+{
+    let lock = match try_lock(pool, SYNC_PIPELINE_LOCK_KEY).await {
+        Ok(Some(lock)) => lock,
+        Ok(None) => {
+            return (
+                StatusCode::CONFLICT,
+                Json(json!({ "message": "다른 동기화 파이프라인이 실행 중입니다" })).into_response(),
+            )
+        }
+        Err(e) => {
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(json!({ "message": e })).into_response(),
+            )
+        }
+    };
+
+    let started = spawn_sync_run(pool, kind, params, move |progress| async move {
+        let result = job(progress).await;
+
+        if let Err(e) = unlock(lock).await {
+            tracing::error!("{}", e);
+        }
+
+        result
+    })
+    .await;
+
+    started_response(started)
+}
+
+/// 상품 + 매장 데이터 동기화를 백그라운드로 시작한다.
+///
+/// # Arguments
+/// * `state` - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_goods_and_stores_handler(
+    State(state): State<AppState>
+) -> impl IntoResponse {
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::GoodsAndStores, json!({}), move |progress| async move {
+        service::upsert_good_and_store(&job_state.pool, &job_state.sources, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// 특정 조사일의 가격 데이터 동기화를 백그라운드로 시작한다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
 /// * `param`   - InspectDayReq { inspect_day }
-/// 
+///
 /// # Returns
-/// * `200 OK`                      - 저장 성공
-/// * `500 INTERNAL_SERVER_ERROR`   - 저장 실패
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `400 BAD_REQUEST`             - 조사일 형식 오류 또는 미래 날짜
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
 pub async fn sync_prices_handler(
-    State(pool): State<PgPool>,
+    State(state): State<AppState>,
     Query(param): Query<InspectDayReq>,
 ) -> impl IntoResponse {
-    match service::upsert_price(&pool, &param.inspect_day).await {
-        Ok(res) => (StatusCode::OK, res.into_response()),
+    if let Err(e) = service::validate_inspect_day(&param.inspect_day) {
+        return (StatusCode::BAD_REQUEST, Json(json!({ "message": e })).into_response());
+    }
+
+    let params = json!({ "inspect_day": param.inspect_day });
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::Prices, params, move |progress| async move {
+        service::upsert_price(&job_state.pool, &job_state.sources, &param.inspect_day, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// 지역코드 데이터 동기화를 백그라운드로 시작한다.
+///
+/// # Arguments
+/// * `state` - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_region_codes_handler(State(state): State<AppState>) -> impl IntoResponse {
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::RegionCodes, json!({}), move |progress| async move {
+        service::upsert_region_codes(&job_state.pool, &job_state.sources, &progress)
+            .await
+            .map(|count| json!({ "saved_regions": count }))
+    })
+    .await
+}
+
+/// 표준코드 동기화를 백그라운드로 시작한다.
+/// 분류를 지정하지 않으면 지역 / 상품분류 / 단위 / 업태 코드를 모두 동기화한다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+/// * `param`   - StandardCodeReq { class }
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_standard_codes_handler(
+    State(state): State<AppState>,
+    Query(param): Query<StandardCodeReq>,
+) -> impl IntoResponse {
+    let job_state = state.clone();
+    let params = json!({ "class": param.class });
+
+    spawn_pipeline_run(&state.pool, SyncKind::StandardCodes, params, move |progress| async move {
+        service::upsert_standard_codes(&job_state.pool, &job_state.sources, param.class, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// 기간 내 가격 데이터 및 가격 변동 백필을 백그라운드로 시작한다.
+/// 날짜별 진행 상황은 백필 실행 기록과 날짜별 가격 동기화 실행 기록으로 조회한다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+/// * `param`   - BackfillReq { from, to }
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_backfill_handler(
+    State(state): State<AppState>,
+    Query(param): Query<BackfillReq>,
+) -> impl IntoResponse {
+    let params = json!({ "from": param.from, "to": param.to });
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::PriceBackfill, params, move |progress| async move {
+        service::backfill_prices(&job_state.pool, &job_state.sources, &param.from, &param.to, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// 공공데이터에 게시된 조사일 탐색을 백그라운드로 시작한다.
+/// 발견한 조사일은 inspect_days 테이블에 등록된다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+/// * `param`   - InspectDayDiscoverReq { days }
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_inspect_days_discover_handler(
+    State(state): State<AppState>,
+    Query(param): Query<InspectDayDiscoverReq>,
+) -> impl IntoResponse {
+    let probe_days = param.days.unwrap_or_else(|| load_scheduler_config().probe_days);
+    let params = json!({ "days": probe_days });
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::InspectDayDiscovery, params, move |progress| async move {
+        service::discover_inspect_days(&job_state.pool, &job_state.sources, probe_days, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// 게시된 조사일을 탐색한 뒤 가장 최근 조사일의 가격 동기화를 백그라운드로 시작한다.
+///
+/// # Arguments
+/// * `state` - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_latest_prices_handler(
+    State(state): State<AppState>
+) -> impl IntoResponse {
+    let probe_days = load_scheduler_config().probe_days;
+    let job_state = state.clone();
+
+    spawn_pipeline_run(&state.pool, SyncKind::Prices, json!({ "latest": true }), move |progress| async move {
+        service::sync_latest_prices(&job_state.pool, &job_state.sources, probe_days, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await
+}
+
+/// raw_payloads에 보관된 원본 응답으로 DB 재반영을 백그라운드로 시작한다.
+/// 업스트림 API는 호출하지 않는다.
+///
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `param`   - ReprocessReq { target, inspect_day }
+///
+/// # Returns
+/// * `202 ACCEPTED`                - 실행 시작 (run_id)
+/// * `409 CONFLICT`                - 다른 동기화 파이프라인이 실행 중
+/// * `500 INTERNAL_SERVER_ERROR`   - 잠금 조회 또는 실행 기록 생성 실패
+pub async fn sync_reprocess_handler(
+    State(pool): State<PgPool>,
+    Query(param): Query<ReprocessReq>,
+) -> impl IntoResponse {
+    let params = json!({ "target": param.target, "inspect_day": param.inspect_day });
+    let job_pool = pool.clone();
+
+    spawn_pipeline_run(&pool, SyncKind::Reprocess, params, move |progress| async move {
+        service::reprocess_archived(&job_pool, param.target, param.inspect_day.as_deref(), &progress).await
+    })
+    .await
+}
+
+/// 동기화 실행 기록과 진행 상황을 조회한다.
+///
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `run_id`  - 실행 기록 ID
+///
+/// # Returns
+/// * `200 OK`                      - 조회 성공
+/// * `404 NOT_FOUND`               - 해당 실행 기록 없음
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn sync_run_get_handler(
+    State(pool): State<PgPool>,
+    Path(run_id): Path<i32>,
+) -> impl IntoResponse {
+    match service::get_sync_run(&pool, run_id).await {
+        Ok(Some(run)) => (StatusCode::OK, Json(run).into_response()),
+        Ok(None) => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "message": "동기화 실행 기록 없음" })).into_response(),
+        ),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({ "message": e })).into_response(),
@@ -72,17 +363,29 @@ pub async fn sync_prices_handler(
     }
 }
 
-/// 지역코드 데이터를 공공데이터 API에서 받아 DB에 갱신한다.
-/// 
+/// 검증 실패로 격리된 항목을 조회한다.
+///
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
-/// 
+/// * `pool`    - DB 커넥션 풀
+/// * `param`   - QuarantineReq { run_id, reason, entity, limit }
+///
 /// # Returns
-/// * `200 OK`                      - 갱신 성공
-/// * `500 INTERNAL_SERVER_ERROR`   - 갱신 실패
-pub async fn sync_region_codes_handler(State(pool): State<PgPool>) -> impl IntoResponse {
-    match service::upsert_region_codes(&pool).await {
-        Ok(res) => (StatusCode::OK, res.into_response()),
+/// * `200 OK`                      - 조회 성공
+/// * `500 INTERNAL_SERVER_ERROR`   - 조회 실패
+pub async fn sync_quarantine_list_handler(
+    State(pool): State<PgPool>,
+    Query(param): Query<QuarantineReq>,
+) -> impl IntoResponse {
+    match service::get_quarantine(
+        &pool,
+        param.run_id,
+        param.reason.as_deref(),
+        param.entity.as_deref(),
+        param.limit,
+    )
+    .await
+    {
+        Ok(rows) => (StatusCode::OK, Json(rows).into_response()),
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(json!({ "message": e })).into_response(),
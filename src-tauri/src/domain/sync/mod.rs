@@ -1,3 +1,7 @@
 pub mod dto;
 pub mod handler;
+pub mod pagination;
+pub mod progress;
+pub mod scheduler;
 pub mod service;
+pub mod validation;
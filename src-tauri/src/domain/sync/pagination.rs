@@ -0,0 +1,222 @@
+/************************************************************************
+ * File: domain/sync/pagination.rs
+ * Description:
+ *     공공데이터포털 목록 API(상품 / 매장 / 지역코드)의 페이지 조회를 담당한다.
+ *     응답에 포함된 전체 건수(totalCount)를 기준으로 모든 페이지를 읽고,
+ *     받은 건수가 전체 건수와 다르면 잘린 목록으로 동기화하지 않도록 실패 처리한다.
+ *
+ * Responsibilities:
+ *     1) PagedResponse
+ *         - 목록 API 응답 DTO에서 전체 건수 / 항목 목록을 꺼내는 공통 인터페이스
+ * 
+ *     2) fetch_all_pages()
+ *         - pageNo / numOfRows 로 전체 페이지 조회 및 건수 검증
+************************************************************************/
+
+use quick_xml::de::from_str;
+use serde::de::DeserializeOwned;
+use std::future::Future;
+
+use crate::common::external::{
+    archive::content_hash,
+    http_client::{HttpError, HttpResponse},
+};
+
+/// 조회할 수 있는 최대 페이지 수 (응답 오류로 인한 무한 반복 방지)
+const MAX_PAGES: u32 = 10_000;
+
+/// 페이지 단위로 조회되는 목록 API 응답
+pub trait PagedResponse: DeserializeOwned {
+    /// 목록 항목 DTO
+    type Item;
+
+    /// 응답에 보고된 전체 건수
+    fn total_count(&self) -> Option<usize>;
+
+    /// 이번 페이지의 항목 목록
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+/// 목록 API의 모든 페이지를 조회하여 항목을 모은다.
+/// 
+/// 첫 페이지의 전체 건수(totalCount)만큼 받을 때까지 다음 페이지를 요청하며,
+/// 전체 건수가 없는 응답은 페이지 크기보다 적게 받은 페이지를 마지막으로 본다.
+/// 
+/// # Arguments
+/// * `label`       - 로그 / 에러 메시지에 사용할 목록 이름
+/// * `page_size`   - 페이지당 요청 건수 (numOfRows)
+/// * `fetch`       - 페이지 번호(pageNo)를 받아 원본 응답을 조회하는 함수
+/// 
+/// # Returns
+/// * `Ok(Vec<R::Item>)`    - 전체 항목 목록
+/// * `Err(String)`         - 요청 / 파싱 실패 또는 받은 건수가 전체 건수와 다름
+pub async fn fetch_all_pages<R, F, Fut>(
+    label: &str,
+    page_size: u32,
+    fetch: F,
+) -> Result<Vec<R::Item>, String>
+where
+    R: PagedResponse,
+    F: Fn(u32) -> Fut,
+    Fut: Future<Output = Result<HttpResponse, HttpError>>,
+{
+    let mut items: Vec<R::Item> = Vec::new();
+    let mut reported_total: Option<usize> = None;
+
+    for page_no in 1..=MAX_PAGES {
+        let text = fetch(page_no).await?.body;
+        let parsed: R = from_str(&text).map_err(|e| {
+            format!(
+                "{} {}페이지 XML 파싱 실패 (sha256 {}): {}",
+                label,
+                page_no,
+                content_hash(&text),
+                e
+            )
+        })?;
+
+        match (reported_total, parsed.total_count()) {
+            (None, total) => reported_total = total,
+            (Some(prev), Some(total)) if prev != total => {
+                return Err(format!(
+                    "{} 조회 중 전체 건수 변경: {}건 → {}건",
+                    label, prev, total
+                ));
+            }
+            _ => {}
+        }
+
+        let page = parsed.into_items();
+        let page_len = page.len();
+        items.extend(page);
+
+        tracing::debug!("{} {}페이지 {}건 조회 (누적 {}건)", label, page_no, page_len, items.len());
+
+        let done = match reported_total {
+            Some(total) => items.len() >= total || page_len == 0,
+            None => page_len < page_size as usize,
+        };
+        if done {
+            return verify_total(label, reported_total, items);
+        }
+    }
+
+    Err(format!("{} 조회 페이지 수 초과 ({}페이지)", label, MAX_PAGES))
+}
+
+/// 받은 항목 수가 응답에 보고된 전체 건수와 같은지 확인한다.
+/// 
+/// # Arguments
+/// * `label`           - 목록 이름
+/// * `reported_total`  - 응답에 보고된 전체 건수
+/// * `items`           - 받은 항목 목록
+/// 
+/// # Returns
+/// * `Ok(Vec<T>)`  - 건수 일치 (전체 건수가 없으면 그대로 반환)
+/// * `Err(String)` - 건수 불일치
+fn verify_total<T>(
+    label: &str,
+    reported_total: Option<usize>,
+    items: Vec<T>,
+) -> Result<Vec<T>, String> {
+    match reported_total {
+        Some(total) if total != items.len() => Err(format!(
+            "{} 응답 건수 불일치: 전체 {}건 중 {}건 수신",
+            label,
+            total,
+            items.len()
+        )),
+        Some(_) => Ok(items),
+        None => {
+            tracing::warn!("{} 응답에 전체 건수 없음 — {}건 수신 (건수 검증 생략)", label, items.len());
+            Ok(items)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::sync::dto::dto_goods_api::ApiResponse as GoodsApiResponse;
+
+    /// 전체 건수와 상품 ID 목록으로 상품 목록 응답을 만든다.
+    fn goods_page(total: Option<usize>, ids: &[&str]) -> Result<HttpResponse, HttpError> {
+        let total = total.map(|t| format!("<totalCount>{}</totalCount>", t)).unwrap_or_default();
+        let items: String = ids
+            .iter()
+            .map(|id| format!("<item><goodId>{}</goodId><goodName>상품 {}</goodName></item>", id, id))
+            .collect();
+
+        Ok(HttpResponse {
+            status: 200,
+            body: format!("<response><result>{}{}</result></response>", total, items),
+        })
+    }
+
+    #[test]
+    fn verify_total_accepts_matching_or_missing_total() {
+        assert_eq!(verify_total("상품", Some(2), vec![1, 2]), Ok(vec![1, 2]));
+        assert_eq!(verify_total("상품", None, vec![1]), Ok(vec![1]));
+        assert_eq!(verify_total::<i32>("상품", Some(0), vec![]), Ok(vec![]));
+    }
+
+    #[test]
+    fn verify_total_rejects_count_mismatch() {
+        let err = verify_total("상품", Some(3), vec![1, 2]).unwrap_err();
+        assert!(err.contains("전체 3건 중 2건"), "{}", err);
+
+        assert!(verify_total("상품", Some(1), vec![1, 2]).is_err());
+    }
+
+    #[tokio::test]
+    async fn fetch_all_pages_reads_until_total() {
+        let items = fetch_all_pages::<GoodsApiResponse, _, _>("상품", 2, |page_no| async move {
+            match page_no {
+                1 => goods_page(Some(3), &["1", "2"]),
+                2 => goods_page(Some(3), &["3"]),
+                _ => panic!("불필요한 페이지 요청: {}", page_no),
+            }
+        })
+        .await
+        .unwrap();
+
+        let ids: Vec<String> = items.into_iter().map(|i| i.good_id).collect();
+        assert_eq!(ids, vec!["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn fetch_all_pages_fails_on_truncated_or_changing_total() {
+        // 전체 3건이라고 했지만 두 번째 페이지가 비어 있음
+        let truncated = fetch_all_pages::<GoodsApiResponse, _, _>("상품", 2, |page_no| async move {
+            match page_no {
+                1 => goods_page(Some(3), &["1", "2"]),
+                _ => goods_page(Some(3), &[]),
+            }
+        })
+        .await;
+        assert!(truncated.unwrap_err().contains("건수 불일치"));
+
+        let changed = fetch_all_pages::<GoodsApiResponse, _, _>("상품", 2, |page_no| async move {
+            match page_no {
+                1 => goods_page(Some(3), &["1", "2"]),
+                _ => goods_page(Some(4), &["3"]),
+            }
+        })
+        .await;
+        assert!(changed.unwrap_err().contains("전체 건수 변경"));
+    }
+
+    #[tokio::test]
+    async fn fetch_all_pages_without_total_stops_at_short_page() {
+        let items = fetch_all_pages::<GoodsApiResponse, _, _>("상품", 2, |page_no| async move {
+            match page_no {
+                1 => goods_page(None, &["1", "2"]),
+                2 => goods_page(None, &["3"]),
+                _ => panic!("불필요한 페이지 요청: {}", page_no),
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(items.len(), 3);
+    }
+}
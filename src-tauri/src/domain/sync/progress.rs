@@ -0,0 +1,322 @@
+/************************************************************************
+ * File: domain/sync/progress.rs
+ * Description:
+ *     동기화 실행(sync_runs) 기록과 진행 상황 추적을 담당한다.
+ *
+ * Responsibilities:
+ *     1) SyncProgress
+ *         - 성공/실패/건너뜀 카운터 누적 및 DB 반영
+ * 
+ *     2) run_tracked()
+ *         - 동기화 작업을 실행 기록과 함께 현재 태스크에서 실행
+ * 
+ *     3) spawn_sync_run()
+ *         - 동기화 작업을 백그라운드로 실행하고 실행 ID를 즉시 반환
+ *         - 작업 태스크가 패닉으로 끝나면 실행 기록을 실패로 종료
+ * 
+ *     4) recover_interrupted_runs()
+ *         - 생존 신호가 끊긴(프로세스가 중단된) 실행 기록만 정리
+************************************************************************/
+
+use serde_json::Value;
+use sqlx::PgPool;
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    },
+};
+use tokio::time::{interval, Duration};
+
+use crate::common::repository::repository_sync_run::{
+    create_sync_run, fail_interrupted_sync_runs, finish_sync_run, update_sync_run_progress,
+};
+
+/// 진행 상황을 DB에 반영하는 주기 (생존 신호 갱신 주기)
+const FLUSH_INTERVAL: Duration = Duration::from_secs(2);
+/// 실행이 중단된 것으로 보는 생존 신호 경과 시간
+const RUN_STALE_AFTER: Duration = Duration::from_secs(600);
+
+/// 동기화 종류
+pub enum SyncKind {
+    GoodsAndStores,
+    Prices,
+    RegionCodes,
+    StandardCodes,
+    PriceChange,
+    PriceChangeRecompute,
+    PriceBackfill,
+    InspectDayDiscovery,
+    Reprocess,
+    GeocodeRetry,
+}
+
+impl SyncKind {
+    /// sync_runs.kind 컬럼에 저장되는 문자열을 반환한다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SyncKind::GoodsAndStores => "goods_and_stores",
+            SyncKind::Prices => "prices",
+            SyncKind::RegionCodes => "region_codes",
+            SyncKind::StandardCodes => "standard_codes",
+            SyncKind::PriceChange => "price_change",
+            SyncKind::PriceChangeRecompute => "price_change_recompute",
+            SyncKind::PriceBackfill => "price_backfill",
+            SyncKind::InspectDayDiscovery => "inspect_day_discovery",
+            SyncKind::Reprocess => "reprocess",
+            SyncKind::GeocodeRetry => "geocode_retry",
+        }
+    }
+}
+
+/// 진행 중인 동기화 실행의 카운터
+#[derive(Default)]
+struct Counters {
+    success: AtomicI32,
+    failure: AtomicI32,
+    skipped: AtomicI32,
+}
+
+/// 하나의 동기화 실행에 대한 진행 상황 추적기
+///
+/// # Fields
+/// * `run_id`      - sync_runs 실행 기록 ID
+/// * `pool`        - DB 커넥션 풀
+/// * `counters`    - 성공/실패/건너뜀 카운터
+#[derive(Clone)]
+pub struct SyncProgress {
+    run_id: i32,
+    pool: PgPool,
+    counters: Arc<Counters>,
+}
+
+impl SyncProgress {
+    /// 실행 기록 ID를 반환한다.
+    pub fn run_id(&self) -> i32 {
+        self.run_id
+    }
+
+    /// 성공 항목 수를 누적한다.
+    pub fn add_success(&self, n: usize) {
+        self.counters.success.fetch_add(n as i32, Ordering::Relaxed);
+    }
+
+    /// 실패 항목 수를 누적한다.
+    pub fn add_failure(&self, n: usize) {
+        self.counters.failure.fetch_add(n as i32, Ordering::Relaxed);
+    }
+
+    /// 건너뛴 항목 수를 누적한다.
+    pub fn add_skipped(&self, n: usize) {
+        self.counters.skipped.fetch_add(n as i32, Ordering::Relaxed);
+    }
+
+    /// 현재까지의 (성공, 실패, 건너뜀) 카운터를 반환한다.
+    fn counts(&self) -> (i32, i32, i32) {
+        (
+            self.counters.success.load(Ordering::Relaxed),
+            self.counters.failure.load(Ordering::Relaxed),
+            self.counters.skipped.load(Ordering::Relaxed),
+        )
+    }
+
+    /// 현재 카운터를 sync_runs 테이블에 반영한다.
+    /// 진행 상황 기록 실패는 동기화 자체를 중단시키지 않는다.
+    pub async fn flush(&self) {
+        let (success, failure, skipped) = self.counts();
+
+        if let Err(e) =
+            update_sync_run_progress(&self.pool, self.run_id, success, failure, skipped).await
+        {
+            tracing::warn!("run {} 진행 상황 기록 실패: {}", self.run_id, e);
+        }
+    }
+
+    /// 동기화 결과에 따라 실행 기록을 종료 상태로 갱신한다.
+    async fn finish(&self, result: &Result<Value, String>) {
+        let (status, summary, error) = match result {
+            Ok(summary) => ("succeeded", Some(summary.to_string()), None),
+            Err(e) => ("failed", None, Some(e.as_str())),
+        };
+
+        if let Err(e) = finish_sync_run(
+            &self.pool,
+            self.run_id,
+            status,
+            self.counts(),
+            summary.as_deref(),
+            error,
+        )
+        .await
+        {
+            tracing::error!("run {} 종료 기록 실패: {}", self.run_id, e);
+        }
+    }
+}
+
+/// 실행 기록을 생성하고 진행 상황 추적기를 반환한다.
+async fn start_run(
+    pool: &PgPool,
+    kind: &SyncKind,
+    params: &Value,
+) -> Result<SyncProgress, String> {
+    let run_id = create_sync_run(pool, kind.as_str(), &params.to_string()).await?;
+
+    tracing::info!("동기화 시작 — run {} ({})", run_id, kind.as_str());
+
+    Ok(SyncProgress {
+        run_id,
+        pool: pool.clone(),
+        counters: Arc::new(Counters::default()),
+    })
+}
+
+/// 작업을 실행하면서 주기적으로 진행 상황을 기록하고,
+/// 완료되면 실행 기록을 종료 상태로 갱신한다.
+async fn drive<Fut>(
+    progress: SyncProgress,
+    job: Fut,
+) -> Result<Value, String>
+where
+    Fut: Future<Output = Result<Value, String>>,
+{
+    tokio::pin!(job);
+    let mut ticker = interval(FLUSH_INTERVAL);
+
+    let result = loop {
+        tokio::select! {
+            res = &mut job => break res,
+            _ = ticker.tick() => progress.flush().await,
+        }
+    };
+
+    progress.finish(&result).await;
+
+    match &result {
+        Ok(_) => tracing::info!("동기화 완료 — run {}", progress.run_id),
+        Err(e) => tracing::error!("동기화 실패 — run {}: {}", progress.run_id, e),
+    }
+
+    result
+}
+
+/// 동기화 작업을 실행 기록과 함께 현재 태스크에서 실행한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `kind`    - 동기화 종류
+/// * `params`  - 실행 파라미터
+/// * `job`     - 진행 상황 추적기를 받아 동기화를 수행하는 작업
+/// 
+/// # Returns
+/// * `Ok(Value)`   - 작업 결과 요약
+/// * `Err(String)` - 실행 기록 생성 실패 또는 작업 실패
+pub async fn run_tracked<F, Fut>(
+    pool: &PgPool,
+    kind: SyncKind,
+    params: Value,
+    job: F,
+) -> Result<Value, String>
+where
+    F: FnOnce(SyncProgress) -> Fut,
+    Fut: Future<Output = Result<Value, String>>,
+{
+    let progress = start_run(pool, &kind, &params).await?;
+    let job = job(progress.clone());
+
+    drive(progress, job).await
+}
+
+/// 동기화 작업을 백그라운드 태스크로 실행하고 실행 ID를 즉시 반환한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `kind`    - 동기화 종류
+/// * `params`  - 실행 파라미터
+/// * `job`     - 진행 상황 추적기를 받아 동기화를 수행하는 작업
+/// 
+/// # Returns
+/// * `Ok(i32)`     - 실행 기록 ID
+/// * `Err(String)` - 실행 기록 생성 실패
+pub async fn spawn_sync_run<F, Fut>(
+    pool: &PgPool,
+    kind: SyncKind,
+    params: Value,
+    job: F,
+) -> Result<i32, String>
+where
+    F: FnOnce(SyncProgress) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<Value, String>> + Send + 'static,
+{
+    let progress = start_run(pool, &kind, &params).await?;
+    let run_id = progress.run_id;
+
+    tokio::spawn(async move {
+        // 작업은 별도 태스크에서 실행해 패닉을 JoinError로 받아낸다.
+        // 그래야 패닉이 나도 실행 기록이 'running'으로 남지 않는다.
+        let handle = tokio::spawn(job(progress.clone()));
+        let job = async move {
+            handle
+                .await
+                .unwrap_or_else(|e| Err(format!("동기화 작업 비정상 종료: {}", e)))
+        };
+        let _ = drive(progress, job).await;
+    });
+
+    Ok(run_id)
+}
+
+/// 종료 기록 없이 중단된 실행 기록을 실패로 정리한다.
+/// 실행 중인 작업은 FLUSH_INTERVAL마다 생존 신호를 갱신하므로,
+/// RUN_STALE_AFTER 동안 갱신이 없는 실행만 프로세스가 중단된 것으로 본다.
+/// 서버 시작 시와 정기 동기화 회차마다 호출하며, 정리 실패는 호출한 작업을 막지 않는다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+pub async fn recover_interrupted_runs(pool: &PgPool) {
+    match fail_interrupted_sync_runs(pool, RUN_STALE_AFTER.as_secs_f64()).await {
+        Ok(0) => {}
+        Ok(n) => tracing::warn!("중단된 동기화 실행 {}건을 실패로 정리", n),
+        Err(e) => tracing::error!("{}", e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn run_status(pool: &PgPool, run_id: i32) -> String {
+        sqlx::query_scalar("SELECT status FROM sync_runs WHERE id = $1")
+            .bind(run_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    #[ignore = "PostgreSQL 필요: DATABASE_URL 설정 후 cargo test -- --ignored"]
+    async fn recovers_only_runs_with_stale_heartbeat(pool: PgPool) {
+        let live = start_run(&pool, &SyncKind::Prices, &serde_json::json!({})).await.unwrap();
+        let orphaned = start_run(&pool, &SyncKind::Prices, &serde_json::json!({})).await.unwrap();
+        sqlx::query("UPDATE sync_runs SET heartbeat_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+            .bind(orphaned.run_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        recover_interrupted_runs(&pool).await;
+        assert_eq!(run_status(&pool, live.run_id).await, "running");
+        assert_eq!(run_status(&pool, orphaned.run_id).await, "failed");
+
+        // 진행 상황을 기록하는 실행은 생존 신호가 갱신되어 정리 대상이 되지 않는다.
+        sqlx::query("UPDATE sync_runs SET heartbeat_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+            .bind(live.run_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        live.flush().await;
+        recover_interrupted_runs(&pool).await;
+        assert_eq!(run_status(&pool, live.run_id).await, "running");
+    }
+}
@@ -0,0 +1,202 @@
+/************************************************************************
+ * File: domain/sync/scheduler.rs
+ * Description:
+ *     server 프로세스 안에서 cron 일정에 따라 정기 동기화를 실행한다.
+ *     여러 인스턴스가 떠 있어도 PostgreSQL advisory lock으로
+ *     한 번에 하나의 파이프라인만 실행되도록 한다.
+ *
+ * Responsibilities:
+ *     1) spawn_sync_scheduler()
+ *         - SYNC_SCHEDULE(KST 기준 cron) 일정에 맞춰 파이프라인을 반복 실행
+ *         - 회차별로 별도 태스크에서 실행하여 패닉이 나도 다음 회차 유지
+ *
+ *     2) run_scheduled_sync()
+ *         - 생존 신호가 끊긴 실행 기록 정리
+ *         - advisory lock 획득 후 파이프라인 실행, 종료 시 잠금 해제
+ *
+ *     3) run_pipeline()
+ *         - 표준코드 → 상품/매장 → 최신 조사일 가격 순서로 동기화 (가격 변동은 가격 동기화 시 자동 생성)
+ *
+ *     4) prune_raw_payloads()
+ *         - 보관 기간(RAW_PAYLOAD_RETENTION_DAYS)이 지난 원본 응답 삭제
+************************************************************************/
+
+use chrono::Utc;
+use cron::Schedule;
+use serde_json::json;
+use sqlx::PgPool;
+use std::str::FromStr;
+
+use crate::{
+    common::{
+        external::data_source::DataSources,
+        repository::{
+            repository_lock::{try_lock, unlock, SYNC_PIPELINE_LOCK_KEY},
+            repository_raw_payload::delete_expired_raw_payloads,
+        },
+    },
+    config::{
+        data_source::load_data_source_config,
+        state::AppState,
+        sync::{kst, load_scheduler_config},
+    },
+    domain::sync::{
+        progress::{recover_interrupted_runs, run_tracked, SyncKind},
+        service,
+    },
+};
+
+/// SYNC_SCHEDULE 설정에 따라 정기 동기화 태스크를 띄운다.
+/// 설정이 없거나 cron 표현식이 올바르지 않으면 스케줄러를 띄우지 않는다.
+///
+/// # Arguments
+/// * `state` - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+pub fn spawn_sync_scheduler(state: AppState) {
+    let config = load_scheduler_config();
+
+    let Some(expr) = config.schedule else {
+        tracing::info!("SYNC_SCHEDULE 미설정 — 정기 동기화 비활성화");
+        return;
+    };
+
+    let schedule = match Schedule::from_str(&expr) {
+        Ok(schedule) => schedule,
+        Err(e) => {
+            tracing::error!("SYNC_SCHEDULE 파싱 실패 ({}): {}", expr, e);
+            return;
+        }
+    };
+
+    tracing::info!("정기 동기화 활성화: {} (KST)", expr);
+
+    let probe_days = config.probe_days;
+
+    tokio::spawn(async move {
+        loop {
+            let Some(next) = schedule.upcoming(kst()).next() else {
+                tracing::warn!("다음 정기 동기화 일정 없음 — 스케줄러 종료");
+                break;
+            };
+            tracing::info!("다음 정기 동기화: {}", next);
+
+            let wait = (next - Utc::now().with_timezone(&kst()))
+                .to_std()
+                .unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            // 회차마다 별도 태스크로 실행해, 한 회차의 패닉이 스케줄러를 멈추지 않게 한다.
+            let tick_state = state.clone();
+            let tick = tokio::spawn(async move {
+                run_scheduled_sync(&tick_state.pool, &tick_state.sources, probe_days).await
+            });
+
+            match tick.await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => tracing::error!("정기 동기화 실패: {}", e),
+                Err(e) => tracing::error!("정기 동기화 비정상 종료: {}", e),
+            }
+        }
+    });
+}
+
+/// advisory lock을 획득한 경우에만 동기화 파이프라인을 실행한다.
+/// 다른 인스턴스가 이미 실행 중이면 이번 회차는 건너뛴다.
+/// 회차마다 먼저 생존 신호가 끊긴 실행 기록을 정리한다.
+///
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `probe_days`  - 최신 조사일 탐색 범위(일)
+///
+/// # Returns
+/// * `Ok(())`      - 실행 완료 또는 다른 인스턴스가 실행 중이라 건너뜀
+/// * `Err(String)` - 잠금 처리 또는 파이프라인 실행 실패
+async fn run_scheduled_sync(
+    pool: &PgPool,
+    sources: &DataSources,
+    probe_days: u32,
+) -> Result<(), String> {
+    recover_interrupted_runs(pool).await;
+
+    let Some(lock) = try_lock(pool, SYNC_PIPELINE_LOCK_KEY).await? else {
+        tracing::info!("다른 인스턴스에서 정기 동기화 실행 중 — 이번 회차 건너뜀");
+        return Ok(());
+    };
+
+    let result = run_pipeline(pool, sources, probe_days).await;
+    prune_raw_payloads(pool).await;
+
+    if let Err(e) = unlock(lock).await {
+        tracing::error!("{}", e);
+    }
+
+    result
+}
+
+/// 표준코드(지역 / 상품분류 / 단위 / 업태) → 상품/매장 → 최신 조사일 가격 순서로 동기화한다.
+/// 각 단계는 sync_runs에 개별 실행 기록으로 남으며, 한 단계가 실패하면 이후 단계는 실행하지 않는다.
+/// 가격 변동은 최신 조사일 가격 동기화 단계에서 함께 생성된다.
+///
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `probe_days`  - 최신 조사일 탐색 범위(일)
+///
+/// # Returns
+/// * `Ok(())`      - 파이프라인 완료
+/// * `Err(String)` - 단계 실행 실패
+async fn run_pipeline(
+    pool: &PgPool,
+    sources: &DataSources,
+    probe_days: u32,
+) -> Result<(), String> {
+    tracing::info!("정기 동기화 시작");
+
+    run_tracked(pool, SyncKind::StandardCodes, json!({ "trigger": "schedule" }), |progress| async move {
+        service::upsert_standard_codes(pool, sources, None, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await?;
+
+    run_tracked(pool, SyncKind::GoodsAndStores, json!({ "trigger": "schedule" }), |progress| async move {
+        service::upsert_good_and_store(pool, sources, &progress)
+            .await
+            .map(|res| json!(res))
+    })
+    .await?;
+
+    run_tracked(
+        pool,
+        SyncKind::Prices,
+        json!({ "latest": true, "trigger": "schedule" }),
+        |progress| async move {
+            service::sync_latest_prices(pool, sources, probe_days, &progress)
+                .await
+                .map(|res| json!(res))
+        },
+    )
+    .await?;
+
+    tracing::info!("정기 동기화 완료");
+
+    Ok(())
+}
+
+/// 보관 기간이 지난 원본 응답(raw_payloads)을 삭제한다.
+/// 보관 기간이 0이면 정리하지 않으며, 정리 실패는 정기 동기화 결과에 영향을 주지 않는다.
+///
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+async fn prune_raw_payloads(pool: &PgPool) {
+    let retention_days = load_data_source_config().retention_days;
+    if retention_days == 0 {
+        return;
+    }
+
+    match delete_expired_raw_payloads(pool, retention_days).await {
+        Ok(0) => {}
+        Ok(n) => tracing::info!("보관 기간({}일)이 지난 원본 응답 {}건 삭제", retention_days, n),
+        Err(e) => tracing::warn!("{}", e),
+    }
+}
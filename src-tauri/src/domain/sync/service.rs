@@ -8,274 +8,1979 @@
  *         - 차례대로 2, 3번 함수를 실행
  * 
  *     2) upsert_good()
- *         - 상품 정보 API 연동 및 DB 반영
+ *         - 상품 정보 API 연동 및 DB 일괄 반영 (전체 페이지 조회)
+ *         - 반영 후 상품 검색 인덱스 재생성
  * 
  *     3) upsert_store()
- *         - 매장 정보 API 연동 및 DB 반영
+ *         - 매장 정보 API 연동 및 DB 일괄 반영 (전체 페이지 조회)
+ *         - 주소별 좌표 캐시(geocode_cache) 재사용
+ *         - 도로명 → 지번 → 업스트림 좌표 순서로 좌표 확보, 모두 실패 시 수동 확인 필요 상태로 저장
  * 
  *     4) upsert_price()
  *         - 특정 조사일 가격 정보 수집 및 저장
+ *         - 매장 단위 동시 요청 (동시 실행 개수 / 초당 요청 수 제한)
+ *         - 저장 성공 시 앞뒤 조사일과의 가격 변동 자동 생성
  * 
- *     5) upsert_region_codes()
- *         - 지역 코드 API 연동 및 DB 반영 
+ *     5) discover_inspect_days() / sync_latest_prices()
+ *         - 표본 매장 조회로 공공데이터에 게시된 조사일 탐색 및 inspect_days 등록
+ *         - 가장 최근 조사일 가격 동기화
+ * 
+ *     6) backfill_prices()
+ *         - 기간 내 조사일별 가격 동기화 (가격 변동은 조사일별 동기화 시 자동 생성)
+ * 
+ *     7) upsert_region_codes()
+ *         - 지역 코드 API 연동 및 DB 반영 (전체 페이지 조회)
+ *         - 상위 지역 코드 연결로 지역 트리를 구성하여 단계(level) 계산, 지역명 변경 갱신
+ * 
+ *     8) reprocess_archived()
+ *         - raw_payloads에 보관된 원본 응답으로 업스트림 호출 없이 DB 재반영
+ * 
+ *     9) get_sync_run()
+ *         - 동기화 실행 기록 및 진행 상황 조회
+ * 
+ *     10) get_quarantine()
+ *         - 검증 실패로 격리된 항목 조회 (동기화 실행 / 사유별)
+ * 
+ *     11) retry_manual_review_geocodes()
+ *         - 수동 확인 필요 상태인 매장의 좌표를 캐시 없이 다시 조회
+ * 
+ *     12) upsert_standard_codes()
+ *         - 분류(지역 / 상품분류 / 단위 / 업태)별 표준코드 API 연동 및 분류별 테이블 반영
 ************************************************************************/
 
-use chrono::Utc;
+use chrono::{Days, NaiveDate, Utc};
 use quick_xml::de::from_str;
+use serde_json::{json, Value};
 use sqlx::PgPool;
-use std::io::{self, Write};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+use tokio::{sync::Semaphore, task::JoinSet};
 
 use crate::{
     common::{
         entity::{
-            entity_good::GoodEntity, entity_price::PriceEntity, entity_region::RegionEntity,
-            entity_store::StoreEntity,
+            entity_geocode_cache::GeocodeCacheEntity, entity_good::GoodEntity, entity_price::PriceEntity, entity_region::RegionEntity,
+            entity_standard_code::StandardCodeEntity, entity_store::StoreEntity,
+            entity_sync_quarantine::SyncQuarantineEntity,
         },
+        geo::coord_transform::upstream_to_wgs84,
         external::{
-            api_public_data::{
-                fetch_goods_api, fetch_prices_api, fetch_region_codes_api, fetch_stores_api,
-            },
-            api_vworld::geocode_with_vworld,
+            api_vworld::AddressType,
+            archive::{content_hash, ArchiveSource},
+            data_source::{DataSources, PriceDataSource},
+            rate_limiter::RateLimiter,
         },
         repository::{
-            repository_good::insert_or_update_good,
-            repository_price::insert_price_to_db,
-            repository_region::insert_region_codes_if_not_exists,
-            repository_store::{get_all_stores_id, insert_or_update_store},
+            repository_geocode_cache::{find_geocode_cache_by_keys, upsert_geocode_cache},
+            repository_good::{deactivate_goods_not_in, insert_or_update_goods_bulk},
+            repository_inspect_day::{
+                find_inspect_days_between, find_latest_inspect_day, finish_inspect_day,
+                insert_inspect_days_if_not_exists, mark_inspect_day_syncing,
+            },
+            repository_price::insert_prices_bulk,
+            repository_raw_payload::count_raw_payloads_by_inspect_day,
+            repository_region::upsert_regions_bulk,
+            repository_standard_code::upsert_standard_codes_bulk,
+            repository_store::{
+                deactivate_stores_not_in, find_active_stores_by_geocode_status, get_active_stores_id,
+                insert_or_update_stores_bulk, sample_active_stores_id, update_store_geocode,
+            },
+            repository_sync_quarantine::{find_quarantine, insert_quarantine_bulk},
+            repository_sync_run::find_sync_run_by_id,
         },
     },
-    domain::sync::dto::{
-        dto_goods_api::ApiResponse as goodApiResponse,
-        dto_prices_api::ApiResponse as priceApiResponse,
-        dto_region_codes_api::ApiResponse as regionCodesApiResponse,
-        dto_stores_api::ApiResponse as storeApiResponse,
+    config::sync::{
+        kst, load_geocode_negative_ttl, load_price_sync_config, load_probe_sample_size,
+        load_public_api_page_size,
+    },
+    domain::{
+        good::search_index::rebuild_search_index,
+        price_change::service::generate_price_change_after_sync,
+        sync::{
+            dto::{
+                dto_goods_api::ApiResponse as goodApiResponse,
+                dto_prices_api::ApiResponse as priceApiResponse,
+                dto_req::{ReprocessTarget, StandardCodeClass},
+                dto_res::{
+                    BackfillDayRes, BackfillRes, GeocodeRetryRes, GoodSyncRes, GoodsAndStoresSyncRes, InspectDayDiscoverRes,
+                    LatestPriceSyncRes, PriceChangePairRes, PriceSyncRes, QuarantineRes, StandardCodeSyncRes,
+                    StoreSyncRes, SyncFailureRes, SyncRunRes,
+                },
+                dto_standard_codes_api::ApiResponse as standardCodesApiResponse,
+                dto_stores_api::ApiResponse as storeApiResponse,
+            },
+            pagination::fetch_all_pages,
+            progress::{run_tracked, SyncKind, SyncProgress},
+            validation::{to_quarantine, validate_good, validate_price, validate_store},
+        },
     },
 };
 
+/// 매장 동기화 시 지오코딩 결과를 중간 저장하는 단위
+const STORE_FLUSH_SIZE: usize = 200;
+
+/// 좌표 캐시에 기록하는 좌표 제공자
+const GEOCODE_PROVIDER: &str = "vworld";
+
+/// 한 번에 백필할 수 있는 최대 일수
+const BACKFILL_MAX_DAYS: i64 = 366;
+
+/// 격리 항목 조회 기본 / 최대 건수
+const QUARANTINE_DEFAULT_LIMIT: i64 = 100;
+const QUARANTINE_MAX_LIMIT: i64 = 1000;
+
 /// 상품 + 매장 데이터를 모두 동기화한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 동기화 완료
-/// * `Err(String)` - 동기화 실패
+/// * `Ok(GoodsAndStoresSyncRes)`   - 동기화 결과
+/// * `Err(String)`                 - 동기화 실패
 pub async fn upsert_good_and_store(
-    pool: &PgPool
-) -> Result<(), String> {
-    if let Err(e) = upsert_good(pool).await {
+    pool: &PgPool,
+    sources: &DataSources,
+    progress: &SyncProgress,
+) -> Result<GoodsAndStoresSyncRes, String> {
+    let goods = upsert_good(pool, sources, progress).await.map_err(|e| {
         tracing::error!("상품 데이터 동기화 실패: {}", e);
-        return Err(format!("상품 데이터 동기화 실패: {}", e));
-    }
+        format!("상품 데이터 동기화 실패: {}", e)
+    })?;
 
-    if let Err(e) = upsert_store(pool).await {
+    let stores = upsert_store(pool, sources, progress).await.map_err(|e| {
         tracing::error!("매장 데이터 동기화 실패: {}", e);
-        return Err(format!("매장 데이터 동기화 실패: {}", e));
-    }
+        format!("매장 데이터 동기화 실패: {}", e)
+    })?;
 
-    Ok(())
+    Ok(GoodsAndStoresSyncRes { goods, stores })
 }
 
 /// 상품 정보를 API로부터 가져와 goods 테이블에 저장/갱신한다.
 /// 
+/// 전체 목록에 없는 상품은 비활성 상태로 표시하며, 다시 나타나면 재활성화된다.
+/// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 완료
-/// * `Err(String)` - 저장 실패
+/// * `Ok(GoodSyncRes)` - 상품 동기화 결과
+/// * `Err(String)`     - 저장 실패
 pub async fn upsert_good(
-    pool: &PgPool
-) -> Result<(), String> {
-    let text = fetch_goods_api().await?;
-    let parsed: goodApiResponse = from_str(&text).map_err(|e| format!("XML 파싱 실패: {}", e))?;
+    pool: &PgPool,
+    sources: &DataSources,
+    progress: &SyncProgress,
+) -> Result<GoodSyncRes, String> {
+    let page_size = load_public_api_page_size();
+    let items = fetch_all_pages::<goodApiResponse, _, _>("상품", page_size, |page_no| {
+        sources.prices.fetch_goods(page_no, page_size)
+    })
+    .await?;
 
-    let mut total_count: i32 = 0;
+    let total_goods = items.len();
+    // 격리된 상품도 업스트림에는 존재하므로 비활성화 대상에서 제외한다.
+    let seen_ids: Vec<String> = items.iter().map(|item| item.good_id.clone()).collect();
+    let mut goods: Vec<GoodEntity> = Vec::with_capacity(total_goods);
+    let mut quarantine: Vec<SyncQuarantineEntity> = Vec::new();
 
-    for item in parsed.result.items {
-        let good = GoodEntity {
-            id: 0, // SERIAL이므로 임시값
-            good_id: item.good_id.clone(),
-            good_name: item.good_name.clone(),
-            total_cnt: item
-                .good_total_cnt
-                .as_ref()
-                .and_then(|s| s.parse::<i32>().ok()),
-            total_div_code: item.good_total_div_code.clone(),
-            created_at: Utc::now().naive_utc(),
-            updated_at: Utc::now().naive_utc(),
-        };
+    for item in items {
+        match validate_good(&item) {
+            Ok(good) => goods.push(good),
+            Err(violation) => quarantine.push(to_quarantine(
+                progress.run_id(),
+                "good",
+                item.good_id.clone(),
+                violation,
+                &item,
+            )),
+        }
+    }
+
+    insert_or_update_goods_bulk(pool, &goods, progress.run_id()).await?;
+    insert_quarantine_bulk(pool, &quarantine).await?;
+    progress.add_success(goods.len());
+    progress.add_skipped(quarantine.len());
 
-        insert_or_update_good(pool, &good).await?;
-        total_count += 1;
+    // 빈 목록은 업스트림 장애일 수 있으므로 전체 비활성화를 막는다.
+    let deactivated = if seen_ids.is_empty() {
+        tracing::warn!("상품 목록이 비어 있어 비활성화를 건너뜀");
+        0
+    } else {
+        deactivate_goods_not_in(pool, &seen_ids, progress.run_id()).await?
+    };
+    tracing::info!(
+        "상품 데이터 {}개 업데이트 완료 (격리: {}개, 비활성화: {}개)",
+        goods.len(),
+        quarantine.len(),
+        deactivated
+    );
+
+    // 검색 인덱스 갱신 실패는 동기화 실패로 보지 않는다. (다음 조회 시 다시 생성)
+    if let Err(e) = rebuild_search_index(pool).await {
+        tracing::warn!("상품 검색 인덱스 재생성 실패: {}", e);
     }
-    tracing::info!("상품 데이터 {}개 업데이트 완료", total_count);
 
-    Ok(())
+    Ok(GoodSyncRes {
+        total_goods,
+        saved_goods: goods.len(),
+        quarantined: quarantine.len(),
+        deactivated,
+    })
 }
 
 /// 매장 정보를 API로부터 가져와 stores 테이블에 저장/갱신한다.
 /// 
+/// 좌표는 도로명 주소 → 지번 주소(type=parcel) → 업스트림 지도 좌표 순서로 구하며,
+/// 모두 실패한 매장도 버리지 않고 수동 확인 필요(manual_review) 상태로 저장한다.
+/// 주소 검색 요청 자체가 실패한 매장은 대체 좌표를 쓰지 않고 기존 좌표와 상태를 유지한다.
+/// 전체 목록에 없는 매장은 비활성 상태로 표시하며, 다시 나타나면 재활성화된다.
+/// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 완료
-/// * `Err(String)` - 저장 실패
+/// * `Ok(StoreSyncRes)`    - 매장 동기화 결과
+/// * `Err(String)`         - 저장 실패
 pub async fn upsert_store(
-    pool: &PgPool
-) -> Result<(), String> {
-    let text = fetch_stores_api().await?;
-    let parsed: storeApiResponse = from_str(&text).map_err(|e| format!("XML 파싱 실패: {}", e))?;
+    pool: &PgPool,
+    sources: &DataSources,
+    progress: &SyncProgress,
+) -> Result<StoreSyncRes, String> {
+    let page_size = load_public_api_page_size();
+    let items = fetch_all_pages::<storeApiResponse, _, _>("매장", page_size, |page_no| {
+        sources.prices.fetch_stores(page_no, page_size)
+    })
+    .await?;
 
-    let mut total_count = 0;
-    let mut success_count = 0;
-    let mut fail_count = 0;
+    let mut res = StoreSyncRes {
+        total_stores: items.len(),
+        geocoded: 0,
+        geocoded_road: 0,
+        geocoded_parcel: 0,
+        geocoded_upstream: 0,
+        manual_review: 0,
+        no_address: 0,
+        quarantined: 0,
+        geocode_cache_hits: 0,
+        geocode_cache_misses: 0,
+        geocode_failed: 0,
+        deactivated: 0,
+    };
+    // 격리된 매장도 업스트림에는 존재하므로 비활성화 대상에서 제외한다.
+    let seen_ids: Vec<String> = items.iter().map(|item| item.entp_id.clone()).collect();
+    let mut buffer: Vec<StoreEntity> = Vec::with_capacity(STORE_FLUSH_SIZE);
 
-    for item in parsed.result.items {
-        if item.road_addr.is_none() && item.jibun_addr.is_none() {
-            continue;
+    // 검증 규칙을 통과하지 못한 매장은 좌표 변환 전에 격리한다.
+    let mut quarantine: Vec<SyncQuarantineEntity> = Vec::new();
+    let items: Vec<_> = items
+        .into_iter()
+        .filter_map(|item| match validate_store(&item) {
+            Ok(()) => Some(item),
+            Err(violation) => {
+                quarantine.push(to_quarantine(
+                    progress.run_id(),
+                    "store",
+                    item.entp_id.clone(),
+                    violation,
+                    &item,
+                ));
+                None
+            }
+        })
+        .collect();
+    res.quarantined = quarantine.len();
+    insert_quarantine_bulk(pool, &quarantine).await?;
+    progress.add_skipped(quarantine.len());
+
+    // 이번 동기화에 필요한 주소의 캐시를 한 번에 불러온다.
+    let mut keys: Vec<String> = Vec::new();
+    for item in &items {
+        if let Some(addr) = non_empty(&item.road_addr) {
+            keys.push(geocode_cache_key(addr, AddressType::Road));
+        }
+        if let Some(addr) = non_empty(&item.jibun_addr) {
+            keys.push(geocode_cache_key(addr, AddressType::Parcel));
+        }
+    }
+    let negative_ttl = load_geocode_negative_ttl();
+    let mut cache: HashMap<String, Option<(f64, f64)>> = find_geocode_cache_by_keys(pool, &keys)
+        .await?
+        .into_iter()
+        .filter(|c| is_geocode_cache_fresh(c, negative_ttl))
+        .map(|c| (c.address_key, c.lat.zip(c.lon)))
+        .collect();
+
+    for item in items {
+        let road_addr = non_empty(&item.road_addr);
+        let jibun_addr = non_empty(&item.jibun_addr);
+        if road_addr.is_none() && jibun_addr.is_none() {
+            res.no_address += 1;
         }
 
-        let addr = item
-            .road_addr
-            .clone()
-            .unwrap_or_else(|| item.jibun_addr.clone().unwrap_or_default());
-
-        let coords = geocode_with_vworld(&addr).await?;
-
-        match coords {
-            Some((lat, lon)) => {
-                success_count += 1;
-                let store = StoreEntity {
-                    id: 0,
-                    store_id: item.entp_id.clone(),
-                    store_name: item.entp_name.clone(),
-                    tel_no: item.tel_no.clone(),
-                    post_no: item.post_no.clone(),
-                    jibun_addr: item.jibun_addr.clone().unwrap_or_default(),
-                    road_addr: item.road_addr.clone().unwrap_or_default(),
-                    x_coord: Some(lat),
-                    y_coord: Some(lon),
-                    created_at: Utc::now().naive_utc(),
-                    updated_at: Utc::now().naive_utc(),
-                    area_code: item.area_code.clone(),
-                    area_detail_code: item.area_detail_code.clone(),
-                };
-                insert_or_update_store(pool, &store).await?;
+        let mut located: Option<((f64, f64), &str)> = None;
+        let mut request_failed = false;
+
+        if let Some(addr) = road_addr {
+            match geocode_with_cache(pool, sources, &mut cache, &mut res, addr, AddressType::Road).await? {
+                GeocodeLookup::Found(coords) => located = Some((coords, "road")),
+                GeocodeLookup::NotFound => {}
+                GeocodeLookup::RequestFailed => request_failed = true,
+            }
+        }
+        if located.is_none() && !request_failed {
+            if let Some(addr) = jibun_addr {
+                match geocode_with_cache(pool, sources, &mut cache, &mut res, addr, AddressType::Parcel).await? {
+                    GeocodeLookup::Found(coords) => located = Some((coords, "parcel")),
+                    GeocodeLookup::NotFound => {}
+                    GeocodeLookup::RequestFailed => request_failed = true,
+                }
+            }
+        }
+        // 주소 검색이 일시적으로 실패한 매장은 업스트림 좌표로 대체하지 않고 기존 좌표를 유지한다.
+        if located.is_none() && !request_failed {
+            located = upstream_to_wgs84(item.x_coord, item.y_coord).map(|coords| (coords, "upstream"));
+        }
+
+        let keep_previous_coords = located.is_none() && request_failed;
+        let (coords, geocode_status) = match located {
+            None if request_failed => {
+                res.geocode_failed += 1;
+                progress.add_failure(1);
+                tracing::warn!("주소 검색 실패로 기존 좌표 유지: {} ({})", item.entp_name, item.entp_id);
+                (None, "manual_review")
+            }
+            Some((coords, status)) => {
+                res.geocoded += 1;
+                match status {
+                    "road" => res.geocoded_road += 1,
+                    "parcel" => res.geocoded_parcel += 1,
+                    _ => res.geocoded_upstream += 1,
+                }
+                progress.add_success(1);
+                (Some(coords), status)
             }
             None => {
-                fail_count += 1;
+                res.manual_review += 1;
+                progress.add_failure(1);
+                tracing::warn!("매장 좌표 변환 실패 — 수동 확인 필요: {} ({})", item.entp_name, item.entp_id);
+                (None, "manual_review")
             }
+        };
+
+        buffer.push(StoreEntity {
+            id: 0,
+            store_id: item.entp_id.clone(),
+            store_name: item.entp_name.clone(),
+            tel_no: item.tel_no.clone(),
+            post_no: item.post_no.clone(),
+            jibun_addr: item.jibun_addr.clone().unwrap_or_default(),
+            road_addr: item.road_addr.clone().unwrap_or_default(),
+            x_coord: coords.map(|c| c.0),
+            y_coord: coords.map(|c| c.1),
+            created_at: Utc::now().naive_utc(),
+            updated_at: Utc::now().naive_utc(),
+            area_code: item.area_code.clone(),
+            area_detail_code: item.area_detail_code.clone(),
+            geocode_status: geocode_status.to_string(),
+            is_active: true,
+            last_seen_at: Utc::now().naive_utc(),
+            store_type_code: non_empty(&item.entp_type_code).map(|c| c.trim().to_string()),
+            keep_previous_coords,
+        });
+
+        // 지오코딩이 오래 걸리므로 일정 개수마다 중간 저장한다.
+        if buffer.len() >= STORE_FLUSH_SIZE {
+            insert_or_update_stores_bulk(pool, &buffer, progress.run_id()).await?;
+            buffer.clear();
         }
-        print!(
-            "\r매장 좌표 지오코딩 중... 성공: {}개 | 실패: {}개",
-            success_count, fail_count
-        );
-        io::stdout().flush().unwrap();
-        total_count += 1;
     }
-    println!();
+    insert_or_update_stores_bulk(pool, &buffer, progress.run_id()).await?;
+    // 빈 목록은 업스트림 장애일 수 있으므로 전체 비활성화를 막는다.
+    if seen_ids.is_empty() {
+        tracing::warn!("매장 목록이 비어 있어 비활성화를 건너뜀");
+    } else {
+        res.deactivated = deactivate_stores_not_in(pool, &seen_ids, progress.run_id()).await?;
+    }
+
     tracing::info!(
-        "매장 데이터 {}개 중 {}개 업데이트 완료",
-        total_count,
-        success_count
+        "매장 데이터 {}개 중 {}개 좌표 확보 (도로명: {}개, 지번: {}개, 업스트림 좌표: {}개, 수동 확인 필요: {}개, 주소 검색 실패: {}개, 격리: {}개, 비활성화: {}개, 좌표 캐시 적중: {}개, 조회: {}개)",
+        res.total_stores,
+        res.geocoded,
+        res.geocoded_road,
+        res.geocoded_parcel,
+        res.geocoded_upstream,
+        res.manual_review,
+        res.geocode_failed,
+        res.quarantined,
+        res.deactivated,
+        res.geocode_cache_hits,
+        res.geocode_cache_misses
     );
 
-    Ok(())
+    Ok(res)
+}
+
+/// 주소 검색 결과
+///
+/// * `Found`           - 좌표 확보 (lat, lon)
+/// * `NotFound`        - 주소 검색 결과 없음
+/// * `RequestFailed`   - 주소 검색 요청 자체가 실패함 (일시적 오류, 캐시에 남기지 않음)
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum GeocodeLookup {
+    Found((f64, f64)),
+    NotFound,
+    RequestFailed,
+}
+
+impl GeocodeLookup {
+    /// 좌표 캐시 값(좌표 또는 결과 없음)을 검색 결과로 변환한다.
+    fn from_cached(coords: Option<(f64, f64)>) -> Self {
+        coords.map_or(GeocodeLookup::NotFound, GeocodeLookup::Found)
+    }
+
+    /// 확보한 좌표를 반환한다.
+    fn coords(self) -> Option<(f64, f64)> {
+        match self {
+            GeocodeLookup::Found(coords) => Some(coords),
+            _ => None,
+        }
+    }
+}
+
+/// 좌표 캐시를 먼저 확인하고, 없으면 주소 검색 후 결과를 캐시에 저장한다.
+/// 주소 검색 요청 자체가 실패하면 RequestFailed를 돌려주며, 캐시에는 남기지 않는다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `sources`         - 업스트림 데이터 소스
+/// * `cache`           - 이번 동기화에서 사용하는 좌표 캐시 (주소 키 → 좌표)
+/// * `res`             - 캐시 적중 / 조회 수를 기록할 매장 동기화 결과
+/// * `addr`            - 좌표를 구할 주소
+/// * `address_type`    - 주소 검색 유형 (도로명 / 지번)
+/// 
+/// # Returns
+/// * `Ok(GeocodeLookup)`   - 좌표 확보 / 결과 없음 / 주소 검색 요청 실패
+/// * `Err(String)`         - 좌표 캐시 저장 실패
+async fn geocode_with_cache(
+    pool: &PgPool,
+    sources: &DataSources,
+    cache: &mut HashMap<String, Option<(f64, f64)>>,
+    res: &mut StoreSyncRes,
+    addr: &str,
+    address_type: AddressType,
+) -> Result<GeocodeLookup, String> {
+    let key = geocode_cache_key(addr, address_type);
+
+    if let Some(coords) = cache.get(&key) {
+        res.geocode_cache_hits += 1;
+        return Ok(GeocodeLookup::from_cached(*coords));
+    }
+    res.geocode_cache_misses += 1;
+
+    let lookup = geocode_and_cache(pool, sources, &key, addr, address_type).await?;
+    if lookup != GeocodeLookup::RequestFailed {
+        cache.insert(key, lookup.coords());
+    }
+
+    Ok(lookup)
+}
+
+/// 캐시를 확인하지 않고 주소 검색을 수행한 뒤 결과를 좌표 캐시에 저장한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `sources`         - 업스트림 데이터 소스
+/// * `key`             - 좌표 캐시 키
+/// * `addr`            - 좌표를 구할 주소
+/// * `address_type`    - 주소 검색 유형 (도로명 / 지번)
+/// 
+/// # Returns
+/// * `Ok(GeocodeLookup)`   - 좌표 확보 / 결과 없음 / 주소 검색 요청 실패 (실패는 캐시에 남기지 않음)
+/// * `Err(String)`         - 좌표 캐시 저장 실패
+async fn geocode_and_cache(
+    pool: &PgPool,
+    sources: &DataSources,
+    key: &str,
+    addr: &str,
+    address_type: AddressType,
+) -> Result<GeocodeLookup, String> {
+    let coords = match sources.geocoder.geocode(addr, address_type).await {
+        Ok(coords) => coords,
+        Err(e) => {
+            tracing::warn!("주소 검색 실패 ({}, type={}): {}", addr, address_type.as_param(), e);
+            return Ok(GeocodeLookup::RequestFailed);
+        }
+    };
+
+    upsert_geocode_cache(pool, key, addr, coords, GEOCODE_PROVIDER).await?;
+
+    Ok(GeocodeLookup::from_cached(coords))
+}
+
+/// 좌표 캐시 키를 생성한다.
+/// 도로명 주소는 정규화된 주소를 그대로, 지번 주소는 "parcel:" 접두어를 붙여 사용한다.
+/// 
+/// # Arguments
+/// * `addr`            - 원본 주소
+/// * `address_type`    - 주소 검색 유형 (도로명 / 지번)
+/// 
+/// # Returns
+/// * `String` - 좌표 캐시 키
+fn geocode_cache_key(
+    addr: &str,
+    address_type: AddressType,
+) -> String {
+    match address_type {
+        AddressType::Road => normalize_address(addr),
+        AddressType::Parcel => format!("parcel:{}", normalize_address(addr)),
+    }
+}
+
+/// 공백뿐인 값(주소, 코드)을 없는 값으로 취급한다.
+fn non_empty(
+    addr: &Option<String>
+) -> Option<&str> {
+    addr.as_deref().filter(|a| !a.trim().is_empty())
+}
+
+/// 좌표 캐시 키로 사용할 수 있도록 주소를 정규화한다.
+/// (앞뒤 공백 제거, 연속 공백을 하나로)
+/// 
+/// # Arguments
+/// * `addr` - 원본 주소
+/// 
+/// # Returns
+/// * `String` - 정규화된 주소
+fn normalize_address(
+    addr: &str
+) -> String {
+    addr.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// 좌표 캐시 항목을 그대로 사용할 수 있는지 판단한다.
+/// 좌표가 있는 항목은 항상 사용하고, 결과 없음 항목은 유효 기간 안에서만 사용한다.
+/// 
+/// # Arguments
+/// * `entry`           - 좌표 캐시 항목
+/// * `negative_ttl`    - 결과 없음 캐시 유효 기간
+/// 
+/// # Returns
+/// * `bool` - 캐시 사용 가능 여부
+fn is_geocode_cache_fresh(
+    entry: &GeocodeCacheEntity,
+    negative_ttl: chrono::Duration,
+) -> bool {
+    match entry.status.as_str() {
+        "found" => entry.lat.is_some() && entry.lon.is_some(),
+        _ => entry.geocoded_at + negative_ttl > Utc::now().naive_utc(),
+    }
 }
 
 /// 특정 조사일 기준 모든 매장의 가격 데이터를 API로부터 가져와 
 /// prices 테이블에 저장/갱신한다.
 /// 
+/// 매장 단위로 동시에 요청하되, 동시 실행 개수와 초당 요청 수는
+/// PRICE_SYNC_CONCURRENCY / PRICE_SYNC_RPS 설정을 따른다.
+/// 개별 매장의 실패는 전체 동기화를 중단하지 않고 결과에 모아서 반환한다.
+/// 동기화 시작과 결과는 inspect_days 테이블의 조사일 상태로도 기록하며,
+/// 가격이 저장된 매장이 있으면 앞뒤 조사일과의 가격 변동을 이어서 생성한다.
+/// 
 /// # Arguments
 /// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
 /// * `inspect_day` - 조사일(YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 완료
-/// * `Err(String)` - 저장 실패
+/// * `Ok(PriceSyncRes)`    - 동기화 결과 (매장별 실패 내역 포함)
+/// * `Err(String)`         - 잘못된 조사일 또는 매장 목록 조회 실패
 pub async fn upsert_price(
     pool: &PgPool, 
-    inspect_day: &str
-) -> Result<(), String> {
-    let store_ids = get_all_stores_id(pool).await?;
+    sources: &DataSources,
+    inspect_day: &str,
+    progress: &SyncProgress,
+) -> Result<PriceSyncRes, String> {
+    validate_inspect_day(inspect_day)?;
+    mark_inspect_day_syncing(pool, inspect_day, progress.run_id()).await?;
+
+    let result = upsert_day_prices(pool, sources, inspect_day, progress).await;
+
+    let (status, saved_prices, failed_stores) = match &result {
+        Ok(res) => (inspect_day_status(res), res.saved_prices, res.failures.len()),
+        Err(_) => ("failed", 0, 0),
+    };
+
+    if let Err(e) = finish_inspect_day(
+        pool,
+        inspect_day,
+        status,
+        saved_prices as i32,
+        failed_stores as i32,
+    )
+    .await
+    {
+        tracing::warn!("조사일 {} 상태 기록 실패: {}", inspect_day, e);
+    }
+
+    let mut res = result?;
+    if res.success_stores > 0 {
+        res.price_changes = generate_price_change_after_sync(pool, inspect_day).await;
+    }
+
+    Ok(res)
+}
+
+/// 가격 동기화 결과로부터 inspect_days 테이블에 기록할 조사일 상태를 결정한다.
+/// 
+/// # Arguments
+/// * `res` - 가격 동기화 결과
+/// 
+/// # Returns
+/// * `&str` - "synced" | "partial" | "failed" | "no_data"
+fn inspect_day_status(
+    res: &PriceSyncRes
+) -> &'static str {
+    match (res.success_stores, res.failures.len()) {
+        (0, 0) => "no_data",
+        (0, _) => "failed",
+        (_, 0) => "synced",
+        _ => "partial",
+    }
+}
+
+/// 특정 조사일 기준 모든 활성 매장의 가격 데이터를 매장 단위로 동시에 저장한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(PriceSyncRes)`    - 동기화 결과 (매장별 실패 내역 포함)
+/// * `Err(String)`         - 매장 목록 조회 실패
+async fn upsert_day_prices(
+    pool: &PgPool, 
+    sources: &DataSources,
+    inspect_day: &str,
+    progress: &SyncProgress,
+) -> Result<PriceSyncRes, String> {
+    let store_ids = get_active_stores_id(pool).await?;
+    let config = load_price_sync_config();
 
-    let mut total_count = 0;
-    let mut success_count = 0;
+    let semaphore = Arc::new(Semaphore::new(config.concurrency));
+    let limiter = Arc::new(RateLimiter::new(config.requests_per_second));
+    let mut tasks = JoinSet::new();
 
     for store_id in store_ids {
-        let text = fetch_prices_api(inspect_day, &store_id).await?;
+        let run_id = progress.run_id();
+        let pool = pool.clone();
+        let source = sources.prices.clone();
+        let inspect_day = inspect_day.to_string();
+        let semaphore = Arc::clone(&semaphore);
+        let limiter = Arc::clone(&limiter);
+
+        tasks.spawn(async move {
+            // 동시 실행 개수 제한 후 초당 요청 수 제한 순서로 대기
+            let _permit = semaphore.acquire_owned().await;
+            limiter.acquire().await;
+
+            let result =
+                upsert_store_price(&pool, source.as_ref(), &inspect_day, &store_id, run_id).await;
+            (store_id, result)
+        });
+    }
+
+    let mut res = PriceSyncRes {
+        inspect_day: inspect_day.to_string(),
+        total_stores: tasks.len(),
+        success_stores: 0,
+        skipped_stores: 0,
+        saved_prices: 0,
+        quarantined: 0,
+        failures: Vec::new(),
+        price_changes: Vec::new(),
+    };
+
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((_, Ok(Some((saved, quarantined))))) => {
+                res.success_stores += 1;
+                res.saved_prices += saved;
+                res.quarantined += quarantined;
+                progress.add_success(1);
+            }
+            Ok((store_id, Ok(None))) => {
+                tracing::warn!("조사 데이터 없음 — store_id {}", store_id);
+                res.skipped_stores += 1;
+                progress.add_skipped(1);
+            }
+            Ok((store_id, Err(e))) => {
+                tracing::warn!("가격 데이터 동기화 실패 — store_id {}: {}", store_id, e);
+                progress.add_failure(1);
+                res.failures.push(SyncFailureRes {
+                    store_id,
+                    message: e,
+                });
+            }
+            Err(e) => {
+                tracing::error!("가격 동기화 작업 실행 실패: {}", e);
+                progress.add_failure(1);
+                res.failures.push(SyncFailureRes {
+                    store_id: String::from("-"),
+                    message: format!("작업 실행 실패: {}", e),
+                });
+            }
+        }
+    }
+
+    tracing::info!(
+        "가격 데이터 {}개 업데이트 완료 (격리: {}개, 매장 성공: {}개, 데이터 없음: {}개, 실패: {}개)",
+        res.saved_prices,
+        res.quarantined,
+        res.success_stores,
+        res.skipped_stores,
+        res.failures.len()
+    );
+
+    Ok(res)
+}
+
+/// 한 매장의 특정 조사일 가격 데이터를 API로부터 가져와 저장한다.
+/// 검증 규칙을 통과하지 못한 항목은 sync_quarantine 테이블로 격리한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `source`      - 가격 정보 데이터 소스
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// * `store_id`    - 매장 ID
+/// * `run_id`      - 격리 항목에 기록할 동기화 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(Some((usize, usize)))` - (저장된 가격 데이터 수, 격리된 가격 데이터 수)
+/// * `Ok(None)`        - 해당 매장의 조사 데이터 없음
+/// * `Err(String)`     - 요청, 파싱 또는 저장 실패
+async fn upsert_store_price(
+    pool: &PgPool,
+    source: &dyn PriceDataSource,
+    inspect_day: &str,
+    store_id: &str,
+    run_id: i32,
+) -> Result<Option<(usize, usize)>, String> {
+    let text = source.fetch_prices(inspect_day, store_id).await?.body;
+
+    if !text.contains("goodPriceVO") {
+        return Ok(None);
+    }
+
+    let parsed: priceApiResponse = from_str(&text).map_err(|e| {
+        format!(
+            "XML 파싱 실패 (store_id {}, sha256 {}): {}",
+            store_id,
+            content_hash(&text),
+            e
+        )
+    })?;
+
+    let mut prices: Vec<PriceEntity> = Vec::with_capacity(parsed.result.items.len());
+    let mut quarantine: Vec<SyncQuarantineEntity> = Vec::new();
+
+    for item in parsed.result.items {
+        match validate_price(&item, inspect_day, store_id) {
+            Ok(price) => prices.push(price),
+            Err(violation) => quarantine.push(to_quarantine(
+                run_id,
+                "price",
+                format!("{}:{}:{}", inspect_day, store_id, item.good_id),
+                violation,
+                &item,
+            )),
+        }
+    }
+
+    insert_prices_bulk(pool, &prices).await?;
+    insert_quarantine_bulk(pool, &quarantine).await?;
+
+    Ok(Some((prices.len(), quarantine.len())))
+}
+
+/// 오늘(KST)부터 하루씩 거슬러 올라가며 표본 매장의 가격 데이터를 조회하여
+/// 공공데이터에 게시된 조사일을 찾아 inspect_days 테이블에 등록한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `probe_days`  - 오늘부터 거슬러 올라갈 최대 일수
+/// * `progress`    - 동기화 진행 상황 추적기 (새로 발견한 날짜: 성공, 그 외: 건너뜀)
+/// 
+/// # Returns
+/// * `Ok(InspectDayDiscoverRes)`   - 탐색 결과
+/// * `Err(String)`                 - 매장 목록 조회, API 요청 또는 등록 실패
+pub async fn discover_inspect_days(
+    pool: &PgPool,
+    sources: &DataSources,
+    probe_days: u32,
+    progress: &SyncProgress,
+) -> Result<InspectDayDiscoverRes, String> {
+    let res = probe_inspect_days(pool, sources, probe_days).await?;
+
+    progress.add_success(res.discovered.len());
+    progress.add_skipped(res.probed_days - res.discovered.len());
+
+    Ok(res)
+}
+
+/// 게시된 조사일을 탐색한 뒤, 가장 최근 조사일의 가격 데이터를 동기화한다.
+/// 가장 최근 조사일이 이미 실패 없이 동기화되어 있으면 건너뛴다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `probe_days`  - 오늘부터 거슬러 올라갈 최대 일수
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(LatestPriceSyncRes)`  - 최신 조사일 동기화 결과
+/// * `Err(String)`             - 탐색 또는 동기화 실패
+pub async fn sync_latest_prices(
+    pool: &PgPool,
+    sources: &DataSources,
+    probe_days: u32,
+    progress: &SyncProgress,
+) -> Result<LatestPriceSyncRes, String> {
+    probe_inspect_days(pool, sources, probe_days).await?;
+
+    let Some(latest) = find_latest_inspect_day(pool).await? else {
+        tracing::warn!("최근 {}일 안에 게시된 조사일 없음", probe_days);
+        return Ok(LatestPriceSyncRes {
+            inspect_day: None,
+            already_synced: false,
+            prices: None,
+        });
+    };
+
+    if latest.status == "synced" {
+        tracing::info!("조사일 {} 가격 데이터가 이미 동기화됨", latest.inspect_day);
+        return Ok(LatestPriceSyncRes {
+            inspect_day: Some(latest.inspect_day),
+            already_synced: true,
+            prices: None,
+        });
+    }
+
+    let prices = upsert_price(pool, sources, &latest.inspect_day, progress).await?;
+
+    Ok(LatestPriceSyncRes {
+        inspect_day: Some(latest.inspect_day),
+        already_synced: false,
+        prices: Some(prices),
+    })
+}
+
+/// 오늘(KST)부터 거슬러 올라가며 아직 등록되지 않은 날짜만 표본 매장으로 조회하고,
+/// 조사 데이터가 있는 날짜를 inspect_days 테이블에 등록한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `probe_days`  - 오늘부터 거슬러 올라갈 최대 일수
+/// 
+/// # Returns
+/// * `Ok(InspectDayDiscoverRes)`   - 탐색 결과
+/// * `Err(String)`                 - 매장 목록 조회, API 요청 또는 등록 실패
+async fn probe_inspect_days(
+    pool: &PgPool,
+    sources: &DataSources,
+    probe_days: u32,
+) -> Result<InspectDayDiscoverRes, String> {
+    let samples = sample_store_ids(pool).await?;
+    if samples.is_empty() {
+        return Err(String::from("매장 데이터 없음 — 상품/매장 동기화를 먼저 실행해야 함"));
+    }
+
+    let today = Utc::now().with_timezone(&kst()).date_naive();
+    let first_day = today
+        .checked_sub_days(Days::new(probe_days as u64))
+        .unwrap_or(today);
+
+    let from = first_day.format("%Y%m%d").to_string();
+    let to = today.format("%Y%m%d").to_string();
+
+    let known: HashSet<String> = find_inspect_days_between(pool, &from, &to)
+        .await?
+        .into_iter()
+        .map(|d| d.inspect_day)
+        .collect();
+
+    let limiter = RateLimiter::new(load_price_sync_config().requests_per_second);
+    let mut found: Vec<String> = Vec::new();
+    let mut probed_days = 0;
+
+    for date in first_day.iter_days().take_while(|d| *d <= today) {
+        let day = date.format("%Y%m%d").to_string();
+        probed_days += 1;
+
+        if known.contains(&day) {
+            continue;
+        }
+
+        if has_inspect_data(sources.prices.as_ref(), &day, &samples, &limiter).await? {
+            found.push(day);
+        }
+    }
+
+    let discovered = insert_inspect_days_if_not_exists(pool, &found).await?;
+    tracing::info!(
+        "조사일 탐색 완료: {} ~ {} (새로 발견 {}일)",
+        from,
+        to,
+        discovered.len()
+    );
+
+    Ok(InspectDayDiscoverRes {
+        from,
+        to,
+        probed_days,
+        discovered,
+    })
+}
+
+/// 조사일 탐색에 사용할 표본 매장 ID 목록을 조회한다.
+/// 활성 매장 중 지역별로 고르게 무작위 선택하며, 표본 수는 SYNC_PROBE_SAMPLE_STORES로 설정한다.
+/// 
+/// # Arguments
+/// * `pool` - DB 커넥션 풀
+/// 
+/// # Returns
+/// * `Ok(Vec<String>)` - 표본 매장 ID 목록
+/// * `Err(String)`     - 매장 목록 조회 실패
+async fn sample_store_ids(
+    pool: &PgPool
+) -> Result<Vec<String>, String> {
+    sample_active_stores_id(pool, load_probe_sample_size()).await
+}
+
+/// 표본 매장 중 한 곳이라도 해당 조사일의 가격 데이터가 있는지 확인한다.
+/// 
+/// # Arguments
+/// * `source`      - 가격 정보 데이터 소스
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// * `samples`     - 표본 매장 ID 목록
+/// * `limiter`     - 공공데이터 API 요청 속도 제한기
+/// 
+/// # Returns
+/// * `Ok(bool)`    - 조사 데이터 존재 여부
+/// * `Err(String)` - API 요청 실패
+async fn has_inspect_data(
+    source: &dyn PriceDataSource,
+    inspect_day: &str,
+    samples: &[String],
+    limiter: &RateLimiter,
+) -> Result<bool, String> {
+    for store_id in samples {
+        limiter.acquire().await;
+        let text = source.fetch_prices(inspect_day, store_id).await?.body;
+
+        if text.contains("goodPriceVO") {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// 기간 내의 모든 조사일 가격 데이터를 동기화한다.
+/// 
+/// inspect_days 테이블에 등록되지 않은 날짜는 표본 매장으로 조사 데이터 존재 여부를 먼저 확인하고,
+/// 데이터가 있는 날짜는 개별 가격 동기화 실행 기록(parent_run_id 포함)으로 남긴다.
+/// 실패 매장 없이 이미 동기화된 날짜("synced")는 건너뛰며,
+/// 가격 변동은 날짜별 가격 동기화가 끝날 때마다 앞뒤 조사일 기준으로 생성된 결과를 모아 반환한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `from`        - 시작 조사일(YYYYMMDD)
+/// * `to`          - 종료 조사일(YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기 (날짜 단위로 누적)
+/// 
+/// # Returns
+/// * `Ok(BackfillRes)` - 날짜별 / 조사일 쌍별 결과
+/// * `Err(String)`     - 잘못된 기간 또는 매장 목록 조회 실패
+pub async fn backfill_prices(
+    pool: &PgPool,
+    sources: &DataSources,
+    from: &str,
+    to: &str,
+    progress: &SyncProgress,
+) -> Result<BackfillRes, String> {
+    let from_date = parse_inspect_day(from)?;
+    let to_date = parse_inspect_day(to)?;
+
+    if from_date > to_date {
+        return Err(format!("시작일({})이 종료일({})보다 늦음", from, to));
+    }
+    if (to_date - from_date).num_days() >= BACKFILL_MAX_DAYS {
+        return Err(format!("백필 기간은 최대 {}일까지 가능", BACKFILL_MAX_DAYS));
+    }
+
+    // 실패 없이 동기화된 날짜는 건너뛰고, 이미 게시가 확인된 날짜는 조회를 생략한다.
+    let known_days = find_inspect_days_between(pool, from, to).await?;
+    let completed: HashSet<String> = known_days
+        .iter()
+        .filter(|d| d.status == "synced")
+        .map(|d| d.inspect_day.clone())
+        .collect();
+    let published: HashSet<String> = known_days
+        .iter()
+        .filter(|d| d.status != "no_data")
+        .map(|d| d.inspect_day.clone())
+        .collect();
+
+    let samples = sample_store_ids(pool).await?;
+    if samples.is_empty() {
+        return Err(String::from("매장 데이터 없음 — 상품/매장 동기화를 먼저 실행해야 함"));
+    }
+
+    let limiter = RateLimiter::new(load_price_sync_config().requests_per_second);
+
+    let mut res = BackfillRes {
+        from: from.to_string(),
+        to: to.to_string(),
+        days: Vec::new(),
+        no_data_days: 0,
+        price_changes: Vec::new(),
+    };
+
+    for date in from_date.iter_days().take_while(|d| *d <= to_date) {
+        let day = date.format("%Y%m%d").to_string();
 
-        if !text.contains("goodPriceVO") {
-            tracing::warn!("조사 데이터 없음 — store_id {}", store_id);
+        if completed.contains(&day) {
+            progress.add_skipped(1);
+            res.days.push(BackfillDayRes {
+                inspect_day: day.clone(),
+                status: String::from("already_loaded"),
+                run_id: None,
+                saved_prices: 0,
+                message: None,
+            });
+            progress.flush().await;
             continue;
         }
 
-        let parsed: priceApiResponse =
-            from_str(&text).map_err(|e| format!("XML 파싱 실패 (store_id {}): {}", store_id, e))?;
+        let has_data = if published.contains(&day) {
+            Ok(true)
+        } else {
+            has_inspect_data(sources.prices.as_ref(), &day, &samples, &limiter).await
+        };
 
-        for item in parsed.result.items {
-            if item.good_price.trim().is_empty() {
+        match has_data {
+            Ok(true) => {}
+            Ok(false) => {
+                res.no_data_days += 1;
+                progress.add_skipped(1);
+                progress.flush().await;
+                continue;
+            }
+            Err(e) => {
+                tracing::warn!("조사일 {} 데이터 확인 실패: {}", day, e);
+                progress.add_failure(1);
+                res.days.push(BackfillDayRes {
+                    inspect_day: day,
+                    status: String::from("failed"),
+                    run_id: None,
+                    saved_prices: 0,
+                    message: Some(e),
+                });
+                progress.flush().await;
                 continue;
             }
+        }
 
-            let price = PriceEntity {
-                id: 0,
-                good_id: item.good_id.clone(),
-                store_id: item.entp_id.clone(),
-                inspect_day: item.good_inspect_day.clone(),
-                price: item.good_price.parse::<i32>().unwrap_or(0),
-                is_one_plus_one: item.plus_one_yn.unwrap_or_else(|| "N".to_string()),
-                is_discount: item.good_dc_yn.unwrap_or_else(|| "N".to_string()),
-                discount_start: item.good_dc_start_day.clone(),
-                discount_end: item.good_dc_end_day.clone(),
-                created_at: Utc::now().naive_utc(),
-            };
-
-            insert_price_to_db(pool, &price).await?;
-
-            total_count += 1;
+        let (day_res, price_changes) = backfill_day(pool, sources, &day, progress.run_id()).await;
+
+        if day_res.status == "synced" {
+            progress.add_success(1);
+        } else {
+            progress.add_failure(1);
         }
-        success_count += 1;
+        res.days.push(day_res);
+        res.price_changes.extend(price_changes);
+        progress.flush().await;
+    }
 
-        print!("\r가격 데이터 갱신 중... 성공: {}개", success_count);
-        io::stdout().flush().unwrap();
+    tracing::info!(
+        "가격 데이터 백필 완료: {} ~ {} (조사일 {}개, 데이터 없음 {}일)",
+        from,
+        to,
+        res.days.len(),
+        res.no_data_days
+    );
+
+    Ok(res)
+}
+
+/// 백필 대상 하루의 가격 데이터를 개별 실행 기록과 함께 동기화한다.
+/// 
+/// # Arguments
+/// * `pool`            - DB 커넥션 풀
+/// * `sources`         - 업스트림 데이터 소스
+/// * `inspect_day`     - 조사일(YYYYMMDD)
+/// * `parent_run_id`   - 백필 실행 기록 ID
+/// 
+/// # Returns
+/// * `(BackfillDayRes, Vec<PriceChangePairRes>)` - (날짜별 결과 ("synced" | "partial" | "failed"), 동기화 후 생성된 가격 변동 결과)
+async fn backfill_day(
+    pool: &PgPool,
+    sources: &DataSources,
+    inspect_day: &str,
+    parent_run_id: i32,
+) -> (BackfillDayRes, Vec<PriceChangePairRes>) {
+    let params = json!({
+        "inspect_day": inspect_day,
+        "trigger": "backfill",
+        "parent_run_id": parent_run_id,
+    });
+
+    let mut run_id: Option<i32> = None;
+    let mut synced: Option<PriceSyncRes> = None;
+    let (run_slot, synced_slot) = (&mut run_id, &mut synced);
+
+    let result = run_tracked(pool, SyncKind::Prices, params, |child| async move {
+        *run_slot = Some(child.run_id());
+        let res = upsert_price(pool, sources, inspect_day, &child).await?;
+        let summary = json!(res);
+        *synced_slot = Some(res);
+
+        Ok(summary)
+    })
+    .await;
+
+    let res = match (result, synced) {
+        (Ok(_), Some(res)) => res,
+        (Err(e), _) => {
+            return (
+                BackfillDayRes {
+                    inspect_day: inspect_day.to_string(),
+                    status: String::from("failed"),
+                    run_id,
+                    saved_prices: 0,
+                    message: Some(e),
+                },
+                Vec::new(),
+            );
+        }
+        (Ok(_), None) => {
+            return (
+                BackfillDayRes {
+                    inspect_day: inspect_day.to_string(),
+                    status: String::from("failed"),
+                    run_id,
+                    saved_prices: 0,
+                    message: Some(String::from("동기화 결과 없음")),
+                },
+                Vec::new(),
+            );
+        }
+    };
+
+    let failed_stores = res.failures.len();
+
+    (
+        BackfillDayRes {
+            inspect_day: inspect_day.to_string(),
+            status: String::from(if failed_stores == 0 { "synced" } else { "partial" }),
+            run_id,
+            saved_prices: res.saved_prices,
+            message: (failed_stores > 0).then(|| format!("{}개 매장 동기화 실패", failed_stores)),
+        },
+        res.price_changes,
+    )
+}
+
+/// 조사일 문자열(YYYYMMDD)을 날짜로 변환한다.
+/// 
+/// # Arguments
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(NaiveDate)`   - 변환된 날짜
+/// * `Err(String)`     - 형식 오류
+fn parse_inspect_day(
+    inspect_day: &str
+) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(inspect_day, "%Y%m%d")
+        .map_err(|e| format!("조사일 형식 오류 ({}): {}", inspect_day, e))
+}
+
+/// 가격 동기화를 요청한 조사일이 YYYYMMDD 형식의 오늘(KST) 이전 날짜인지 검증한다.
+/// 잘못된 값이 inspect_days에 기록되어 최신 조사일로 잡히지 않도록 기록 전에 호출한다.
+/// 
+/// # Arguments
+/// * `inspect_day` - 조사일(YYYYMMDD)
+/// 
+/// # Returns
+/// * `Ok(())`      - 유효한 조사일
+/// * `Err(String)` - 형식 오류 또는 미래 날짜
+pub fn validate_inspect_day(
+    inspect_day: &str
+) -> Result<(), String> {
+    if inspect_day.len() != 8 || !inspect_day.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("조사일은 YYYYMMDD 형식이어야 합니다: {}", inspect_day));
     }
+    let date = parse_inspect_day(inspect_day)?;
 
-    tracing::info!("가격 데이터 {}개 업데이트 완료", total_count);
+    let today = Utc::now().with_timezone(&kst()).date_naive();
+    if date > today {
+        return Err(format!("미래 날짜는 조사일로 사용할 수 없습니다: {}", inspect_day));
+    }
 
     Ok(())
 }
 
 /// 지역 코드를 API로부터 가져와 regions 테이블에 저장/갱신한다.
+/// 단계(level)는 상위 지역 코드 연결을 최상위 지역까지 따라가며 깊이에 제한 없이 계산하고,
+/// 이미 저장된 지역은 지역명 / 상위 지역 / 단계 변경을 갱신한다.
 /// 
 /// # Arguments
-/// * `pool` - DB 커넥션 풀
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `progress`    - 동기화 진행 상황 추적기
 /// 
 /// # Returns
-/// * `Ok(())`      - 저장 완료
+/// * `Ok(usize)`   - 저장된 지역 코드 수
 /// * `Err(String)` - 저장 실패
-pub async fn upsert_region_codes(pool: &PgPool) -> Result<(), String> {
-    let text = fetch_region_codes_api().await?;
-    let parsed: regionCodesApiResponse =
-        from_str(&text).map_err(|e| format!("XML 파싱 실패: {}", e))?;
+pub async fn upsert_region_codes(
+    pool: &PgPool,
+    sources: &DataSources,
+    progress: &SyncProgress,
+) -> Result<usize, String> {
+    let page_size = load_public_api_page_size();
+    let class = StandardCodeClass::Region;
+    let items = fetch_all_pages::<standardCodesApiResponse, _, _>(class.label(), page_size, |page_no| {
+        sources.prices.fetch_standard_codes(class.class_code(), page_no, page_size)
+    })
+    .await?;
 
-    let mut total_count: i32 = 0;
+    let regions = build_region_tree(
+        items
+            .into_iter()
+            .map(|item| (item.code, item.code_name, item.high_code))
+            .collect(),
+    );
 
-    for item in parsed.result.items {
-        let level: i16 = if item.high_code == "020000000" { 1 } else { 2 };
-        let region = RegionEntity {
-            code: item.code.clone(),
-            name: item.code_name.clone(),
-            parent_code: Some(item.high_code.clone()),
-            level,
+    let changed = upsert_regions_bulk(pool, &regions).await?;
+    progress.add_success(regions.len());
+    tracing::info!("지역코드 데이터 {}개 업데이트 완료 (신규 / 변경: {}개)", regions.len(), changed);
+
+    Ok(regions.len())
+}
+
+/// (코드, 지역명, 상위 코드) 목록으로부터 지역 트리를 구성한다.
+/// 
+/// 상위 코드가 비어 있거나, 자기 자신이거나, 목록에 없는 지역은 최상위(level 1) 지역으로 보고
+/// 나머지는 상위 지역을 따라 올라간 깊이로 단계를 정한다.
+/// 순환 참조가 있으면 순환이 시작되는 지역을 최상위 지역으로 끊는다.
+/// 상위 지역이 먼저 저장되도록 단계 → 코드 순으로 정렬하여 반환한다.
+/// 
+/// # Arguments
+/// * `items` - (코드, 지역명, 상위 코드) 목록
+/// 
+/// # Returns
+/// * `Vec<RegionEntity>` - 단계 / 상위 지역이 정해진 지역 목록
+fn build_region_tree(
+    items: Vec<(String, String, String)>
+) -> Vec<RegionEntity> {
+    // 같은 코드가 여러 번 오면 마지막 항목을 사용한다.
+    let mut parents: HashMap<String, (String, Option<String>)> = HashMap::new();
+    for (code, name, high_code) in items {
+        let code = code.trim().to_string();
+        let high_code = high_code.trim().to_string();
+        if code.is_empty() {
+            continue;
+        }
+        let parent = (!high_code.is_empty() && high_code != code).then_some(high_code);
+        parents.insert(code, (name.trim().to_string(), parent));
+    }
+
+    // 목록에 없는 상위 코드는 끊는다.
+    let known: HashSet<String> = parents.keys().cloned().collect();
+    for (_, parent) in parents.values_mut() {
+        if parent.as_ref().is_some_and(|p| !known.contains(p)) {
+            *parent = None;
+        }
+    }
+
+    // 순환 참조를 끊는다. 자기 자신으로 돌아오는 지역의 상위 연결을 제거한다.
+    let mut codes: Vec<String> = known.into_iter().collect();
+    codes.sort();
+    for code in &codes {
+        let mut visited: HashSet<String> = HashSet::new();
+        let mut current = parents[code].1.clone();
+        while let Some(parent) = current {
+            if parent == *code {
+                tracing::warn!("지역코드 순환 참조 — {} 를 최상위 지역으로 처리", code);
+                if let Some(entry) = parents.get_mut(code) {
+                    entry.1 = None;
+                }
+                break;
+            }
+            // 자기 자신이 포함되지 않은 순환은 해당 지역 차례에서 끊는다.
+            if !visited.insert(parent.clone()) {
+                break;
+            }
+            current = parents[&parent].1.clone();
+        }
+    }
+
+    let mut regions: Vec<RegionEntity> = parents
+        .iter()
+        .map(|(code, (name, parent))| {
+            let mut level: i16 = 1;
+            let mut current = parent.as_ref();
+            while let Some(p) = current {
+                level += 1;
+                current = parents[p].1.as_ref();
+            }
+
+            RegionEntity {
+                code: code.clone(),
+                name: name.clone(),
+                parent_code: parent.clone(),
+                level,
+            }
+        })
+        .collect();
+
+    regions.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.code.cmp(&b.code)));
+    regions
+}
+
+/// raw_payloads에 보관된 원본 응답만으로 동기화를 다시 실행한다.
+/// 업스트림을 호출하지 않으므로, 피드 형식 변경 분석이나 잘못된 마이그레이션 이후 복구에 사용한다.
+/// 
+/// 요청 종류.파라미터별로 가장 최근에 보관된 응답을 사용하며,
+/// 목록 API는 현재 PUBLIC_API_PAGE_SIZE 설정과 같은 페이지 크기로 보관된 응답이 있어야 한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `target`      - 재처리 대상 (goods, stores, region_codes, standard_codes, prices)
+/// * `inspect_day` - 가격 재처리 시 조사일 (YYYYMMDD)
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(Value)`   - 대상별 동기화 결과
+/// * `Err(String)` - 보관된 응답 없음 또는 재처리 실패
+pub async fn reprocess_archived(
+    pool: &PgPool,
+    target: ReprocessTarget,
+    inspect_day: Option<&str>,
+    progress: &SyncProgress,
+) -> Result<Value, String> {
+    let archive = Arc::new(ArchiveSource::new(pool.clone()));
+    let sources = DataSources {
+        prices: archive.clone(),
+        geocoder: archive,
+    };
+
+    match target {
+        ReprocessTarget::Goods => upsert_good(pool, &sources, progress)
+            .await
+            .map(|res| json!(res)),
+        ReprocessTarget::Stores => upsert_store(pool, &sources, progress)
+            .await
+            .map(|res| json!(res)),
+        ReprocessTarget::RegionCodes => upsert_region_codes(pool, &sources, progress)
+            .await
+            .map(|count| json!({ "saved_regions": count })),
+        ReprocessTarget::StandardCodes => upsert_standard_codes(pool, &sources, None, progress)
+            .await
+            .map(|res| json!(res)),
+        ReprocessTarget::Prices => {
+            let inspect_day =
+                inspect_day.ok_or_else(|| "가격 재처리에는 inspect_day가 필요합니다.".to_string())?;
+            parse_inspect_day(inspect_day)?;
+
+            if count_raw_payloads_by_inspect_day(pool, inspect_day).await? == 0 {
+                return Err(format!("{} 조사일의 보관된 가격 응답 없음", inspect_day));
+            }
+
+            upsert_price(pool, &sources, inspect_day, progress)
+                .await
+                .map(|res| json!(res))
+        }
+    }
+}
+
+/// 동기화 실행 기록과 진행 상황을 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `run_id`  - 실행 기록 ID
+/// 
+/// # Returns
+/// * `Ok(Some(SyncRunRes))`    - 조회 성공
+/// * `Ok(None)`                - 해당 실행 기록 없음
+/// * `Err(String)`             - 조회 실패
+pub async fn get_sync_run(
+    pool: &PgPool,
+    run_id: i32,
+) -> Result<Option<SyncRunRes>, String> {
+    let run = find_sync_run_by_id(pool, run_id).await?;
+
+    Ok(run.map(|r| SyncRunRes {
+        id: r.id,
+        kind: r.kind,
+        params: serde_json::from_str(&r.params).unwrap_or_default(),
+        status: r.status,
+        started_at: r.started_at,
+        finished_at: r.finished_at,
+        success_count: r.success_count,
+        failure_count: r.failure_count,
+        skipped_count: r.skipped_count,
+        summary: r.summary.and_then(|s| serde_json::from_str(&s).ok()),
+        error: r.error,
+    }))
+}
+
+/// 검증 실패로 격리된 항목을 최신순으로 조회한다.
+/// 
+/// # Arguments
+/// * `pool`    - DB 커넥션 풀
+/// * `run_id`  - 동기화 실행 기록 ID (None이면 전체)
+/// * `reason`  - 격리 사유 코드 (None이면 전체)
+/// * `entity`  - 항목 종류 (None이면 전체)
+/// * `limit`   - 최대 조회 건수 (기본값 100, 최대 1000)
+/// 
+/// # Returns
+/// * `Ok(Vec<QuarantineRes>)`  - 격리 항목 목록
+/// * `Err(String)`             - 조회 실패
+pub async fn get_quarantine(
+    pool: &PgPool,
+    run_id: Option<i32>,
+    reason: Option<&str>,
+    entity: Option<&str>,
+    limit: Option<i64>,
+) -> Result<Vec<QuarantineRes>, String> {
+    let limit = limit
+        .unwrap_or(QUARANTINE_DEFAULT_LIMIT)
+        .clamp(1, QUARANTINE_MAX_LIMIT);
+    let rows = find_quarantine(pool, run_id, reason, entity, limit).await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| QuarantineRes {
+            id: r.id,
+            run_id: r.run_id,
+            entity: r.entity,
+            record_key: r.record_key,
+            reason: r.reason,
+            detail: r.detail,
+            payload: serde_json::from_str(&r.payload).unwrap_or_default(),
+            created_at: r.created_at,
+        })
+        .collect())
+}
+
+/// 수동 확인 필요(manual_review) 상태인 활성 매장의 좌표를 다시 구한다.
+/// 실패 기록(음성 캐시)을 무시하고 도로명 → 지번 주소 순서로 새로 조회하며,
+/// 좌표를 찾은 매장은 좌표와 상태를 갱신하고 결과를 캐시에 반영한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(GeocodeRetryRes)` - 재시도 결과
+/// * `Err(String)`         - 조회 / 저장 실패
+pub async fn retry_manual_review_geocodes(
+    pool: &PgPool,
+    sources: &DataSources,
+    progress: &SyncProgress,
+) -> Result<GeocodeRetryRes, String> {
+    let stores = find_active_stores_by_geocode_status(pool, "manual_review").await?;
+    let mut res = GeocodeRetryRes {
+        total_stores: stores.len(),
+        geocoded_road: 0,
+        geocoded_parcel: 0,
+        manual_review: 0,
+    };
+
+    for store in stores {
+        let mut located: Option<((f64, f64), &str)> = None;
+
+        if !store.road_addr.trim().is_empty() {
+            let key = geocode_cache_key(&store.road_addr, AddressType::Road);
+            located = geocode_and_cache(pool, sources, &key, &store.road_addr, AddressType::Road)
+                .await?
+                .coords()
+                .map(|coords| (coords, "road"));
+        }
+        if located.is_none() && !store.jibun_addr.trim().is_empty() {
+            let key = geocode_cache_key(&store.jibun_addr, AddressType::Parcel);
+            located = geocode_and_cache(pool, sources, &key, &store.jibun_addr, AddressType::Parcel)
+                .await?
+                .coords()
+                .map(|coords| (coords, "parcel"));
+        }
+
+        match located {
+            Some((coords, status)) => {
+                update_store_geocode(pool, &store.store_id, coords, status, progress.run_id()).await?;
+                match status {
+                    "road" => res.geocoded_road += 1,
+                    _ => res.geocoded_parcel += 1,
+                }
+                progress.add_success(1);
+            }
+            None => {
+                res.manual_review += 1;
+                progress.add_failure(1);
+            }
+        }
+    }
+
+    tracing::info!(
+        "매장 좌표 재시도 {}개 중 도로명 {}개, 지번 {}개 확보 (수동 확인 필요: {}개)",
+        res.total_stores,
+        res.geocoded_road,
+        res.geocoded_parcel,
+        res.manual_review
+    );
+
+    Ok(res)
+}
+
+/// 표준코드 API로부터 분류별 코드를 가져와 분류별 테이블에 저장/갱신한다.
+/// 분류를 지정하지 않으면 지역코드를 먼저, 이어서 상품분류 / 단위 / 업태 코드를 차례로 동기화한다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `class`       - 동기화할 표준코드 분류 (없으면 전체)
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(Vec<StandardCodeSyncRes>)`    - 분류별 저장 결과
+/// * `Err(String)`                     - 조회 또는 저장 실패
+pub async fn upsert_standard_codes(
+    pool: &PgPool,
+    sources: &DataSources,
+    class: Option<StandardCodeClass>,
+    progress: &SyncProgress,
+) -> Result<Vec<StandardCodeSyncRes>, String> {
+    let classes = match class {
+        Some(class) => vec![class],
+        None => StandardCodeClass::ALL.to_vec(),
+    };
+
+    let mut results = Vec::with_capacity(classes.len());
+    for class in classes {
+        let saved = match class {
+            StandardCodeClass::Region => upsert_region_codes(pool, sources, progress).await?,
+            _ => upsert_standard_code_class(pool, sources, class, progress).await?,
         };
 
-        insert_region_codes_if_not_exists(pool, &region).await?;
-        total_count += 1;
+        results.push(StandardCodeSyncRes {
+            class,
+            class_code: class.class_code().to_string(),
+            saved,
+        });
+        progress.flush().await;
     }
-    tracing::info!("지역코드 데이터 {}개 업데이트 완료", total_count);
 
-    Ok(())
+    Ok(results)
+}
+
+/// 지역코드를 제외한 한 분류의 표준코드를 가져와 저장한다.
+/// 코드 또는 코드명이 비어 있는 항목은 건너뛴다.
+/// 
+/// # Arguments
+/// * `pool`        - DB 커넥션 풀
+/// * `sources`     - 업스트림 데이터 소스
+/// * `class`       - 표준코드 분류
+/// * `progress`    - 동기화 진행 상황 추적기
+/// 
+/// # Returns
+/// * `Ok(usize)`   - 저장된 코드 수
+/// * `Err(String)` - 조회 또는 저장 실패
+async fn upsert_standard_code_class(
+    pool: &PgPool,
+    sources: &DataSources,
+    class: StandardCodeClass,
+    progress: &SyncProgress,
+) -> Result<usize, String> {
+    let page_size = load_public_api_page_size();
+    let items = fetch_all_pages::<standardCodesApiResponse, _, _>(class.label(), page_size, |page_no| {
+        sources.prices.fetch_standard_codes(class.class_code(), page_no, page_size)
+    })
+    .await?;
+
+    let mut codes: Vec<StandardCodeEntity> = Vec::with_capacity(items.len());
+    for item in items {
+        let code = item.code.trim();
+        let name = item.code_name.trim();
+        if code.is_empty() || name.is_empty() {
+            tracing::warn!("{} 항목 건너뜀 — 코드 또는 코드명 없음: {:?}", class.label(), item);
+            progress.add_skipped(1);
+            continue;
+        }
+
+        let parent_code = item.high_code.trim();
+        codes.push(StandardCodeEntity {
+            code: code.to_string(),
+            name: name.to_string(),
+            parent_code: (!parent_code.is_empty() && parent_code != code).then(|| parent_code.to_string()),
+        });
+    }
+
+    upsert_standard_codes_bulk(pool, class, &codes).await?;
+    progress.add_success(codes.len());
+    tracing::info!("{} 데이터 {}개 업데이트 완료", class.label(), codes.len());
+
+    Ok(codes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::external::fixture::{FixtureStore, ReplaySource},
+        domain::sync::progress::{run_tracked, SyncKind},
+    };
+    use std::path::{Path, PathBuf};
+
+    const INSPECT_DAY: &str = "20250103";
+
+    const GOODS_XML: &str = "<response><result>\
+        <totalCount>2</totalCount>\
+        <item><goodId>101</goodId><goodName>서울우유 1L</goodName><goodTotalCnt>1</goodTotalCnt></item>\
+        <item><goodId>102</goodId><goodName>  </goodName></item>\
+        </result></response>";
+
+    const PRICES_XML: &str = "<response><result>\
+        <iros.openapi.service.vo.goodPriceVO>\
+        <goodInspectDay>20250103</goodInspectDay><entpId>900</entpId><goodId>101</goodId>\
+        <goodPrice>2980</goodPrice><plusoneYn>N</plusoneYn><goodDcYn>N</goodDcYn>\
+        </iros.openapi.service.vo.goodPriceVO>\
+        <iros.openapi.service.vo.goodPriceVO>\
+        <goodInspectDay>20250103</goodInspectDay><entpId>900</entpId><goodId>102</goodId>\
+        <goodPrice>0</goodPrice>\
+        </iros.openapi.service.vo.goodPriceVO>\
+        </result></response>";
+
+    /// 테스트별 녹화 디렉터리에 상품 / 가격 응답을 기록한다. (매장 901의 가격 응답은 녹화하지 않음)
+    fn write_fixtures(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("storerader-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("goods")).unwrap();
+        std::fs::create_dir_all(dir.join("prices")).unwrap();
+        std::fs::write(dir.join("goods").join("1.xml"), GOODS_XML).unwrap();
+        std::fs::write(dir.join("prices").join(format!("{}_900.xml", INSPECT_DAY)), PRICES_XML).unwrap();
+        dir
+    }
+
+    fn replay_sources(dir: &Path, missing_prices_empty: bool) -> DataSources {
+        let replay = Arc::new(ReplaySource::new(FixtureStore::new(dir), missing_prices_empty));
+        DataSources {
+            prices: replay.clone(),
+            geocoder: replay,
+        }
+    }
+
+    async fn insert_stores(pool: &PgPool) {
+        sqlx::query(
+            "
+            INSERT INTO stores (store_id, store_name, jibun_addr, road_addr, area_code, area_detail_code)
+            VALUES
+                ('900', '녹화 매장', '', '', '020000000', '020100000'),
+                ('901', '미녹화 매장', '', '', '020000000', '020100000')
+            ",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn replay_goods(pool: &PgPool, sources: &DataSources) -> Value {
+        run_tracked(pool, SyncKind::GoodsAndStores, json!({}), |progress| async move {
+            upsert_good(pool, sources, &progress).await.map(|res| json!(res))
+        })
+        .await
+        .unwrap()
+    }
+
+    async fn replay_prices(pool: &PgPool, sources: &DataSources) -> Value {
+        run_tracked(pool, SyncKind::Prices, json!({}), |progress| async move {
+            upsert_price(pool, sources, INSPECT_DAY, &progress).await.map(|res| json!(res))
+        })
+        .await
+        .unwrap()
+    }
+
+    fn region_rows(items: &[(&str, &str, &str)]) -> Vec<(String, String, String)> {
+        items
+            .iter()
+            .map(|(code, name, high)| (code.to_string(), name.to_string(), high.to_string()))
+            .collect()
+    }
+
+    fn region_levels(regions: &[RegionEntity]) -> Vec<(&str, Option<&str>, i16)> {
+        regions
+            .iter()
+            .map(|r| (r.code.as_str(), r.parent_code.as_deref(), r.level))
+            .collect()
+    }
+
+    #[test]
+    fn inspect_day_must_be_past_yyyymmdd() {
+        assert!(validate_inspect_day("20250103").is_ok());
+        let today = Utc::now().with_timezone(&kst()).date_naive();
+        assert!(validate_inspect_day(&today.format("%Y%m%d").to_string()).is_ok());
+
+        for bad in ["abc", "", "2025013", "202501031", "2025-01-03", "20250230", "+2025013"] {
+            assert!(validate_inspect_day(bad).is_err(), "{}", bad);
+        }
+        let tomorrow = today + chrono::Duration::days(1);
+        assert!(validate_inspect_day(&tomorrow.format("%Y%m%d").to_string()).is_err());
+        assert!(validate_inspect_day("20991231").is_err());
+    }
+
+    #[test]
+    fn region_tree_orders_parents_first() {
+        let regions = build_region_tree(region_rows(&[
+            ("020101000", "종로구", "020100000"),
+            ("020100000", "서울특별시", " "),
+            ("020101010", "청운효자동", "020101000"),
+            ("020200000", "부산광역시", ""),
+        ]));
+
+        assert_eq!(
+            region_levels(&regions),
+            vec![
+                ("020100000", None, 1),
+                ("020200000", None, 1),
+                ("020101000", Some("020100000"), 2),
+                ("020101010", Some("020101000"), 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn region_tree_detaches_orphans_and_self_parents() {
+        let regions = build_region_tree(region_rows(&[
+            ("A", "고아 지역", "MISSING"),
+            ("B", "자기 참조", "B"),
+            ("C", "하위", "A"),
+            ("  ", "빈 코드", "A"),
+            ("D", "이전 이름", "A"),
+            ("D", " 마지막 이름 ", ""),
+        ]));
+
+        assert_eq!(
+            region_levels(&regions),
+            vec![("A", None, 1), ("B", None, 1), ("D", None, 1), ("C", Some("A"), 2)]
+        );
+        assert_eq!(regions[2].name, "마지막 이름");
+    }
+
+    #[test]
+    fn region_tree_breaks_cycles() {
+        // A → B → C → A 순환과, 순환에 매달린 D
+        let regions = build_region_tree(region_rows(&[
+            ("A", "가", "B"),
+            ("B", "나", "C"),
+            ("C", "다", "A"),
+            ("D", "라", "C"),
+        ]));
+
+        assert_eq!(
+            region_levels(&regions),
+            vec![("A", None, 1), ("C", Some("A"), 2), ("B", Some("C"), 3), ("D", Some("C"), 3)]
+        );
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    #[ignore = "PostgreSQL 필요: DATABASE_URL 설정 후 cargo test -- --ignored"]
+    async fn replay_upserts_goods_and_prices_and_fails_unrecorded_store(pool: PgPool) {
+        let dir = write_fixtures("replay-strict");
+        let sources = replay_sources(&dir, false);
+
+        let goods = replay_goods(&pool, &sources).await;
+        assert_eq!(goods["total_goods"], 2);
+        assert_eq!(goods["saved_goods"], 1);
+        assert_eq!(goods["quarantined"], 1);
+
+        insert_stores(&pool).await;
+        let prices = replay_prices(&pool, &sources).await;
+        assert_eq!(prices["success_stores"], 1);
+        assert_eq!(prices["saved_prices"], 1);
+        assert_eq!(prices["quarantined"], 1);
+        assert_eq!(prices["skipped_stores"], 0);
+        assert_eq!(prices["failures"][0]["store_id"], "901");
+
+        let saved: i32 = sqlx::query_scalar(
+            "SELECT price FROM prices WHERE good_id = '101' AND store_id = '900' AND inspect_day = $1",
+        )
+        .bind(INSPECT_DAY)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(saved, 2980);
+
+        let status: String = sqlx::query_scalar("SELECT status FROM inspect_days WHERE inspect_day = $1")
+            .bind(INSPECT_DAY)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(status, "partial");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    #[ignore = "PostgreSQL 필요: DATABASE_URL 설정 후 cargo test -- --ignored"]
+    async fn replay_treats_unrecorded_store_as_no_data_when_enabled(pool: PgPool) {
+        let dir = write_fixtures("replay-lenient");
+        let sources = replay_sources(&dir, true);
+
+        replay_goods(&pool, &sources).await;
+        insert_stores(&pool).await;
+        let prices = replay_prices(&pool, &sources).await;
+
+        assert_eq!(prices["success_stores"], 1);
+        assert_eq!(prices["skipped_stores"], 1);
+        assert_eq!(prices["failures"].as_array().map(Vec::len), Some(0));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    #[ignore = "PostgreSQL 필요: DATABASE_URL 설정 후 cargo test -- --ignored"]
+    async fn geocode_request_failure_keeps_existing_coords(pool: PgPool) {
+        // 주소 검색 응답을 녹화하지 않아 모든 주소 검색 요청이 실패한다.
+        let dir = write_fixtures("geocode-failure");
+        std::fs::create_dir_all(dir.join("stores")).unwrap();
+        std::fs::write(
+            dir.join("stores").join("1.xml"),
+            "<response><result><totalCount>2</totalCount>\
+            <iros.openapi.service.vo.entpInfoVO>\
+            <entpId>900</entpId><entpName>기존 매장</entpName>\
+            <roadAddrBasic>서울특별시 중구 세종대로 110</roadAddrBasic>\
+            <xMapCoord>127.0</xMapCoord><yMapCoord>37.0</yMapCoord>\
+            <entpAreaCode>020000000</entpAreaCode><areaDetailCode>020100000</areaDetailCode>\
+            </iros.openapi.service.vo.entpInfoVO>\
+            <iros.openapi.service.vo.entpInfoVO>\
+            <entpId>902</entpId><entpName>신규 매장</entpName>\
+            <roadAddrBasic>부산광역시 연제구 중앙대로 1001</roadAddrBasic>\
+            <xMapCoord>129.0</xMapCoord><yMapCoord>35.0</yMapCoord>\
+            <entpAreaCode>020000000</entpAreaCode><areaDetailCode>020100000</areaDetailCode>\
+            </iros.openapi.service.vo.entpInfoVO>\
+            </result></response>",
+        )
+        .unwrap();
+        let sources = replay_sources(&dir, false);
+
+        sqlx::query(
+            "
+            INSERT INTO stores (store_id, store_name, jibun_addr, road_addr, x_coord, y_coord, area_code, area_detail_code, geocode_status)
+            VALUES ('900', '기존 매장', '', '서울특별시 중구 세종대로 110', 37.5663, 126.9779, '020000000', '020100000', 'road')
+            ",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let res = run_tracked(&pool, SyncKind::GoodsAndStores, json!({}), |progress| {
+            let (pool, sources) = (&pool, &sources);
+            async move { upsert_store(pool, sources, &progress).await.map(|res| json!(res)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(res["geocode_failed"], 2);
+        assert_eq!(res["geocoded_upstream"], 0);
+        assert_eq!(res["manual_review"], 0);
+
+        let rows: Vec<(String, Option<f64>, Option<f64>, String)> = sqlx::query_as(
+            "SELECT store_id, x_coord, y_coord, geocode_status FROM stores ORDER BY store_id",
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(rows[0], ("900".to_string(), Some(37.5663), Some(126.9779), "road".to_string()));
+        assert_eq!(rows[1], ("902".to_string(), None, None, "manual_review".to_string()));
+
+        let cached: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM geocode_cache")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(cached, 0);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[sqlx::test(migrations = "../migrations")]
+    #[ignore = "PostgreSQL 필요: DATABASE_URL 설정 후 cargo test -- --ignored"]
+    async fn geocode_not_found_clears_existing_coords(pool: PgPool) {
+        // 주소 검색 결과 없음이 캐시되어 있고 업스트림 좌표도 없어 모든 대체 수단이 결과 없음으로 끝난다.
+        let dir = write_fixtures("geocode-not-found");
+        std::fs::create_dir_all(dir.join("stores")).unwrap();
+        std::fs::write(
+            dir.join("stores").join("1.xml"),
+            "<response><result><totalCount>1</totalCount>\
+            <iros.openapi.service.vo.entpInfoVO>\
+            <entpId>900</entpId><entpName>기존 매장</entpName>\
+            <roadAddrBasic>서울특별시 중구 없는대로 1</roadAddrBasic>\
+            <entpAreaCode>020000000</entpAreaCode><areaDetailCode>020100000</areaDetailCode>\
+            </iros.openapi.service.vo.entpInfoVO>\
+            </result></response>",
+        )
+        .unwrap();
+        let sources = replay_sources(&dir, false);
+
+        sqlx::query(
+            "
+            INSERT INTO stores (store_id, store_name, jibun_addr, road_addr, x_coord, y_coord, area_code, area_detail_code, geocode_status)
+            VALUES ('900', '기존 매장', '', '서울특별시 중구 세종대로 110', 37.5663, 126.9779, '020000000', '020100000', 'road')
+            ",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "
+            INSERT INTO geocode_cache (address_key, address, status, lat, lon, provider)
+            VALUES ('서울특별시 중구 없는대로 1', '서울특별시 중구 없는대로 1', 'not_found', NULL, NULL, 'vworld')
+            ",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let res = run_tracked(&pool, SyncKind::GoodsAndStores, json!({}), |progress| {
+            let (pool, sources) = (&pool, &sources);
+            async move { upsert_store(pool, sources, &progress).await.map(|res| json!(res)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(res["geocode_failed"], 0);
+        assert_eq!(res["manual_review"], 1);
+
+        let row: (Option<f64>, Option<f64>, String) = sqlx::query_as(
+            "SELECT x_coord, y_coord, geocode_status FROM stores WHERE store_id = '900'",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(row, (None, None, "manual_review".to_string()));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -0,0 +1,469 @@
+/************************************************************************
+ * File: domain/sync/validation.rs
+ * Description:
+ *     공공데이터 API 항목(가격 / 상품 / 매장)을 DB에 쓰기 전에 검증한다.
+ *     규칙을 통과하지 못한 항목은 저장하거나 버리지 않고
+ *     사유와 원본을 담아 sync_quarantine 테이블로 격리한다.
+ *
+ * Responsibilities:
+ *     1) validate_price()
+ *         - 가격 항목 검증 및 PriceEntity 변환
+ * 
+ *     2) validate_good()
+ *         - 상품 항목 검증 및 GoodEntity 변환
+ * 
+ *     3) validate_store()
+ *         - 매장 항목 검증 (좌표 변환 전 단계)
+ * 
+ *     4) to_quarantine()
+ *         - 검증 실패 항목을 격리 엔터티로 변환
+************************************************************************/
+
+use chrono::{NaiveDate, Utc};
+use serde::Serialize;
+
+use crate::{
+    common::entity::{
+        entity_good::GoodEntity, entity_price::PriceEntity,
+        entity_sync_quarantine::SyncQuarantineEntity,
+    },
+    domain::sync::dto::{
+        dto_goods_api::ApiItem as GoodApiItem, dto_prices_api::ApiItem as PriceApiItem,
+        dto_stores_api::ApiItem as StoreApiItem,
+    },
+};
+
+/// 검증 규칙 위반 내역
+///
+/// # Fields
+/// * `reason`  - 위반 사유 코드 (격리 항목 조회 필터로 사용)
+/// * `detail`  - 위반 상세 내용
+#[derive(Debug)]
+pub struct Violation {
+    pub reason: &'static str,
+    pub detail: String,
+}
+
+impl Violation {
+    fn new(reason: &'static str, detail: String) -> Self {
+        Self { reason, detail }
+    }
+}
+
+/// 가격 항목을 검증하고 PriceEntity로 변환한다.
+/// 
+/// 규칙
+/// - 상품 ID / 매장 ID가 비어 있지 않고 컬럼 길이(10자) 이내
+/// - 조사일 / 매장 ID가 요청한 값과 일치
+/// - 가격은 비어 있지 않은 1 이상의 정수
+/// - 1+1 / 할인 여부는 Y 또는 N
+/// - 할인 시작일 / 종료일은 YYYYMMDD 형식이며 시작일 <= 종료일
+/// 
+/// # Arguments
+/// * `item`        - 가격정보 API 항목
+/// * `inspect_day` - 요청한 조사일 (YYYYMMDD)
+/// * `store_id`    - 요청한 매장 ID
+/// 
+/// # Returns
+/// * `Ok(PriceEntity)` - 검증 통과
+/// * `Err(Violation)`  - 규칙 위반
+pub fn validate_price(
+    item: &PriceApiItem,
+    inspect_day: &str,
+    store_id: &str,
+) -> Result<PriceEntity, Violation> {
+    check_id("goodId", &item.good_id, 10)?;
+    check_id("entpId", &item.entp_id, 10)?;
+
+    if item.good_inspect_day.trim() != inspect_day || item.entp_id.trim() != store_id {
+        return Err(Violation::new(
+            "mismatched_key",
+            format!(
+                "요청({} / {})과 응답({} / {})의 조사일 / 매장이 다름",
+                inspect_day, store_id, item.good_inspect_day, item.entp_id
+            ),
+        ));
+    }
+
+    let raw_price = item.good_price.trim();
+    if raw_price.is_empty() {
+        return Err(Violation::new("missing_price", "가격 없음".to_string()));
+    }
+    let price = raw_price
+        .parse::<i32>()
+        .map_err(|_| Violation::new("invalid_price", format!("숫자가 아닌 가격: {}", raw_price)))?;
+    if price <= 0 {
+        return Err(Violation::new("invalid_price", format!("0 이하 가격: {}", price)));
+    }
+
+    let is_one_plus_one = check_yn("plusoneYn", item.plus_one_yn.as_deref())?;
+    let is_discount = check_yn("goodDcYn", item.good_dc_yn.as_deref())?;
+
+    let discount_start = check_optional_day("goodDcStartDay", item.good_dc_start_day.as_deref())?;
+    let discount_end = check_optional_day("goodDcEndDay", item.good_dc_end_day.as_deref())?;
+    if let (Some((start, start_day)), Some((end, end_day))) = (&discount_start, &discount_end) {
+        if start_day > end_day {
+            return Err(Violation::new(
+                "invalid_discount_period",
+                format!("할인 시작일({})이 종료일({})보다 늦음", start, end),
+            ));
+        }
+    }
+
+    Ok(PriceEntity {
+        id: 0,
+        good_id: item.good_id.trim().to_string(),
+        store_id: item.entp_id.trim().to_string(),
+        inspect_day: inspect_day.to_string(),
+        price,
+        is_one_plus_one,
+        is_discount,
+        discount_start: discount_start.map(|(day, _)| day),
+        discount_end: discount_end.map(|(day, _)| day),
+        created_at: Utc::now().naive_utc(),
+    })
+}
+
+/// 상품 항목을 검증하고 GoodEntity로 변환한다.
+/// 
+/// 규칙
+/// - 상품 ID가 비어 있지 않고 컬럼 길이(20자) 이내
+/// - 상품명이 비어 있지 않고 100자 이내
+/// - 총 개수는 값이 있으면 정수
+/// - 구분 코드는 값이 있으면 5자 이내
+/// - 상품 소분류 / 단위 코드는 값이 있으면 20자 이내
+/// 
+/// # Arguments
+/// * `item` - 상품정보 API 항목
+/// 
+/// # Returns
+/// * `Ok(GoodEntity)`  - 검증 통과
+/// * `Err(Violation)`  - 규칙 위반
+pub fn validate_good(
+    item: &GoodApiItem
+) -> Result<GoodEntity, Violation> {
+    check_id("goodId", &item.good_id, 20)?;
+    check_text("goodName", &item.good_name, 100)?;
+
+    let total_cnt = match item.good_total_cnt.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(cnt) => Some(cnt.parse::<i32>().map_err(|_| {
+            Violation::new("invalid_total_cnt", format!("숫자가 아닌 총 개수: {}", cnt))
+        })?),
+    };
+
+    if let Some(code) = &item.good_total_div_code {
+        check_length("goodTotalDivCode", code, 5)?;
+    }
+    let category_code = check_optional_code("goodSmlclsCode", item.good_smlcls_code.as_deref())?;
+    let unit_code = check_optional_code("goodUnitDivCode", item.good_unit_div_code.as_deref())?;
+
+    Ok(GoodEntity {
+        id: 0, // SERIAL이므로 임시값
+        good_id: item.good_id.trim().to_string(),
+        good_name: item.good_name.trim().to_string(),
+        total_cnt,
+        total_div_code: item.good_total_div_code.clone(),
+        created_at: Utc::now().naive_utc(),
+        updated_at: Utc::now().naive_utc(),
+        is_active: true,
+        last_seen_at: Utc::now().naive_utc(),
+        category_code,
+        unit_code,
+    })
+}
+
+/// 매장 항목을 검증한다.
+/// 좌표를 구하지 못한 매장은 격리하지 않고 수동 확인 필요(manual_review) 상태로 저장한다.
+/// 
+/// 규칙
+/// - 매장 ID가 비어 있지 않은 10자 이내의 숫자 (매장 목록은 숫자 순으로 정렬)
+/// - 매장명이 비어 있지 않고 100자 이내
+/// - 전화번호 20자, 우편번호 10자, 주소 255자 이내
+/// - 지역 코드가 비어 있지 않음
+/// - 업태 코드는 값이 있으면 20자 이내
+/// 
+/// # Arguments
+/// * `item` - 매장정보 API 항목
+/// 
+/// # Returns
+/// * `Ok(())`          - 검증 통과
+/// * `Err(Violation)`  - 규칙 위반
+pub fn validate_store(
+    item: &StoreApiItem
+) -> Result<(), Violation> {
+    check_id("entpId", &item.entp_id, 10)?;
+    if !item.entp_id.trim().chars().all(|c| c.is_ascii_digit()) {
+        return Err(Violation::new(
+            "invalid_id",
+            format!("entpId가 숫자가 아님: {}", item.entp_id),
+        ));
+    }
+
+    check_text("entpName", &item.entp_name, 100)?;
+
+    if let Some(tel_no) = &item.tel_no {
+        check_length("entpTelno", tel_no, 20)?;
+    }
+    if let Some(post_no) = &item.post_no {
+        check_length("postNo", post_no, 10)?;
+    }
+    if let Some(addr) = &item.road_addr {
+        check_length("roadAddrBasic", addr, 255)?;
+    }
+    if let Some(addr) = &item.jibun_addr {
+        check_length("plmkAddrBasic", addr, 255)?;
+    }
+
+    if item.area_code.trim().is_empty() {
+        return Err(Violation::new("missing_area_code", "지역 코드 없음".to_string()));
+    }
+
+    check_optional_code("entpTypeCode", item.entp_type_code.as_deref())?;
+
+    Ok(())
+}
+
+/// 검증 실패 항목을 격리 엔터티로 변환한다.
+/// 
+/// # Arguments
+/// * `run_id`      - 동기화 실행 기록 ID
+/// * `entity`      - 항목 종류 (price, good, store)
+/// * `record_key`  - 항목 식별 값
+/// * `violation`   - 위반 내역
+/// * `payload`     - 원본 API 항목
+/// 
+/// # Returns
+/// * `SyncQuarantineEntity` - 격리 엔터티
+pub fn to_quarantine<T: Serialize>(
+    run_id: i32,
+    entity: &str,
+    record_key: String,
+    violation: Violation,
+    payload: &T,
+) -> SyncQuarantineEntity {
+    SyncQuarantineEntity {
+        id: 0,
+        run_id: Some(run_id),
+        entity: entity.to_string(),
+        record_key,
+        reason: violation.reason.to_string(),
+        detail: Some(violation.detail),
+        payload: serde_json::to_string(payload).unwrap_or_else(|_| "{}".to_string()),
+        created_at: Utc::now().naive_utc(),
+    }
+}
+
+/// ID 값이 비어 있지 않고 최대 길이 이내인지 확인한다.
+fn check_id(field: &str, value: &str, max_len: usize) -> Result<(), Violation> {
+    if value.trim().is_empty() {
+        return Err(Violation::new("invalid_id", format!("{} 없음", field)));
+    }
+    if value.trim().chars().count() > max_len {
+        return Err(Violation::new(
+            "invalid_id",
+            format!("{} 길이 초과 ({}자 초과): {}", field, max_len, value),
+        ));
+    }
+    Ok(())
+}
+
+/// 필수 문자열 값이 비어 있지 않고 최대 길이 이내인지 확인한다.
+fn check_text(field: &str, value: &str, max_len: usize) -> Result<(), Violation> {
+    if value.trim().is_empty() {
+        return Err(Violation::new("missing_name", format!("{} 없음", field)));
+    }
+    check_length(field, value, max_len)
+}
+
+/// 문자열 값이 최대 길이 이내인지 확인한다.
+fn check_length(field: &str, value: &str, max_len: usize) -> Result<(), Violation> {
+    if value.chars().count() > max_len {
+        return Err(Violation::new(
+            "too_long",
+            format!("{} 길이 초과 ({}자 초과)", field, max_len),
+        ));
+    }
+    Ok(())
+}
+
+/// 표준코드 값을 확인한다. 값이 없거나 비어 있으면 None을 반환한다.
+fn check_optional_code(field: &str, value: Option<&str>) -> Result<Option<String>, Violation> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(code) => {
+            check_length(field, code, 20)?;
+            Ok(Some(code.to_string()))
+        }
+    }
+}
+
+/// Y/N 값을 확인한다. 값이 없으면 N으로 본다.
+fn check_yn(field: &str, value: Option<&str>) -> Result<String, Violation> {
+    match value.map(str::trim) {
+        None | Some("") => Ok("N".to_string()),
+        Some(v @ ("Y" | "N")) => Ok(v.to_string()),
+        Some(v) => Err(Violation::new(
+            "invalid_flag",
+            format!("{} 값이 Y/N이 아님: {}", field, v),
+        )),
+    }
+}
+
+/// YYYYMMDD 날짜 값을 확인한다. 값이 없으면 None을 반환한다.
+fn check_optional_day(
+    field: &str,
+    value: Option<&str>,
+) -> Result<Option<(String, NaiveDate)>, Violation> {
+    match value.map(str::trim) {
+        None | Some("") => Ok(None),
+        Some(day) => NaiveDate::parse_from_str(day, "%Y%m%d")
+            .map(|date| Some((day.to_string(), date)))
+            .map_err(|_| {
+                Violation::new(
+                    "invalid_discount_date",
+                    format!("{} 날짜 형식 오류: {}", field, day),
+                )
+            }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price_item(price: &str) -> PriceApiItem {
+        PriceApiItem {
+            good_inspect_day: "20250103".to_string(),
+            entp_id: "900".to_string(),
+            good_id: "101".to_string(),
+            good_price: price.to_string(),
+            plus_one_yn: None,
+            good_dc_yn: Some("Y".to_string()),
+            good_dc_start_day: Some("20250101".to_string()),
+            good_dc_end_day: Some("20250131".to_string()),
+        }
+    }
+
+    fn good_item(good_id: &str, good_name: &str) -> GoodApiItem {
+        GoodApiItem {
+            good_id: good_id.to_string(),
+            good_name: good_name.to_string(),
+            good_total_cnt: Some(" 12 ".to_string()),
+            good_total_div_code: None,
+            good_smlcls_code: Some(" 030101 ".to_string()),
+            good_unit_div_code: Some(String::new()),
+        }
+    }
+
+    fn store_item(entp_id: &str, area_code: &str) -> StoreApiItem {
+        StoreApiItem {
+            entp_id: entp_id.to_string(),
+            entp_name: "테스트 매장".to_string(),
+            tel_no: None,
+            post_no: None,
+            jibun_addr: None,
+            road_addr: Some("서울특별시 중구 세종대로 110".to_string()),
+            x_coord: None,
+            y_coord: None,
+            area_code: area_code.to_string(),
+            area_detail_code: String::new(),
+            entp_type_code: None,
+        }
+    }
+
+    fn price_reason(item: &PriceApiItem) -> &'static str {
+        validate_price(item, "20250103", "900").unwrap_err().reason
+    }
+
+    #[test]
+    fn valid_price_is_converted() {
+        let price = validate_price(&price_item(" 2980 "), "20250103", "900").unwrap();
+        assert_eq!(price.price, 2980);
+        assert_eq!(price.is_one_plus_one, "N");
+        assert_eq!(price.is_discount, "Y");
+        assert_eq!(price.discount_start.as_deref(), Some("20250101"));
+        assert_eq!(price.discount_end.as_deref(), Some("20250131"));
+    }
+
+    #[test]
+    fn invalid_prices_are_rejected() {
+        assert_eq!(price_reason(&price_item("")), "missing_price");
+        assert_eq!(price_reason(&price_item("abc")), "invalid_price");
+        assert_eq!(price_reason(&price_item("0")), "invalid_price");
+        assert_eq!(price_reason(&price_item("-10")), "invalid_price");
+    }
+
+    #[test]
+    fn price_key_flags_and_discount_period_are_checked() {
+        let mut item = price_item("1000");
+        item.entp_id = "901".to_string();
+        assert_eq!(price_reason(&item), "mismatched_key");
+
+        let mut item = price_item("1000");
+        item.good_id = "12345678901".to_string();
+        assert_eq!(price_reason(&item), "invalid_id");
+
+        let mut item = price_item("1000");
+        item.entp_id = " ".to_string();
+        let violation = validate_price(&item, "20250103", "900").unwrap_err();
+        assert_eq!(violation.detail, "entpId 없음");
+
+        let mut item = price_item("1000");
+        item.plus_one_yn = Some("1".to_string());
+        assert_eq!(price_reason(&item), "invalid_flag");
+
+        let mut item = price_item("1000");
+        item.good_dc_start_day = Some("2025-01-01".to_string());
+        assert_eq!(price_reason(&item), "invalid_discount_date");
+
+        let mut item = price_item("1000");
+        item.good_dc_start_day = Some("20250201".to_string());
+        assert_eq!(price_reason(&item), "invalid_discount_period");
+    }
+
+    #[test]
+    fn good_rules() {
+        let good = validate_good(&good_item(" 101 ", " 서울우유 1L ")).unwrap();
+        assert_eq!(good.good_id, "101");
+        assert_eq!(good.good_name, "서울우유 1L");
+        assert_eq!(good.total_cnt, Some(12));
+        assert_eq!(good.category_code.as_deref(), Some("030101"));
+        assert_eq!(good.unit_code, None);
+
+        assert_eq!(validate_good(&good_item("", "상품")).unwrap_err().reason, "invalid_id");
+        assert_eq!(validate_good(&good_item("101", "  ")).unwrap_err().reason, "missing_name");
+        assert_eq!(
+            validate_good(&good_item("101", &"가".repeat(101))).unwrap_err().reason,
+            "too_long"
+        );
+
+        let mut item = good_item("101", "상품");
+        item.good_total_cnt = Some("12개".to_string());
+        assert_eq!(validate_good(&item).unwrap_err().reason, "invalid_total_cnt");
+    }
+
+    #[test]
+    fn store_rules() {
+        assert!(validate_store(&store_item("900", "020000000")).is_ok());
+        assert_eq!(validate_store(&store_item("90A", "020000000")).unwrap_err().reason, "invalid_id");
+        assert_eq!(validate_store(&store_item("12345678901", "020000000")).unwrap_err().reason, "invalid_id");
+        assert_eq!(validate_store(&store_item("900", " ")).unwrap_err().reason, "missing_area_code");
+
+        let mut item = store_item("900", "020000000");
+        item.tel_no = Some("0".repeat(21));
+        assert_eq!(validate_store(&item).unwrap_err().reason, "too_long");
+    }
+
+    #[test]
+    fn quarantine_keeps_reason_and_payload() {
+        let item = price_item("0");
+        let violation = validate_price(&item, "20250103", "900").unwrap_err();
+        let quarantine = to_quarantine(7, "price", "101".to_string(), violation, &item);
+
+        assert_eq!(quarantine.run_id, Some(7));
+        assert_eq!(quarantine.entity, "price");
+        assert_eq!(quarantine.reason, "invalid_price");
+        assert!(quarantine.payload.contains("\"goodPrice\":\"0\""), "{}", quarantine.payload);
+    }
+}
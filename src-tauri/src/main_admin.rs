@@ -0,0 +1,538 @@
+/************************************************************************
+ * File: main_admin.rs
+ * Description:
+ *     StoreRader 관리자 CLI(storerader-admin)의 엔트리 포인트.
+ *     HTTP 서버 없이 storerader_lib를 직접 호출해 동기화와 점검 작업을 실행한다.
+ *     크론 / 운영자가 사용하며, 결과는 표 또는 JSON(--json)으로 출력한다.
+ *
+ * Reponsibilities:
+ *     1) parse_args()
+ *         - 서브커맨드 / 옵션(--key value) / 플래그(--json) 파싱
+ *
+ *     2) run_command()
+ *         - sync ...           : 상품·매장 / 지역코드 / 표준코드 / 가격 / 조사일 탐색 / 백필 / 재처리 동기화
+ *         - price-change       : 가격 변동 생성 / 기간 재계산
+ *         - geocode retry      : 수동 확인 필요 매장 좌표 재시도
+ *         - migrate status     : 마이그레이션 적용 상태
+ *         - report data-quality: 데이터 품질 리포트
+ *         - run {id}           : 동기화 실행 기록 조회
+ *         - 동기화 작업은 sync_runs 실행 기록과 함께 현재 프로세스에서 실행
+ *
+ *     3) run_locked_command()
+ *         - 파이프라인 단계 동기화 / 재반영 / 가격변동 / 좌표 재시도는
+ *           서버 / 스케줄러와 같은 advisory lock을 잡고 실행
+ *         - 이미 실행 중이면 실행하지 않고 종료 코드 1로 종료
+ *
+ *     4) print_output()
+ *         - 결과를 표(기본) 또는 JSON으로 출력
+ *         - 실패 시 종료 코드 1, 사용법 오류 시 종료 코드 2
+************************************************************************/
+
+use serde_json::{json, Value};
+use std::{collections::HashMap, path::PathBuf, process::ExitCode};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+use storerader_lib::{
+    common::repository::repository_lock::{try_lock, unlock, SYNC_PIPELINE_LOCK_KEY},
+    config::{
+        database::connect_db,
+        env::{get_env_value, init_env},
+        state::{build_app_state, AppState},
+        sync::load_scheduler_config,
+    },
+    domain::{
+        admin::service::{get_data_quality_report, get_migration_status},
+        price_change::service::{recompute_price_change, upsert_price_change},
+        sync::{
+            dto::dto_req::{ReprocessTarget, StandardCodeClass},
+            progress::{run_tracked, SyncKind},
+            service,
+        },
+    },
+};
+
+const USAGE: &str = "\
+사용법: storerader-admin [--json] <명령> [옵션]
+
+명령:
+  sync goods-and-stores                         상품 + 매장 동기화
+  sync region-codes                             지역코드 동기화
+  sync standard-codes [--class region|good_category|unit|store_type]
+                                                표준코드 동기화 (기본값: 전체 분류)
+  sync prices --inspect-day YYYYMMDD            특정 조사일 가격 동기화
+  sync prices-latest                            가장 최근 조사일 가격 동기화
+  sync inspect-days [--days N]                  게시된 조사일 탐색
+  sync backfill --from YYYYMMDD --to YYYYMMDD   기간 내 가격 백필 (가격 변동 자동 생성)
+  sync reprocess --target goods|stores|region_codes|standard_codes|prices [--inspect-day YYYYMMDD]
+                                                보관된 원본 응답으로 재반영
+  price-change --inspect-day YYYYMMDD           특정 조사일 가격 변동 재생성
+  price-change recompute --from YYYYMMDD [--to YYYYMMDD]
+                                                하루 또는 기간 내 가격 변동 재계산 (단일 트랜잭션)
+  geocode retry                                 수동 확인 필요 매장 좌표 재시도
+  migrate status [--dir PATH]                   마이그레이션 적용 상태 (기본값: MIGRATIONS_DIR 또는 ../migrations)
+  report data-quality [--days N]                데이터 품질 리포트 (격리 항목 집계 기간, 기본값: 7)
+  run ID                                        동기화 실행 기록 조회
+
+옵션:
+  --json    결과를 JSON으로 출력";
+
+/// 데이터 품질 리포트의 기본 격리 항목 집계 기간 (일)
+const DEFAULT_REPORT_DAYS: i64 = 7;
+
+/// 파싱된 명령행 인자
+///
+/// # Fields
+/// * `json`        - JSON 출력 여부
+/// * `positional`  - 명령 / 하위 명령 / 위치 인자
+/// * `options`     - --key value 옵션
+struct Args {
+    json: bool,
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+/// 명령 실행 실패 종류
+enum CommandError {
+    Usage(String),
+    Failed(String),
+}
+
+impl From<String> for CommandError {
+    fn from(e: String) -> Self {
+        CommandError::Failed(e)
+    }
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    init_env();
+
+    // 표 / JSON 출력과 섞이지 않도록 로그는 stderr로 보낸다.
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::EnvFilter::new(
+            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
+        ))
+        .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+        .init();
+
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    if args.positional.is_empty() || args.positional[0] == "help" {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let pool = connect_db().await;
+    let state = build_app_state(pool);
+
+    match run_locked_command(&state, &args).await {
+        Ok(output) => {
+            print_output(&output, args.json);
+            ExitCode::SUCCESS
+        }
+        Err(CommandError::Usage(e)) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+        Err(CommandError::Failed(e)) => {
+            if args.json {
+                println!("{}", json!({ "error": e }));
+            } else {
+                eprintln!("실패: {}", e);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// 명령행 인자를 위치 인자 / 옵션 / 플래그로 나눈다.
+///
+/// # Arguments
+/// * `raw` - 프로그램 이름을 제외한 명령행 인자
+///
+/// # Returns
+/// * `Ok(Args)`    - 파싱 결과
+/// * `Err(String)` - 값이 없는 옵션
+fn parse_args(mut raw: impl Iterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args {
+        json: false,
+        positional: Vec::new(),
+        options: HashMap::new(),
+    };
+
+    while let Some(arg) = raw.next() {
+        if arg == "--json" {
+            args.json = true;
+        } else if arg == "-h" || arg == "--help" {
+            args.positional = vec!["help".into()];
+        } else if let Some(key) = arg.strip_prefix("--") {
+            let value = raw
+                .next()
+                .ok_or_else(|| format!("--{} 옵션에 값이 없습니다", key))?;
+            args.options.insert(key.to_string(), value);
+        } else {
+            args.positional.push(arg);
+        }
+    }
+
+    Ok(args)
+}
+
+/// 필수 옵션 값을 가져온다.
+fn required<'a>(args: &'a Args, key: &str) -> Result<&'a str, CommandError> {
+    args.options
+        .get(key)
+        .map(|v| v.as_str())
+        .ok_or_else(|| CommandError::Usage(format!("--{} 옵션이 필요합니다", key)))
+}
+
+/// 숫자 옵션 값을 가져온다. 없으면 None을 반환한다.
+fn numeric<T: std::str::FromStr>(args: &Args, key: &str) -> Result<Option<T>, CommandError> {
+    args.options
+        .get(key)
+        .map(|v| {
+            v.parse::<T>()
+                .map_err(|_| CommandError::Usage(format!("--{} 옵션은 숫자여야 합니다: {}", key, v)))
+        })
+        .transpose()
+}
+
+/// 명령을 실행하고 결과를 JSON 값으로 반환한다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+/// * `args`    - 파싱된 명령행 인자
+///
+/// # Returns
+/// * `Ok(Value)`           - 실행 결과
+/// * `Err(CommandError)`   - 사용법 오류 또는 실행 실패
+async fn run_command(state: &AppState, args: &Args) -> Result<Value, CommandError> {
+    let pool = &state.pool;
+    let sources = &state.sources;
+    let command: Vec<&str> = args.positional.iter().map(|s| s.as_str()).collect();
+
+    let output = match command.as_slice() {
+        ["sync", "goods-and-stores"] => {
+            run_tracked(pool, SyncKind::GoodsAndStores, json!({}), |progress| async move {
+                service::upsert_good_and_store(pool, sources, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "region-codes"] => {
+            run_tracked(pool, SyncKind::RegionCodes, json!({}), |progress| async move {
+                service::upsert_region_codes(pool, sources, &progress)
+                    .await
+                    .map(|count| json!({ "saved_regions": count }))
+            })
+            .await?
+        }
+        ["sync", "standard-codes"] => {
+            let class = args
+                .options
+                .get("class")
+                .map(|c| {
+                    serde_json::from_value::<StandardCodeClass>(json!(c))
+                        .map_err(|_| CommandError::Usage(format!("알 수 없는 표준코드 분류: {}", c)))
+                })
+                .transpose()?;
+
+            run_tracked(pool, SyncKind::StandardCodes, json!({ "class": class }), |progress| async move {
+                service::upsert_standard_codes(pool, sources, class, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "prices"] => {
+            let inspect_day = required(args, "inspect-day")?;
+            service::validate_inspect_day(inspect_day).map_err(CommandError::Usage)?;
+            let params = json!({ "inspect_day": inspect_day });
+
+            run_tracked(pool, SyncKind::Prices, params, |progress| async move {
+                service::upsert_price(pool, sources, inspect_day, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "prices-latest"] => {
+            let probe_days = load_scheduler_config().probe_days;
+
+            run_tracked(pool, SyncKind::Prices, json!({ "latest": true }), |progress| async move {
+                service::sync_latest_prices(pool, sources, probe_days, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "inspect-days"] => {
+            let probe_days = numeric::<u32>(args, "days")?
+                .unwrap_or_else(|| load_scheduler_config().probe_days);
+
+            run_tracked(pool, SyncKind::InspectDayDiscovery, json!({ "days": probe_days }), |progress| async move {
+                service::discover_inspect_days(pool, sources, probe_days, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "backfill"] => {
+            let from = required(args, "from")?;
+            let to = required(args, "to")?;
+
+            run_tracked(pool, SyncKind::PriceBackfill, json!({ "from": from, "to": to }), |progress| async move {
+                service::backfill_prices(pool, sources, from, to, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["sync", "reprocess"] => {
+            let target_str = required(args, "target")?;
+            let target: ReprocessTarget = serde_json::from_value(json!(target_str))
+                .map_err(|_| CommandError::Usage(format!("알 수 없는 재처리 대상: {}", target_str)))?;
+            let inspect_day = args.options.get("inspect-day").map(|s| s.as_str());
+            let params = json!({ "target": target, "inspect_day": inspect_day });
+
+            run_tracked(pool, SyncKind::Reprocess, params, |progress| async move {
+                service::reprocess_archived(pool, target, inspect_day, &progress).await
+            })
+            .await?
+        }
+        ["price-change"] => {
+            let inspect_day = required(args, "inspect-day")?;
+
+            run_tracked(pool, SyncKind::PriceChange, json!({ "inspect_day": inspect_day }), |progress| async move {
+                upsert_price_change(pool, inspect_day, &progress)
+                    .await
+                    .map(|msg| json!({ "message": msg }))
+            })
+            .await?
+        }
+        ["price-change", "recompute"] => {
+            let from = required(args, "from")?;
+            let to = args.options.get("to").map(|s| s.as_str()).unwrap_or(from);
+
+            run_tracked(pool, SyncKind::PriceChangeRecompute, json!({ "from": from, "to": to }), |progress| async move {
+                recompute_price_change(pool, from, to, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["geocode", "retry"] => {
+            run_tracked(pool, SyncKind::GeocodeRetry, json!({}), |progress| async move {
+                service::retry_manual_review_geocodes(pool, sources, &progress)
+                    .await
+                    .map(|res| json!(res))
+            })
+            .await?
+        }
+        ["migrate", "status"] => {
+            let dir = args
+                .options
+                .get("dir")
+                .cloned()
+                .or_else(|| Some(get_env_value("MIGRATIONS_DIR")).filter(|d| !d.is_empty()))
+                .unwrap_or_else(|| "../migrations".into());
+
+            json!(get_migration_status(pool, &PathBuf::from(dir)).await?)
+        }
+        ["report", "data-quality"] => {
+            let days = numeric::<i64>(args, "days")?.unwrap_or(DEFAULT_REPORT_DAYS);
+
+            json!(get_data_quality_report(pool, days).await?)
+        }
+        ["run", id] => {
+            let run_id = id
+                .parse::<i32>()
+                .map_err(|_| CommandError::Usage(format!("실행 기록 ID는 숫자여야 합니다: {}", id)))?;
+
+            match service::get_sync_run(pool, run_id).await? {
+                Some(run) => json!(run),
+                None => return Err(CommandError::Failed(format!("동기화 실행 기록 없음: {}", run_id))),
+            }
+        }
+        _ => {
+            return Err(CommandError::Usage(format!(
+                "알 수 없는 명령: {}",
+                args.positional.join(" ")
+            )))
+        }
+    };
+
+    Ok(output)
+}
+
+/// 서버 / 스케줄러와 동시에 실행되면 안 되는 파이프라인 단계 명령인지 확인한다.
+/// 동기화 단계와 같은 테이블을 갱신하는 재반영 / 조사일 탐색 / 가격변동 / 좌표 재시도 명령도 포함한다.
+fn needs_pipeline_lock(args: &Args) -> bool {
+    let command: Vec<&str> = args.positional.iter().map(|s| s.as_str()).collect();
+
+    matches!(
+        command.as_slice(),
+        ["sync", "goods-and-stores" | "region-codes" | "standard-codes"]
+            | ["sync", "prices" | "prices-latest" | "backfill"]
+            | ["sync", "inspect-days" | "reprocess"]
+            | ["price-change"]
+            | ["price-change", "recompute"]
+            | ["geocode", "retry"]
+    )
+}
+
+/// 파이프라인 단계 명령은 동기화 파이프라인 잠금을 획득한 뒤 실행한다.
+/// 서버 / 스케줄러 / 다른 CLI가 잠금을 보유 중이면 실행하지 않고 실패로 반환한다.
+///
+/// # Arguments
+/// * `state`   - 애플리케이션 상태 (DB 커넥션 풀, 데이터 소스)
+/// * `args`    - 파싱된 명령행 인자
+///
+/// # Returns
+/// * `Ok(Value)`           - 실행 결과
+/// * `Err(CommandError)`   - 사용법 오류, 잠금 보유 중 또는 실행 실패
+async fn run_locked_command(state: &AppState, args: &Args) -> Result<Value, CommandError> {
+    if !needs_pipeline_lock(args) {
+        return run_command(state, args).await;
+    }
+
+    let Some(lock) = try_lock(&state.pool, SYNC_PIPELINE_LOCK_KEY).await? else {
+        return Err(CommandError::Failed(
+            "다른 동기화 파이프라인(서버 / 스케줄러 / CLI)이 실행 중입니다. 완료 후 다시 실행하세요".into(),
+        ));
+    };
+
+    let result = run_command(state, args).await;
+
+    if let Err(e) = unlock(lock).await {
+        tracing::error!("{}", e);
+    }
+
+    result
+}
+
+/// 실행 결과를 출력한다.
+/// 객체는 "항목 / 값" 표로, 객체 배열은 열 표로 출력하며, 중첩된 객체 배열은 별도 표로 이어서 출력한다.
+///
+/// # Arguments
+/// * `output`  - 실행 결과
+/// * `json`    - JSON 출력 여부
+fn print_output(output: &Value, json: bool) {
+    if json {
+        println!("{}", serde_json::to_string_pretty(output).unwrap_or_default());
+        return;
+    }
+
+    match output {
+        Value::Array(items) => print_rows(items),
+        Value::Object(_) => {
+            let mut fields: Vec<Vec<String>> = Vec::new();
+            let mut nested: Vec<(String, &Vec<Value>)> = Vec::new();
+            flatten("", output, &mut fields, &mut nested);
+
+            print_table(&["항목", "값"], &fields);
+            for (key, items) in nested {
+                println!("\n[{}]", key);
+                print_rows(items);
+            }
+        }
+        other => println!("{}", scalar(other)),
+    }
+}
+
+/// 중첩 객체를 "a.b" 형태의 키로 펼친다. 객체 배열은 별도 표로 출력하도록 모아 둔다.
+fn flatten<'a>(
+    prefix: &str,
+    value: &'a Value,
+    fields: &mut Vec<Vec<String>>,
+    nested: &mut Vec<(String, &'a Vec<Value>)>,
+) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                let key = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten(&key, v, fields, nested);
+            }
+        }
+        Value::Array(items) if items.iter().any(|v| v.is_object()) => {
+            nested.push((prefix.to_string(), items));
+        }
+        other => fields.push(vec![prefix.to_string(), scalar(other)]),
+    }
+}
+
+/// 객체 배열을 첫 번째 객체의 키를 열로 하는 표로 출력한다.
+fn print_rows(items: &[Value]) {
+    let Some(Value::Object(first)) = items.first() else {
+        let rows: Vec<Vec<String>> = items.iter().map(|v| vec![scalar(v)]).collect();
+        print_table(&["값"], &rows);
+        return;
+    };
+
+    let headers: Vec<&str> = first.keys().map(|k| k.as_str()).collect();
+    let rows: Vec<Vec<String>> = items
+        .iter()
+        .map(|item| headers.iter().map(|h| scalar(&item[*h])).collect())
+        .collect();
+
+    print_table(&headers, &rows);
+}
+
+/// 열 너비를 맞춰 표를 출력한다.
+fn print_table(headers: &[&str], rows: &[Vec<String>]) {
+    let mut widths: Vec<usize> = headers.iter().map(|h| display_width(h)).collect();
+    for row in rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(display_width(cell));
+        }
+    }
+
+    let line = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, w)| format!("{}{}", c, " ".repeat(w - display_width(c))))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    println!("{}", line(headers.to_vec()));
+    let separator: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+    println!("{}", line(separator.iter().map(String::as_str).collect()));
+    for row in rows {
+        println!("{}", line(row.iter().map(|s| s.as_str()).collect()));
+    }
+    if rows.is_empty() {
+        println!("(없음)");
+    }
+}
+
+/// 터미널 표시 너비를 계산한다. (한글 등 전각 문자는 2칸)
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| {
+            let wide = ('\u{1100}'..='\u{11FF}').contains(&c)
+                || ('\u{2E80}'..='\u{D7A3}').contains(&c)
+                || ('\u{FF00}'..='\u{FF60}').contains(&c);
+            if wide { 2 } else { 1 }
+        })
+        .sum()
+}
+
+/// 표에 출력할 단일 값 문자열을 만든다.
+fn scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "-".into(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
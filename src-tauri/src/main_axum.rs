@@ -11,22 +11,42 @@
  *     2) tracing_subscriber
  *         - 로그 레벨 및 출력 포맷 설정
  *
- *     3) connect_db()
+ *     3) connect_db() / build_app_state()
  *         - PostgreSQL 커넥션 풀 생성
+ *         - 생존 신호가 끊긴 동기화 실행 기록('running')을 실패로 정리
+ *         - 업스트림 데이터 소스(live / record / replay)와 함께 앱 상태 구성
  *
  *     4) CORS 설정
  *         - 프론트엔드 (Tarui/React) 접근 허용
  *
  *     5) 라우터 구성
  *         - /auth/...         : 인증
- *         - /sync/...         : 공공데이터 기반 DB 동기화
+ *         - /sync/...         : 공공데이터 기반 DB 동기화 (백그라운드 실행, POST)
+ *                               관리자 JWT 또는 X-Admin-Key 필요, 모든 시도는 감사 로그 기록
+ *                               파이프라인 단계는 정기 동기화와 같은 잠금 사용, 실행 중이면 409
+ *         - /sync/runs/{id}   : 동기화 실행 기록 조회
+ *         - /sync/reprocess   : 보관된 원본 응답으로 DB 재반영
+ *         - /sync/quarantine  : 검증 실패로 격리된 항목 조회
+ *         - /sync/price-change/recompute : 하루 또는 기간 내 가격 변동 재생성
  *         - /get/...          : 데이터 조회
+ *         - /get/{stores,goods}/history : 매장 / 상품 기준 정보 변경 이력 조회
+ *         - /get/goods/search : 상품 검색 (부분 / 유사도 / 초성 일치, mode=autocomplete 시 자동완성)
+ *         - /get/region-codes/{tree,children,ancestors} : 지역 트리 / 하위 / 상위 지역 조회
+ *         - /get/stores/nearby : 기준 좌표 반경 내 매장을 거리순으로 조회 (거리 포함)
+ *         - /get/stores/all?region_code= : 지역 및 모든 하위 지역에 속한 매장 조회
+ *         - /get/recommendations : 사용자 가중치 기반 매장 추천 순위 (점수 구성 포함)
+ *         - /get/basket       : 장바구니 최저 비용 매장 / 두 매장 조합 조회 (POST, 이동 비용 반영)
+ *         - /get/standard-codes : 분류별 표준코드(상품분류 / 단위 / 업태 / 지역) 조회
  *         - /update/...       : 데이터 갱신
  *
- *     6) 서버 실행 (0.0.0.0:3000 리스닝)
+ *     6) spawn_sync_scheduler()
+ *         - SYNC_SCHEDULE 설정 시 정기 동기화 스케줄러 실행
+ *
+ *     7) 서버 실행 (0.0.0.0:3000 리스닝)
 ************************************************************************/
 
 use axum::{
+    middleware,
     routing::{get, post},
     Router,
 };
@@ -35,16 +55,33 @@ use tower_http::cors::{Any, CorsLayer};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use storerader_lib::{
-    config::{database::connect_db, env::init_env},
+    common::middleware::admin_auth::require_admin,
+    config::{database::connect_db, env::init_env, state::build_app_state},
     domain::{
         auth::handler::auth_google_handler,
-        good::handler::goods_list_handler,
+        basket::handler::basket_optimize_handler,
+        good::handler::{goods_history_handler, goods_list_handler, goods_search_handler},
+        inspect_day::handler::inspect_days_list_handler,
         price::handler::prices_get_handler,
-        price_change::handler::{price_change_get_handler, sync_price_change_handler},
-        region_code::handler::region_codes_list_handler,
-        store::handler::stores_list_handler,
-        sync::handler::{
-            sync_goods_and_stores_handler, sync_prices_handler, sync_region_codes_handler,
+        price_change::handler::{
+            price_change_get_handler, recompute_price_change_handler, sync_price_change_handler,
+        },
+        recommendation::handler::recommendations_get_handler,
+        region_code::handler::{
+            region_codes_ancestors_handler, region_codes_children_handler,
+            region_codes_list_handler, region_codes_tree_handler,
+        },
+        standard_code::handler::standard_codes_list_handler,
+        store::handler::{stores_history_handler, stores_list_handler, stores_nearby_handler},
+        sync::{
+            handler::{
+                sync_goods_and_stores_handler, sync_prices_handler, sync_region_codes_handler,
+                sync_backfill_handler, sync_inspect_days_discover_handler,
+                sync_latest_prices_handler, sync_quarantine_list_handler, sync_reprocess_handler,
+                sync_run_get_handler, sync_standard_codes_handler,
+            },
+            progress::recover_interrupted_runs,
+            scheduler::spawn_sync_scheduler,
         },
         user_log::handler::{user_selection_log_get_handler, user_selection_log_update_handler},
         user_preference::handler::user_preference_get_handler,
@@ -63,6 +100,10 @@ async fn main() {
         .init();
 
     let pool = connect_db().await;
+    recover_interrupted_runs(&pool).await;
+    let state = build_app_state(pool);
+
+    spawn_sync_scheduler(state.clone());
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
@@ -71,17 +112,38 @@ async fn main() {
 
     let auth_routes = Router::new().route("/auth/google", post(auth_google_handler));
 
+    // 관리자 전용 라우트 : 변경 작업은 POST, 조회는 GET
     let sync_routes = Router::new()
-        .route("/sync/goods-and-stores", get(sync_goods_and_stores_handler))
-        .route("/sync/region-codes", get(sync_region_codes_handler))
-        .route("/sync/prices", get(sync_prices_handler))
-        .route("/sync/price-change", get(sync_price_change_handler));
+        .route("/sync/goods-and-stores", post(sync_goods_and_stores_handler))
+        .route("/sync/region-codes", post(sync_region_codes_handler))
+        .route("/sync/standard-codes", post(sync_standard_codes_handler))
+        .route("/sync/prices", post(sync_prices_handler))
+        .route("/sync/prices/latest", post(sync_latest_prices_handler))
+        .route("/sync/inspect-days/discover", post(sync_inspect_days_discover_handler))
+        .route("/sync/price-change", post(sync_price_change_handler))
+        .route("/sync/price-change/recompute", post(recompute_price_change_handler))
+        .route("/sync/backfill", post(sync_backfill_handler))
+        .route("/sync/reprocess", post(sync_reprocess_handler))
+        .route("/sync/runs/{id}", get(sync_run_get_handler))
+        .route("/sync/quarantine", get(sync_quarantine_list_handler))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_admin));
 
     let get_routes = Router::new()
         .route("/get/stores/all", get(stores_list_handler))
+        .route("/get/stores/history", get(stores_history_handler))
+        .route("/get/stores/nearby", get(stores_nearby_handler))
         .route("/get/goods/all", get(goods_list_handler))
+        .route("/get/goods/history", get(goods_history_handler))
+        .route("/get/goods/search", get(goods_search_handler))
         .route("/get/region-codes/all", get(region_codes_list_handler))
+        .route("/get/region-codes/tree", get(region_codes_tree_handler))
+        .route("/get/region-codes/children", get(region_codes_children_handler))
+        .route("/get/region-codes/ancestors", get(region_codes_ancestors_handler))
+        .route("/get/standard-codes", get(standard_codes_list_handler))
         .route("/get/prices", get(prices_get_handler))
+        .route("/get/recommendations", get(recommendations_get_handler))
+        .route("/get/basket", post(basket_optimize_handler))
+        .route("/get/inspect-days", get(inspect_days_list_handler))
         .route("/get/user-preferences", post(user_preference_get_handler))
         .route("/get/user-selection-log",get(user_selection_log_get_handler),)
         .route("/get/price-change", get(price_change_get_handler))
@@ -97,7 +159,7 @@ async fn main() {
         .merge(get_routes)
         .merge(update_routes)
         .layer(cors)
-        .with_state(pool.clone());
+        .with_state(state);
 
     tracing::info!("서버가 http://localhost:3000에서 시작되었습니다");
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await.unwrap();
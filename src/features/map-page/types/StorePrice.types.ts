@@ -1,5 +1,10 @@
 export interface StorePrice {
+    good_id: string;
     store_id: string;
     price: number;
     inspect_day: string;
+    is_one_plus_one: boolean;
+    is_discount: boolean;
+    discount_start: string | null;
+    discount_end: string | null;
 }